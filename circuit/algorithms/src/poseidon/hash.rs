@@ -20,12 +20,63 @@ impl<E: Environment, const RATE: usize> Hash for Poseidon<E, RATE> {
     type Input = Field<E>;
     type Output = Field<E>;
 
+    /// Returns the Poseidon hash of the given input as a field element.
+    ///
+    /// If every input is a constant, every round of the permutation is evaluated on constants,
+    /// and as such, the hash folds into a constant, and incurs no constraints.
     #[inline]
     fn hash(&self, input: &[Self::Input]) -> Self::Output {
         self.hash_many(input, 1).swap_remove(0)
     }
 }
 
+impl<E: Environment, const RATE: usize> Metrics<dyn Hash<Input = Field<E>, Output = Field<E>>> for Poseidon<E, RATE> {
+    type Case = Vec<Mode>;
+
+    #[inline]
+    fn count(case: &Self::Case) -> Count {
+        // If every input is a constant, the hash is a constant, and only the length of the input is allocated.
+        if case.iter().all(Mode::is_constant) {
+            return Count::is(1, 0, 0, 0);
+        }
+
+        // The Poseidon parameters of the base field have 8 full rounds and 31 partial rounds,
+        // with an S-box of `x^17`, which costs 5 constraints (4 squarings and 1 multiplication).
+        const NUM_FULL_ROUNDS: u64 = 8;
+        const NUM_PARTIAL_ROUNDS: u64 = 31;
+        const NUM_SBOX_CONSTRAINTS: u64 = 5;
+
+        // The first permutation only absorbs the domain and length, and is free. Thereafter,
+        // each block of `RATE` inputs is permuted once, where the S-boxes of the first full round
+        // are free for the capacity element and for any rate element that did not absorb an input.
+        let (rate, num_inputs) = (RATE as u64, case.len() as u64);
+        let num_permutations = num_inputs / rate + u64::from(num_inputs % rate != 0);
+        let num_free_sboxes = rate + 1 - num_inputs.min(rate);
+        let num_sboxes = num_permutations * (NUM_FULL_ROUNDS * (rate + 1) + NUM_PARTIAL_ROUNDS) - num_free_sboxes;
+        let num_constraints = NUM_SBOX_CONSTRAINTS * num_sboxes;
+
+        // If some inputs are constant, their S-boxes may be free, and as such, the count is an upper bound.
+        match case.iter().any(Mode::is_constant) {
+            true => Count::less_than(1, 0, num_constraints, num_constraints),
+            false => Count::is(1, 0, num_constraints, num_constraints),
+        }
+    }
+}
+
+impl<E: Environment, const RATE: usize> OutputMode<dyn Hash<Input = Field<E>, Output = Field<E>>>
+    for Poseidon<E, RATE>
+{
+    type Case = Vec<Mode>;
+
+    #[inline]
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case.iter().all(Mode::is_constant) {
+            true => Mode::Constant,
+            false => Mode::Private,
+        }
+    }
+}
+
 #[cfg(all(test, console))]
 mod tests {
     use super::*;
@@ -67,6 +118,16 @@ mod tests {
                 assert_eq!(expected, candidate.eject_value());
                 let case = format!("(mode = {mode}, num_inputs = {num_inputs})");
                 assert_scope!(case, num_constants, num_public, num_private, num_constraints);
+
+                // Check the constraint counts and output mode.
+                let modes = input.iter().map(|field| field.eject_mode()).collect::<Vec<_>>();
+                assert_count!(Poseidon<Circuit, RATE>, Hash<Input = Field<Circuit>, Output = Field<Circuit>>, &modes);
+                assert_output_mode!(
+                    Poseidon<Circuit, RATE>,
+                    Hash<Input = Field<Circuit>, Output = Field<Circuit>>,
+                    &modes,
+                    candidate
+                );
             });
             Circuit::reset();
         }
@@ -83,6 +144,36 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_hash_mixed() -> Result<()> {
+        use console::Hash as H;
+
+        let mut rng = TestRng::default();
+
+        let native = console::Poseidon::<<Circuit as Environment>::Network, RATE>::setup(DOMAIN)?;
+        let poseidon = Poseidon::<Circuit, RATE>::constant(native.clone());
+
+        for num_inputs in 1..=(2 * RATE + 1) {
+            // Prepare the preimage, where only the first input is a variable.
+            let native_input = (0..num_inputs)
+                .map(|_| console::Field::<<Circuit as Environment>::Network>::rand(&mut rng))
+                .collect::<Vec<_>>();
+            let modes =
+                (0..num_inputs).map(|i| if i == 0 { Mode::Private } else { Mode::Constant }).collect::<Vec<_>>();
+            let input = native_input.iter().zip(&modes).map(|(v, mode)| Field::new(*mode, *v)).collect::<Vec<_>>();
+
+            // Ensure the hash matches, and the count is within the upper bound.
+            let expected = native.hash(&native_input)?;
+            Circuit::scope(format!("Poseidon mixed {num_inputs}"), || {
+                let candidate = poseidon.hash(&input);
+                assert_eq!(expected, candidate.eject_value());
+                assert_count!(Poseidon<Circuit, RATE>, Hash<Input = Field<Circuit>, Output = Field<Circuit>>, &modes);
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
     #[test]
     fn test_hash_public() -> Result<()> {
         let mut rng = TestRng::default();
@@ -21,7 +21,7 @@ mod hash_to_scalar;
 mod prf;
 
 #[cfg(all(test, console))]
-use snarkvm_circuit_types::environment::assert_scope;
+use snarkvm_circuit_types::environment::{assert_count, assert_output_mode, assert_scope};
 #[cfg(test)]
 use snarkvm_utilities::{TestRng, Uniform};
 
@@ -38,6 +38,15 @@ pub trait FromBits {
         Self: Sized;
 }
 
+/// Unary operator for instantiating from bits, without enforcing that the bits encode a value within range.
+pub trait FromBitsUnchecked {
+    type Boolean: BooleanTrait;
+
+    fn from_bits_le_unchecked(bits_le: &[Self::Boolean]) -> Self
+    where
+        Self: Sized;
+}
+
 /// Unary operator for converting from a base field element.
 pub trait FromField {
     type Field: FieldTrait;
@@ -36,6 +36,248 @@ pub trait SquareRoot {
     fn square_root(&self) -> Self::Output;
 }
 
+/// Ternary operator for computing `self * mul_by + add` as a single fused operation.
+pub trait MulAdd<Rhs: ?Sized = Self> {
+    type Output;
+
+    fn mul_add(&self, mul_by: &Rhs, add: &Rhs) -> Self::Output;
+}
+
+/// Operator for selecting between two slices element-wise, given a shared condition.
+pub trait ConditionalSelectMany {
+    type Boolean: BooleanTrait;
+    type Output;
+
+    /// Returns `first` if `condition` is `true`, otherwise returns `second`, element-wise.
+    fn conditional_select_many(condition: &Self::Boolean, first: &[Self], second: &[Self]) -> Self::Output
+    where
+        Self: Sized;
+}
+
+/// Binary operator for computing `self * other`, along with its inverse, and whether the product is nonzero.
+pub trait MulInversePair<Rhs: ?Sized = Self> {
+    type Output;
+
+    fn mul_inverse_pair(&self, other: &Rhs) -> Self::Output;
+}
+
+/// Operator for accumulating the term `constant * field` into `self`.
+pub trait AddTerm<Constant, Rhs: ?Sized> {
+    fn add_term(&mut self, constant: Constant, field: &Rhs);
+}
+
+/// Unary operator for enforcing that `self` is less than a constant bound.
+pub trait AssertLessThanConst<Bound> {
+    fn assert_less_than_const(&self, bound: Bound);
+}
+
+/// Unary operator for shifting `self` left by a constant number of bits.
+pub trait ShlConst {
+    type Output;
+
+    fn shl_const(&self, k: u32) -> Self::Output;
+}
+
+/// Unary operator for shifting `self` right by a constant number of bits.
+pub trait ShrConst {
+    type Output;
+
+    fn shr_const(&self, k: u32) -> Self::Output;
+}
+
+/// Operator for enforcing that `a == b` if `condition` is `true`.
+pub trait ConditionalAssertEq {
+    type Boolean: BooleanTrait;
+
+    fn conditional_assert_eq(condition: &Self::Boolean, a: &Self, b: &Self)
+    where
+        Self: Sized;
+}
+
+/// Operator for enforcing that `self` is a root of the polynomial with the given roots.
+pub trait AssertIsRootOf {
+    fn assert_is_root_of(&self, roots: &[Self])
+    where
+        Self: Sized;
+}
+
+/// Operator for selecting the smaller or the larger of two values.
+pub trait MinMax {
+    type Output;
+
+    /// Returns the smaller of `a` and `b`.
+    fn min(a: &Self, b: &Self) -> Self::Output
+    where
+        Self: Sized;
+
+    /// Returns the larger of `a` and `b`.
+    fn max(a: &Self, b: &Self) -> Self::Output
+    where
+        Self: Sized;
+}
+
+/// Operator for evaluating the polynomial through the given points at `x`.
+pub trait Interpolate<Point> {
+    type Output;
+
+    fn interpolate(points: &[(Point, Self)], x: &Self) -> Self::Output
+    where
+        Self: Sized;
+}
+
+/// Binary operator for subtracting `other` from `self` over `num_bits`-bit values, with a borrow bit.
+pub trait SubWithBorrow<Rhs: ?Sized = Self> {
+    type Output;
+
+    fn sub_with_borrow(&self, other: &Rhs, num_bits: usize) -> Self::Output;
+}
+
+/// Unary operator for enforcing that `self` equals one of the given constants.
+pub trait AssertInSet<Element> {
+    type Output;
+
+    fn assert_in_set(&self, set: &[Element]) -> Self::Output;
+}
+
+/// Unary operator for enforcing that `self` equals one of two given constants.
+pub trait AssertIsOneOfTwo<Element> {
+    fn assert_is_one_of_two(&self, c0: Element, c1: Element);
+}
+
+/// Operator for summing the values whose flag is set.
+pub trait MaskedSum {
+    type Boolean: BooleanTrait;
+    type Output;
+
+    fn masked_sum(values: &[Self], flags: &[Self::Boolean]) -> Self::Output
+    where
+        Self: Sized;
+}
+
+/// Unary operator for exponentiating `self` by a constant exponent.
+pub trait PowU64 {
+    type Output;
+
+    fn pow_u64(&self, exponent: u64) -> Self::Output;
+}
+
+/// Unary operator for exponentiating `self` by a constant exponent, using fixed-window exponentiation.
+pub trait PowU64Windowed {
+    type Output;
+
+    fn pow_u64_windowed(&self, exponent: u64, window: u32) -> Self::Output;
+}
+
+/// Unary operator for enforcing that `self` is nonzero.
+pub trait EnforceNonzero {
+    fn enforce_nonzero(&self);
+}
+
+/// Unary operator for determining whether `self` is a square, without computing its square root.
+pub trait IsSquare {
+    type Boolean: BooleanTrait;
+
+    fn is_square_only(&self) -> Self::Boolean;
+}
+
+/// Binary operator for computing the fixed-point product of `self` and `other`, scaled by `2^scale_bits`.
+pub trait FixedMul<Rhs: ?Sized = Self> {
+    type Output;
+
+    fn fixed_mul(&self, other: &Rhs, scale_bits: u32) -> Self::Output;
+}
+
+/// Operator for exponentiating a constant base by an exponent, given by its little-endian bits.
+pub trait ConstBasePow<Base> {
+    type Boolean: BooleanTrait;
+    type Output;
+
+    fn const_base_pow(base: Base, exp_bits: &[Self::Boolean]) -> Self::Output
+    where
+        Self: Sized;
+}
+
+/// Operator for enforcing that `b` is a permutation of `a`, given a random challenge.
+pub trait AssertPermutation {
+    type Output;
+
+    fn assert_permutation(a: &[Self], b: &[Self], challenge: &Self) -> Self::Output
+    where
+        Self: Sized;
+}
+
+/// Binary operator for comparing `self` and `other`, returning both the ordering and the equality.
+pub trait CompareWithEquality<Rhs: ?Sized = Self> {
+    type Boolean: BooleanTrait;
+
+    /// Returns `(is_less_than, is_equal)` for `self` and `other`.
+    fn compare(&self, other: &Rhs) -> (Self::Boolean, Self::Boolean);
+}
+
+/// Operator for enforcing that `a * b == product` if `condition` is `true`.
+pub trait ConditionalEnforceMul {
+    type Boolean: BooleanTrait;
+
+    fn conditional_enforce_mul(condition: &Self::Boolean, a: &Self, b: &Self, product: &Self)
+    where
+        Self: Sized;
+}
+
+/// Unary operator for enforcing that `self` encodes a valid scalar field element.
+pub trait AssertIsValidScalar {
+    type Output;
+
+    fn assert_is_valid_scalar(&self) -> Self::Output;
+}
+
+/// Operator for updating a multiset accumulator `current` with `element`, given a random challenge.
+pub trait AccumulatorUpdate {
+    type Output;
+
+    fn accumulator_update(current: &Self, element: &Self, challenge: &Self) -> Self::Output
+    where
+        Self: Sized;
+}
+
+/// Binary operator for adding a constant to `self`, without allocating the constant.
+pub trait AddConstant<Constant> {
+    type Output;
+
+    fn add_constant(&self, constant: Constant) -> Self::Output;
+}
+
+/// Unary operator for caching `self` as a divisor, for repeated divisions.
+pub trait AsDivisor {
+    type Output;
+
+    fn as_divisor(&self) -> Self::Output;
+}
+
+/// Binary operator for computing the quotient and remainder of the integer division of `self` by `divisor`.
+pub trait DivRem<Rhs: ?Sized = Self> {
+    type Output;
+
+    fn div_rem(&self, divisor: &Rhs, num_bits: usize) -> Self::Output;
+}
+
+/// Operator for enforcing that two slices are equal, element-wise.
+pub trait AssertEqSlice {
+    type Output;
+
+    fn assert_eq_slice(a: &[Self], b: &[Self]) -> Self::Output
+    where
+        Self: Sized;
+}
+
+/// Binary operator for enforcing that `self` is congruent to `other` modulo a constant.
+pub trait AssertEqMod<Modulus> {
+    type Output;
+
+    fn assert_eq_mod(&self, other: &Self, modulus: Modulus) -> Self::Output
+    where
+        Self: Sized;
+}
+
 ///
 /// A single-bit binary adder with a carry bit.
 ///
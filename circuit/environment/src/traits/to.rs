@@ -50,6 +50,18 @@ pub trait ToUpperBits {
     fn to_upper_bits_be(&self, k: usize) -> Vec<Self::Boolean>;
 }
 
+/// Unary operator for converting to exactly `n` number of bits.
+pub trait ToExactBits {
+    type Boolean: BooleanTrait;
+    type Output;
+
+    ///
+    /// Outputs exactly `n` bits of an element in little-endian representation.
+    /// Enforces that the element is less than `2^n`.
+    ///
+    fn to_exact_bits_le(&self, n: usize) -> Self::Output;
+}
+
 /// Unary operator for converting to a base field.
 pub trait ToField {
     type Field: FieldTrait;
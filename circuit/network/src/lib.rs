@@ -135,4 +135,8 @@ pub trait Aleo: Environment {
         root: &Field<Self>,
         leaf: &Vec<Field<Self>>,
     ) -> Boolean<Self>;
+
+    /// Returns the parent of the given sibling pair, using the Poseidon path hasher (with an input rate of 2)
+    /// of `verify_merkle_path_psd`. The parent matches the console `merkle_tree_psd` path hash.
+    fn merkle_hash_step_psd(left: &Field<Self>, right: &Field<Self>) -> Field<Self>;
 }
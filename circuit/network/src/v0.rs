@@ -266,6 +266,13 @@ impl Aleo for AleoV0 {
     ) -> Boolean<Self> {
         POSEIDON_4.with(|psd4| POSEIDON_2.with(|psd2| path.verify(psd4, psd2, root, leaf)))
     }
+
+    /// Returns the parent of the given sibling pair, using the Poseidon path hasher (with an input rate of 2)
+    /// of `verify_merkle_path_psd`. The parent matches the console `merkle_tree_psd` path hash.
+    fn merkle_hash_step_psd(left: &Field<Self>, right: &Field<Self>) -> Field<Self> {
+        // Prepend the nodes with a `1field` byte, as in the path hasher.
+        Self::hash_psd2(&[Field::one(), left.clone(), right.clone()])
+    }
 }
 
 impl Environment for AleoV0 {
@@ -409,6 +416,7 @@ impl Display for AleoV0 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use console::prelude::{TestRng, Uniform};
     use snarkvm_circuit_types::Field;
 
     type CurrentAleo = AleoV0;
@@ -437,6 +445,46 @@ mod tests {
         candidate
     }
 
+    #[test]
+    fn test_merkle_hash_step_psd() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for _ in 0..10 {
+                // Construct a console Merkle tree with two leaves, whose root is the path hash of the leaf hashes.
+                let leaves = vec![vec![Uniform::rand(&mut rng)], vec![Uniform::rand(&mut rng)]];
+                let tree = <console::Testnet3 as console::Network>::merkle_tree_psd::<1>(&leaves)?;
+                let (left, right) = (tree.tree()[1], tree.tree()[2]);
+
+                // Ensure the circuit path hash matches the console path hash.
+                let candidate = CurrentAleo::merkle_hash_step_psd(&Field::new(mode, left), &Field::new(mode, right));
+                assert_eq!(*tree.root(), candidate.eject_value());
+                assert!(CurrentAleo::is_satisfied());
+                CurrentAleo::reset();
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_psd2_constant() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for num_inputs in 0..5 {
+            let input = (0..num_inputs).map(|_| Uniform::rand(&mut rng)).collect::<Vec<_>>();
+            let expected = <console::Testnet3 as console::Network>::hash_psd2(&input)?;
+
+            // Ensure hashing constant inputs folds into a constant, without any constraints.
+            let num_constraints = CurrentAleo::num_constraints();
+            let candidate = CurrentAleo::hash_psd2(&input.iter().map(|v| Field::constant(*v)).collect::<Vec<_>>());
+            assert!(candidate.is_constant());
+            assert_eq!(expected, candidate.eject_value());
+            assert_eq!(num_constraints, CurrentAleo::num_constraints());
+            CurrentAleo::reset();
+        }
+        Ok(())
+    }
+
     #[test]
     fn test_print_circuit() {
         let circuit = CurrentAleo {};
@@ -0,0 +1,123 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> AccumulatorUpdate for Field<E> {
+    type Output = Field<E>;
+
+    /// Returns the multiset accumulator `current` updated with `element`, as `current * (challenge - element)`,
+    /// enforced with a single constraint. Folding this step over a sequence of elements yields the
+    /// grand product `Π (challenge - element_i)`, as used in permutation and lookup arguments.
+    fn accumulator_update(current: &Field<E>, element: &Field<E>, challenge: &Field<E>) -> Self::Output {
+        // Note: The difference is a linear combination, and incurs no constraints.
+        current * (challenge - element)
+    }
+}
+
+impl<E: Environment> Metrics<dyn AccumulatorUpdate<Output = Field<E>>> for Field<E> {
+    type Case = (Mode, Mode, Mode);
+
+    /// Returns the number of constants, public variables, private variables, and constraints
+    /// to update the accumulator, for the given modes of `current`, `element`, and `challenge`.
+    fn count(case: &Self::Case) -> Count {
+        match case.0.is_constant() || (case.1.is_constant() && case.2.is_constant()) {
+            true => Count::is(0, 0, 0, 0),
+            false => Count::is(0, 0, 1, 1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_accumulator_update(
+        name: &str,
+        expected: &console::Field<<Circuit as Environment>::Network>,
+        current: &Field<Circuit>,
+        element: &Field<Circuit>,
+        challenge: &Field<Circuit>,
+    ) {
+        Circuit::scope(name, || {
+            let candidate = Field::accumulator_update(current, element, challenge);
+            assert_eq!(*expected, candidate.eject_value());
+            assert_count!(
+                AccumulatorUpdate(Field) => Field,
+                &(current.eject_mode(), element.eject_mode(), challenge.eject_mode())
+            );
+        });
+        Circuit::reset();
+    }
+
+    fn run_test(mode_a: Mode, mode_b: Mode, mode_c: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+            let third = Uniform::rand(&mut rng);
+
+            let current = Field::<Circuit>::new(mode_a, first);
+            let element = Field::<Circuit>::new(mode_b, second);
+            let challenge = Field::<Circuit>::new(mode_c, third);
+
+            let name = format!("AccumulatorUpdate: current * (challenge - element) {i}");
+            check_accumulator_update(&name, &(first * (third - second)), &current, &element, &challenge);
+
+            // Test an element equal to the challenge, which zeroes the accumulator.
+            let name = format!("AccumulatorUpdate: current * (challenge - challenge) {i}");
+            let element = Field::<Circuit>::new(mode_b, third);
+            check_accumulator_update(&name, &console::Field::zero(), &current, &element, &challenge);
+        }
+    }
+
+    #[test]
+    fn test_accumulator_update() {
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                for mode_c in [Mode::Constant, Mode::Public, Mode::Private] {
+                    run_test(mode_a, mode_b, mode_c);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_accumulator_update_grand_product() {
+        let mut rng = TestRng::default();
+
+        // Sample the elements and the challenge.
+        let elements: Vec<console::Field<<Circuit as Environment>::Network>> =
+            (0..ITERATIONS).map(|_| Uniform::rand(&mut rng)).collect();
+        let challenge = Uniform::rand(&mut rng);
+
+        // Compute the grand product on the console layer.
+        let expected = elements.iter().fold(console::Field::one(), |product, element| product * (challenge - *element));
+
+        // Fold the accumulator update over the elements.
+        let challenge = Field::<Circuit>::new(Mode::Private, challenge);
+        let candidate = elements.iter().fold(Field::one(), |current, element| {
+            Field::accumulator_update(&current, &Field::new(Mode::Private, *element), &challenge)
+        });
+        assert_eq!(expected, candidate.eject_value());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+}
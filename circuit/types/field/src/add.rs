@@ -65,6 +65,16 @@ impl<E: Environment> AddAssign<&Field<E>> for Field<E> {
     }
 }
 
+impl<E: Environment> AddConstant<console::Field<E::Network>> for Field<E> {
+    type Output = Field<E>;
+
+    /// Returns `self + constant`, by adding the constant directly into the linear combination of `self`.
+    /// This incurs no constraints, and does not allocate a new constant.
+    fn add_constant(&self, constant: console::Field<E::Network>) -> Self::Output {
+        Field::from(&self.linear_combination + E::one() * *constant)
+    }
+}
+
 impl<E: Environment> Metrics<dyn Add<Field<E>, Output = Field<E>>> for Field<E> {
     type Case = (Mode, Mode);
 
@@ -73,6 +83,14 @@ impl<E: Environment> Metrics<dyn Add<Field<E>, Output = Field<E>>> for Field<E>
     }
 }
 
+impl<E: Environment> Metrics<dyn AddConstant<console::Field<E::Network>, Output = Field<E>>> for Field<E> {
+    type Case = Mode;
+
+    fn count(_case: &Self::Case) -> Count {
+        Count::is(0, 0, 0, 0)
+    }
+}
+
 impl<E: Environment> OutputMode<dyn Add<Field<E>, Output = Field<E>>> for Field<E> {
     type Case = (CircuitType<Field<E>>, CircuitType<Field<E>>);
 
@@ -134,6 +152,24 @@ mod tests {
         });
     }
 
+    fn check_add_constant(
+        name: &str,
+        expected: &console::Field<<Circuit as Environment>::Network>,
+        a: &Field<Circuit>,
+        b: console::Field<<Circuit as Environment>::Network>,
+    ) {
+        Circuit::scope(name, || {
+            let candidate = a.add_constant(b);
+            assert_eq!(*expected, candidate.eject_value(), "({} + {})", a.eject_value(), b);
+            assert_eq!(a.is_constant(), candidate.is_constant());
+            assert_count!(
+                Field<Circuit>,
+                AddConstant<console::Field<<Circuit as Environment>::Network>, Output = Field<Circuit>>,
+                &a.eject_mode()
+            );
+        });
+    }
+
     fn run_test(mode_a: Mode, mode_b: Mode) {
         let mut rng = TestRng::default();
 
@@ -149,6 +185,8 @@ mod tests {
             check_add(&name, &expected, &a, &b);
             let name = format!("AddAssign: a + b {}", i);
             check_add_assign(&name, &expected, &a, &b);
+            let name = format!("AddConstant: a + b {}", i);
+            check_add_constant(&name, &expected, &a, second);
 
             // Test identity.
             let name = format!("Add: a + 0 {}", i);
@@ -0,0 +1,270 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> AssertEqMod<console::Field<E::Network>> for Field<E> {
+    type Output = Result<()>;
+
+    /// Enforces that `self` is congruent to `other` modulo the given constant `modulus`, where `self` and `other`
+    /// are interpreted as integers in `[0, BaseField::MODULUS)`. To do so, this method witnesses a signed quotient `q`,
+    /// such that `self - other == q * modulus`, where `q` is negative if and only if `self < other`,
+    /// and enforces `|q| * modulus < BaseField::MODULUS`, so that the identity holds over the integers.
+    ///
+    /// This method halts if `modulus` is zero, and errors if `self` and `other` are constants that are not congruent.
+    fn assert_eq_mod(&self, other: &Field<E>, modulus: console::Field<E::Network>) -> Self::Output {
+        // Ensure the modulus is nonzero.
+        if modulus.is_zero() {
+            E::halt("Attempted to assert congruence modulo zero")
+        }
+
+        // Case 1: Constant == Constant (mod modulus)
+        if self.is_constant() && other.is_constant() {
+            let (first, second) = (self.eject_value(), other.eject_value());
+            let difference = if first < second { second - first } else { first - second };
+            ensure!(
+                Self::div_rem_values(difference, modulus).1.is_zero(),
+                "Field elements {first} and {second} are not congruent modulo {modulus}"
+            );
+        }
+        // Case 2: Constant == Variable | Variable == Constant | Variable == Variable
+        // Note: Every pair is congruent modulo one, which incurs no constraints.
+        else if !modulus.is_one() {
+            // Ensure the bits of each variable are canonical, so that the comparison below holds over the integers.
+            // Note: Otherwise, a prover may witness the bits of `self + BaseField::MODULUS` to flip the sign of `q`,
+            // which only enforces `self - other == q * modulus` modulo the base field.
+            for field in [self, other] {
+                if !field.is_constant() {
+                    field.to_bits_le_with_proof();
+                }
+            }
+            // Compute the sign of the quotient, which is negative if and only if `self < other`.
+            let is_negative = self.is_less_than(other);
+
+            // Witness the magnitude of the quotient, as `|self - other| / modulus`.
+            let magnitude: Field<E> = witness!(|self, other| {
+                let difference = if self < other { other - self } else { self - other };
+                Self::div_rem_values(difference, modulus).0
+            });
+            // Ensure `|q| * modulus < BaseField::MODULUS`, which ensures the product does not wrap around.
+            magnitude.assert_less_than_const(Self::quotient_bound(modulus));
+            // Compute the signed quotient.
+            let quotient = Field::ternary(&is_negative, &-&magnitude, &magnitude);
+
+            // Ensure `self - other == quotient * modulus`.
+            E::assert_eq(self - other, Field::from(&quotient.linear_combination * *modulus));
+        }
+        Ok(())
+    }
+}
+
+impl<E: Environment> Field<E> {
+    /// Returns the exclusive upper bound `(BaseField::MODULUS - 1) / modulus + 1` on the magnitude of the quotient,
+    /// which is zero if `modulus` is one.
+    fn quotient_bound(modulus: console::Field<E::Network>) -> console::Field<E::Network> {
+        Self::div_rem_values(-console::Field::one(), modulus).0 + console::Field::one()
+    }
+}
+
+impl<E: Environment> Metrics<dyn AssertEqMod<console::Field<E::Network>, Output = Result<()>>> for Field<E> {
+    /// The modes of `self` and `other`, and the number of significant bits in the bound on the quotient.
+    type Case = (Mode, Mode, u64);
+
+    fn count(case: &Self::Case) -> Count {
+        match case {
+            (Mode::Constant, Mode::Constant, _) => Count::is(0, 0, 0, 0),
+            // The bound on the quotient is zero if and only if the modulus is one.
+            (_, _, 0) => Count::is(0, 0, 0, 0),
+            // The canonical bit decomposition costs at most 505 private variables and 507 constraints,
+            // the comparison costs 253 constants, and at most 253 private variables and constraints,
+            // the signed quotient costs 2 private variables and 1 constraint, the bound on the quotient costs
+            // at most `2 * num_bits - 1` private variables and `2 * num_bits + 1` constraints,
+            // and the identity costs 1 constraint.
+            (Mode::Constant, _, num_bits) | (_, Mode::Constant, num_bits) => {
+                Count::less_than(253, 0, 2 * num_bits + 759, 2 * num_bits + 763)
+            }
+            // The canonical bit decompositions cost at most 1010 private variables and 1014 constraints,
+            // and the comparison costs at most 506 private variables and constraints.
+            (_, _, num_bits) => Count::less_than(0, 0, 2 * num_bits + 1517, 2 * num_bits + 1523),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::{assert_count_fails, Circuit};
+
+    const ITERATIONS: u64 = 25;
+
+    type CurrentField = console::Field<<Circuit as Environment>::Network>;
+
+    /// Returns the number of significant bits in the bound on the quotient, for the given modulus.
+    fn num_bits(modulus: CurrentField) -> u64 {
+        let bound = Field::<Circuit>::quotient_bound(modulus);
+        bound.to_bits_le().iter().rposition(|bit| *bit).map_or(0, |index| index as u64 + 1)
+    }
+
+    fn check_assert_eq_mod(name: &str, first: CurrentField, second: CurrentField, modulus: u64, modes: (Mode, Mode)) {
+        let modulus = CurrentField::from_u64(modulus);
+        let a = Field::<Circuit>::new(modes.0, first);
+        let b = Field::<Circuit>::new(modes.1, second);
+
+        Circuit::scope(name, || {
+            assert!(a.assert_eq_mod(&b, modulus).is_ok());
+            assert_count!(
+                Field<Circuit>,
+                AssertEqMod<CurrentField, Output = Result<()>>,
+                &(modes.0, modes.1, num_bits(modulus))
+            );
+        });
+        Circuit::reset();
+    }
+
+    fn check_assert_eq_mod_fails(
+        name: &str,
+        first: CurrentField,
+        second: CurrentField,
+        modulus: u64,
+        modes: (Mode, Mode),
+    ) {
+        let modulus = CurrentField::from_u64(modulus);
+        let a = Field::<Circuit>::new(modes.0, first);
+        let b = Field::<Circuit>::new(modes.1, second);
+
+        match modes.0.is_constant() && modes.1.is_constant() {
+            true => assert!(a.assert_eq_mod(&b, modulus).is_err()),
+            false => {
+                Circuit::scope(name, || {
+                    assert!(a.assert_eq_mod(&b, modulus).is_ok());
+                    assert_count_fails!(
+                        Field<Circuit>,
+                        AssertEqMod<CurrentField, Output = Result<()>>,
+                        &(modes.0, modes.1, num_bits(modulus))
+                    );
+                });
+            }
+        }
+        Circuit::reset();
+    }
+
+    fn run_test(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        let modes = (mode_a, mode_b);
+        for i in 0..ITERATIONS {
+            // Sample a modulus, and two integers with the same remainder.
+            let modulus = (u64::rand(&mut rng) >> 1).max(2);
+            let remainder = u64::rand(&mut rng) % modulus;
+            let offset = 1 + u64::rand(&mut rng) % (modulus - 1);
+            let first = remainder as u128 + u64::rand(&mut rng) as u128 * modulus as u128;
+            let second = remainder as u128 + u64::rand(&mut rng) as u128 * modulus as u128;
+            let (first, second) = (CurrentField::from_u128(first), CurrentField::from_u128(second));
+
+            check_assert_eq_mod(&format!("{mode_a} {mode_b} ({i})"), first, second, modulus, modes);
+            check_assert_eq_mod(&format!("{mode_a} {mode_b} swapped ({i})"), second, first, modulus, modes);
+            let incongruent = second + CurrentField::from_u64(offset);
+            check_assert_eq_mod_fails(&format!("{mode_a} {mode_b} fails ({i})"), first, incongruent, modulus, modes);
+
+            // Check values near the base field modulus.
+            let max = -CurrentField::one();
+            let below = max - CurrentField::from_u64(modulus) * CurrentField::from_u64(u64::rand(&mut rng));
+            check_assert_eq_mod(&format!("{mode_a} {mode_b} max ({i})"), max, below, modulus, modes);
+            check_assert_eq_mod(&format!("{mode_a} {mode_b} max swapped ({i})"), below, max, modulus, modes);
+
+            // Ensure a difference that is a multiple of the modulus only in the field is not congruent,
+            // as `0 - (BaseField::MODULUS - modulus) == modulus` in the field, but not over the integers.
+            let wrapped = -CurrentField::from_u64(modulus);
+            check_assert_eq_mod_fails(
+                &format!("{mode_a} {mode_b} wraps ({i})"),
+                CurrentField::zero(),
+                wrapped,
+                modulus,
+                modes,
+            );
+        }
+
+        // Ensure every pair is congruent modulo one.
+        let (first, second) = (Uniform::rand(&mut rng), Uniform::rand(&mut rng));
+        check_assert_eq_mod(&format!("{mode_a} {mode_b} modulo one"), first, second, 1, modes);
+    }
+
+    #[test]
+    fn test_assert_eq_mod() {
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                run_test(mode_a, mode_b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_assert_eq_mod_zero_halts() {
+        let mut rng = TestRng::default();
+
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                let a = Field::<Circuit>::new(mode_a, Uniform::rand(&mut rng));
+                let b = Field::<Circuit>::new(mode_b, Uniform::rand(&mut rng));
+                let result = std::panic::catch_unwind(|| a.assert_eq_mod(&b, CurrentField::zero()));
+                assert!(result.is_err());
+                Circuit::reset();
+            }
+        }
+    }
+
+    #[test]
+    fn test_assert_eq_mod_off_by_modulus_forgery() {
+        let modulus = CurrentField::from_u64(7);
+
+        for mode_a in [Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                // Note: `0` and `BaseField::MODULUS - modulus` are not congruent, yet their difference is `modulus`
+                // in the field, and thus a multiple of `modulus` if the comparison is flipped.
+                let a = Field::<Circuit>::new(mode_a, CurrentField::zero());
+                let b = Field::<Circuit>::new(mode_b, -modulus);
+
+                // Witness the non-canonical bits of `0 + BaseField::MODULUS`, as a malicious prover may.
+                let forged_bits_le = <Circuit as Environment>::BaseField::modulus().to_bits_le();
+                let bits_le = forged_bits_le[..CurrentField::size_in_bits()]
+                    .iter()
+                    .map(|bit| Boolean::new(Mode::Private, *bit))
+                    .collect::<Vec<_>>();
+                // Ensure the bits reconstruct `a`, as `BaseField::MODULUS == 0` in the field.
+                let mut accumulator = Field::zero();
+                let mut coefficient = Field::one();
+                for bit in &bits_le {
+                    accumulator += Field::from_boolean(bit) * &coefficient;
+                    coefficient = coefficient.double();
+                }
+                Circuit::assert_eq(&a, accumulator);
+                assert!(Circuit::is_satisfied());
+
+                // Cache the forged bits, so that they are used in place of the canonical bits of `a`.
+                let a = Field {
+                    linear_combination: a.linear_combination,
+                    bits_le: OnceCell::from(bits_le),
+                    canonicity_proof: Default::default(),
+                };
+
+                // Ensure the forged bits are rejected, as they are not less than the base field modulus.
+                assert!(a.assert_eq_mod(&b, modulus).is_ok());
+                assert!(!Circuit::is_satisfied());
+                Circuit::reset();
+            }
+        }
+    }
+}
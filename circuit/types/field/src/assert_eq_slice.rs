@@ -0,0 +1,119 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> AssertEqSlice for Field<E> {
+    type Output = Result<()>;
+
+    /// Enforces that `a[i] == b[i]` for each index `i`, with one constraint per pair of elements.
+    ///
+    /// This method errors if `a` and `b` differ in length,
+    /// and halts if a pair of constant elements are not equal.
+    fn assert_eq_slice(a: &[Field<E>], b: &[Field<E>]) -> Self::Output {
+        // Ensure the slices are of equal length.
+        ensure!(a.len() == b.len(), "Cannot assert equality of {} and {} field elements", a.len(), b.len());
+        // Enforce `a[i] == b[i]` for each index `i`.
+        a.iter().zip_eq(b).for_each(|(a, b)| E::assert_eq(a, b));
+        Ok(())
+    }
+}
+
+impl<E: Environment> Metrics<dyn AssertEqSlice<Output = Result<()>>> for Field<E> {
+    type Case = (Mode, Mode, usize);
+
+    fn count(case: &Self::Case) -> Count {
+        match case {
+            (Mode::Constant, Mode::Constant, _) => Count::is(0, 0, 0, 0),
+            (_, _, length) => Count::is(0, 0, 0, *length as u64),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::{assert_count_fails, Circuit};
+
+    const ITERATIONS: u64 = 10;
+
+    fn check_assert_eq_slice(mode_a: Mode, mode_b: Mode, length: usize, rng: &mut TestRng) -> Result<()> {
+        for i in 0..ITERATIONS {
+            let given = (0..length).map(|_| Uniform::rand(rng)).collect::<Vec<_>>();
+            let a = given.iter().map(|value| Field::<Circuit>::new(mode_a, *value)).collect::<Vec<_>>();
+            let b = given.iter().map(|value| Field::<Circuit>::new(mode_b, *value)).collect::<Vec<_>>();
+            let case = (mode_a, mode_b, length);
+
+            // Ensure equal slices are satisfiable.
+            Circuit::scope(format!("{mode_a} {mode_b} {length} {i}"), || {
+                Field::assert_eq_slice(&a, &b)?;
+                assert_count!(Field<Circuit>, AssertEqSlice<Output = Result<()>>, &case);
+                Ok::<_, Error>(())
+            })?;
+            Circuit::reset();
+
+            if length == 0 {
+                continue;
+            }
+
+            // Change a single element of `b`.
+            let mut b = b;
+            let index = i as usize % length;
+            b[index] = Field::new(mode_b, given[index] + console::Field::one());
+
+            match (mode_a, mode_b) {
+                // Ensure a violated constant assertion halts.
+                (Mode::Constant, Mode::Constant) => {
+                    let result = std::panic::catch_unwind(|| Field::assert_eq_slice(&a, &b));
+                    assert!(result.is_err());
+                }
+                // Ensure a violated assertion is unsatisfiable.
+                _ => Circuit::scope(format!("{mode_a} {mode_b} {length} {i} (differs)"), || {
+                    Field::assert_eq_slice(&a, &b)?;
+                    assert_count_fails!(Field<Circuit>, AssertEqSlice<Output = Result<()>>, &case);
+                    Ok::<_, Error>(())
+                })?,
+            }
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_assert_eq_slice() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                for length in [0, 1, 2, 8] {
+                    check_assert_eq_slice(mode_a, mode_b, length, &mut rng)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_assert_eq_slice_fails() {
+        let a = vec![Field::<Circuit>::new(Mode::Private, console::Field::one()); 2];
+        let b = vec![Field::<Circuit>::new(Mode::Private, console::Field::one()); 3];
+
+        // Ensure slices of different lengths fail.
+        assert!(Field::assert_eq_slice(&a, &b).is_err());
+        assert!(Field::assert_eq_slice(&b, &a).is_err());
+        assert!(Field::assert_eq_slice(&a, &[]).is_err());
+    }
+}
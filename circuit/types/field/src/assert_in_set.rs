@@ -0,0 +1,198 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> AssertInSet<console::Field<E::Network>> for Field<E> {
+    type Output = Result<()>;
+
+    /// Enforces that `self` equals one of the given constants, as the vanishing polynomial `Π (self - set_i) == 0`.
+    /// As the set elements are constants, the product incurs one multiplication for each element after
+    /// the second, and the final multiplication is folded into the assertion, i.e. `set.len() - 1` constraints.
+    ///
+    /// This method errors if `set` is empty, and halts if `self` is a constant that is not in `set`.
+    fn assert_in_set(&self, set: &[console::Field<E::Network>]) -> Self::Output {
+        // Ensure the set is not empty, as the empty product is never zero.
+        let (last, rest) = match set.split_last() {
+            Some((last, rest)) => (last, rest),
+            None => bail!("Cannot assert membership in an empty set"),
+        };
+
+        // Case 1: Constant
+        if self.is_constant() {
+            let value = self.eject_value();
+            if !set.contains(&value) {
+                E::halt(format!("Field element {value} is not in the given set"))
+            }
+        }
+        // Case 2: Variable
+        else {
+            // Compute `Π (self - set_i)` over all but the last element.
+            let product = rest.iter().fold(Field::one(), |product, element| product * self.add_constant(-*element));
+            // Ensure `Π (self - set_i) * (self - set_n) == 0`.
+            E::enforce(|| (product, self.add_constant(-*last), E::zero()));
+        }
+        Ok(())
+    }
+}
+
+impl<E: Environment> AssertIsOneOfTwo<console::Field<E::Network>> for Field<E> {
+    /// Enforces that `self` equals `c0` or `c1`, as `(self - c0) * (self - c1) == 0` in a single constraint.
+    /// This is equivalent to `assert_in_set(&[c0, c1])`, without the error for an empty set.
+    ///
+    /// This method halts if `self` is a constant that equals neither `c0` nor `c1`.
+    fn assert_is_one_of_two(&self, c0: console::Field<E::Network>, c1: console::Field<E::Network>) {
+        // Case 1: Constant
+        if self.is_constant() {
+            let value = self.eject_value();
+            if value != c0 && value != c1 {
+                E::halt(format!("Field element {value} is neither {c0} nor {c1}"))
+            }
+        }
+        // Case 2: Variable
+        else {
+            // Ensure `(self - c0) * (self - c1) == 0`.
+            E::enforce(|| (self.add_constant(-c0), self.add_constant(-c1), E::zero()));
+        }
+    }
+}
+
+impl<E: Environment> Metrics<dyn AssertInSet<console::Field<E::Network>, Output = Result<()>>> for Field<E> {
+    /// The mode of `self`, and the size of the set.
+    type Case = (Mode, u64);
+
+    fn count(case: &Self::Case) -> Count {
+        match case {
+            (Mode::Constant, _) | (_, 0) => Count::is(0, 0, 0, 0),
+            (_, size) => Count::is(0, 0, size.saturating_sub(2), size - 1),
+        }
+    }
+}
+
+impl<E: Environment> Metrics<dyn AssertIsOneOfTwo<console::Field<E::Network>>> for Field<E> {
+    type Case = Mode;
+
+    fn count(case: &Self::Case) -> Count {
+        match case.is_constant() {
+            true => Count::is(0, 0, 0, 0),
+            false => Count::is(0, 0, 0, 1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::{assert_count_fails, Circuit};
+
+    type CurrentNetwork = <Circuit as Environment>::Network;
+
+    const ITERATIONS: u64 = 20;
+
+    fn check_assert_in_set(mode: Mode, size: usize, rng: &mut TestRng) -> Result<()> {
+        for i in 0..ITERATIONS {
+            // Sample the set.
+            let set: Vec<console::Field<CurrentNetwork>> = (0..size).map(|_| Uniform::rand(rng)).collect();
+            let case = (mode, size as u64);
+
+            // Ensure each element of the set is satisfiable.
+            for (j, value) in set.iter().enumerate() {
+                let candidate = Field::<Circuit>::new(mode, *value);
+                Circuit::scope(format!("{mode} {size} {i} in set {j}"), || {
+                    candidate.assert_in_set(&set)?;
+                    assert_count!(
+                        Field<Circuit>,
+                        AssertInSet<console::Field<CurrentNetwork>, Output = Result<()>>,
+                        &case
+                    );
+                    Ok::<_, Error>(())
+                })?;
+            }
+
+            // Ensure an element outside of the set is unsatisfiable, or halts for a constant.
+            let candidate = Field::<Circuit>::new(mode, Uniform::rand(rng));
+            match mode.is_constant() {
+                true => {
+                    let result = std::panic::catch_unwind(|| candidate.assert_in_set(&set));
+                    assert!(result.is_err());
+                }
+                false => Circuit::scope(format!("{mode} {size} {i} not in set"), || {
+                    candidate.assert_in_set(&set)?;
+                    assert_count_fails!(
+                        Field<Circuit>,
+                        AssertInSet<console::Field<CurrentNetwork>, Output = Result<()>>,
+                        &case
+                    );
+                    Ok::<_, Error>(())
+                })?,
+            }
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_assert_in_set() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for size in [1, 2, 3, 8] {
+                check_assert_in_set(mode, size, &mut rng)?;
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_assert_is_one_of_two() {
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for i in 0..ITERATIONS {
+                let (c0, c1): (console::Field<CurrentNetwork>, _) = (Uniform::rand(&mut rng), Uniform::rand(&mut rng));
+
+                // Ensure each of the two constants is satisfiable.
+                for (j, value) in [c0, c1].into_iter().enumerate() {
+                    let candidate = Field::<Circuit>::new(mode, value);
+                    Circuit::scope(format!("{mode} {i} is one of two {j}"), || {
+                        candidate.assert_is_one_of_two(c0, c1);
+                        assert_count!(Field<Circuit>, AssertIsOneOfTwo<console::Field<CurrentNetwork>>, &mode);
+                    });
+                }
+
+                // Ensure a third value is unsatisfiable, or halts for a constant.
+                let candidate = Field::<Circuit>::new(mode, Uniform::rand(&mut rng));
+                match mode.is_constant() {
+                    true => {
+                        let result = std::panic::catch_unwind(|| candidate.assert_is_one_of_two(c0, c1));
+                        assert!(result.is_err());
+                    }
+                    false => Circuit::scope(format!("{mode} {i} is neither"), || {
+                        candidate.assert_is_one_of_two(c0, c1);
+                        assert_count_fails!(Field<Circuit>, AssertIsOneOfTwo<console::Field<CurrentNetwork>>, &mode);
+                    }),
+                }
+                Circuit::reset();
+            }
+        }
+    }
+
+    #[test]
+    fn test_assert_in_set_empty_fails() {
+        let candidate = Field::<Circuit>::new(Mode::Private, console::Field::one());
+        assert!(candidate.assert_in_set(&[]).is_err());
+    }
+}
@@ -0,0 +1,130 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> AssertIsRootOf for Field<E> {
+    /// Enforces that `self` is a root of the polynomial `Π (X - root_i)`, i.e. that `self` equals one of the roots.
+    /// This is enforced as `Π (self - root_i) == 0`, where the final multiplication is folded into the assertion.
+    ///
+    /// This method halts if `roots` is empty, or if `self` and `roots` are constants and `self` is not a root.
+    fn assert_is_root_of(&self, roots: &[Field<E>]) {
+        // Ensure there is at least one root, as the empty product is never zero.
+        let (last, rest) = match roots.split_last() {
+            Some((last, rest)) => (last, rest),
+            None => E::halt("Attempted to assert that a field element is a root of a polynomial without roots"),
+        };
+
+        // Case 1: Constant
+        if self.is_constant() && roots.iter().all(|root| root.is_constant()) {
+            let value = self.eject_value();
+            if !roots.iter().any(|root| root.eject_value() == value) {
+                E::halt(format!("Field element {value} is not a root of the given polynomial"))
+            }
+        }
+        // Case 2: Variable
+        else {
+            // Compute `Π (self - root_i)` over all but the last root.
+            let product = rest.iter().fold(Field::one(), |product, root| product * (self - root));
+            // Ensure `Π (self - root_i) * (self - root_n) == 0`.
+            E::enforce(|| (product, self - last, E::zero()));
+        }
+    }
+}
+
+impl<E: Environment> Metrics<dyn AssertIsRootOf> for Field<E> {
+    /// The mode of `self`, and the modes of the roots.
+    type Case = (Mode, Vec<Mode>);
+
+    fn count(case: &Self::Case) -> Count {
+        let (mode, modes) = case;
+        match mode.is_constant() && modes.iter().all(|mode| mode.is_constant()) {
+            true => Count::is(0, 0, 0, 0),
+            false => {
+                // Count the differences `self - root_i` that are variables, over all but the last root.
+                let num_variables = match mode.is_constant() {
+                    true => modes.iter().rev().skip(1).filter(|mode| !mode.is_constant()).count(),
+                    false => modes.len().saturating_sub(1),
+                };
+                // The product incurs one multiplication for each variable difference after the first,
+                // and the final multiplication is folded into the assertion.
+                let num_multiplications = (num_variables as u64).saturating_sub(1);
+                Count::is(0, 0, num_multiplications, num_multiplications + 1)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::{assert_count_fails, Circuit};
+
+    const ITERATIONS: u64 = 20;
+
+    fn check_assert_is_root_of(name: &str, mode: Mode, mode_roots: Mode, num_roots: usize, rng: &mut TestRng) {
+        for i in 0..ITERATIONS {
+            // Sample the roots.
+            let values: Vec<console::Field<<Circuit as Environment>::Network>> =
+                (0..num_roots).map(|_| Uniform::rand(rng)).collect();
+            let roots: Vec<_> = values.iter().map(|value| Field::<Circuit>::new(mode_roots, *value)).collect();
+            let case = (mode, vec![mode_roots; num_roots]);
+
+            // Ensure each root is satisfiable.
+            for (j, value) in values.iter().enumerate() {
+                let candidate = Field::<Circuit>::new(mode, *value);
+                Circuit::scope(format!("{name} {i} root {j}"), || {
+                    candidate.assert_is_root_of(&roots);
+                    assert_count!(Field<Circuit>, AssertIsRootOf, &case);
+                });
+            }
+
+            // Ensure a non-root is unsatisfiable, or halts for constants.
+            let candidate = Field::<Circuit>::new(mode, Uniform::rand(rng));
+            match mode.is_constant() && mode_roots.is_constant() {
+                true => {
+                    let result = std::panic::catch_unwind(|| candidate.assert_is_root_of(&roots));
+                    assert!(result.is_err());
+                }
+                false => Circuit::scope(format!("{name} {i} non-root"), || {
+                    candidate.assert_is_root_of(&roots);
+                    assert_count_fails!(Field<Circuit>, AssertIsRootOf, &case);
+                }),
+            }
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_assert_is_root_of() {
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_roots in [Mode::Constant, Mode::Public, Mode::Private] {
+                for num_roots in [1, 2, 3, 8] {
+                    let name = format!("{mode} {mode_roots} {num_roots}");
+                    check_assert_is_root_of(&name, mode, mode_roots, num_roots, &mut rng);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_assert_is_root_of_no_roots_halts() {
+        let result = std::panic::catch_unwind(|| Field::<Circuit>::one().assert_is_root_of(&[]));
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,140 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> AssertIsValidScalar for Field<E> {
+    type Output = Result<()>;
+
+    /// Enforces that `self` encodes a valid scalar field element, i.e. `0 <= self < ScalarField::MODULUS`,
+    /// where `self` is interpreted as an integer in `[0, BaseField::MODULUS)`.
+    ///
+    /// This method errors if `self` is a constant that is not less than the scalar field modulus.
+    fn assert_is_valid_scalar(&self) -> Self::Output {
+        // Retrieve the scalar field modulus as a base field element.
+        // Note: This is safe as the scalar field modulus is less than the base field modulus.
+        let modulus = Self::scalar_modulus()?;
+
+        // Case 1: Constant
+        if self.is_constant() {
+            let value = self.eject_value();
+            ensure!(value < modulus, "Field element {value} is not a valid scalar field element");
+        }
+        // Case 2: Variable
+        else {
+            self.assert_less_than_const(modulus);
+        }
+        Ok(())
+    }
+}
+
+impl<E: Environment> Field<E> {
+    /// Returns the scalar field modulus as a base field element.
+    fn scalar_modulus() -> Result<console::Field<E::Network>> {
+        console::FromBits::from_bits_le(&E::ScalarField::modulus().to_bits_le())
+    }
+}
+
+impl<E: Environment> Metrics<dyn AssertIsValidScalar<Output = Result<()>>> for Field<E> {
+    type Case = Mode;
+
+    /// Returns the number of constants, public variables, private variables, and constraints
+    /// to enforce `self < ScalarField::MODULUS`, for the given mode of `self`.
+    fn count(case: &Self::Case) -> Count {
+        match case {
+            Mode::Constant => Count::is(0, 0, 0, 0),
+            // See `Field::assert_less_than_const`, for a bound with `ScalarField::MODULUS_BITS` significant bits.
+            _ => {
+                let num_bits = E::ScalarField::size_in_bits() as u64;
+                Count::less_than(0, 0, 2 * num_bits - 1, 2 * num_bits + 1)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::{assert_count_fails, Circuit};
+
+    const ITERATIONS: u64 = 100;
+
+    type CurrentField = console::Field<<Circuit as Environment>::Network>;
+
+    fn check_assert_is_valid_scalar(name: &str, value: CurrentField, mode: Mode) {
+        let a = Field::<Circuit>::new(mode, value);
+
+        Circuit::scope(name, || {
+            assert!(a.assert_is_valid_scalar().is_ok());
+            assert_count!(Field<Circuit>, AssertIsValidScalar<Output = Result<()>>, &mode);
+        });
+        Circuit::reset();
+    }
+
+    fn check_assert_is_valid_scalar_fails(name: &str, value: CurrentField, mode: Mode) {
+        let a = Field::<Circuit>::new(mode, value);
+
+        match mode.is_constant() {
+            true => assert!(a.assert_is_valid_scalar().is_err()),
+            false => {
+                Circuit::scope(name, || {
+                    assert!(a.assert_is_valid_scalar().is_ok());
+                    assert_count_fails!(Field<Circuit>, AssertIsValidScalar<Output = Result<()>>, &mode);
+                });
+            }
+        }
+        Circuit::reset();
+    }
+
+    fn run_test(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        let modulus = Field::<Circuit>::scalar_modulus().unwrap();
+
+        for i in 0..ITERATIONS {
+            // Sample a random value below the scalar field modulus, and one at or above it.
+            let offset = CurrentField::from_u64(u64::rand(&mut rng));
+            check_assert_is_valid_scalar(&format!("{mode} {offset} ({i})"), offset, mode);
+            check_assert_is_valid_scalar(
+                &format!("{mode} -{offset} ({i})"),
+                modulus - CurrentField::one() - offset,
+                mode,
+            );
+            check_assert_is_valid_scalar_fails(&format!("{mode} +{offset} ({i})"), modulus + offset, mode);
+        }
+
+        // Check the boundary cases.
+        check_assert_is_valid_scalar(&format!("{mode} 0"), CurrentField::zero(), mode);
+        check_assert_is_valid_scalar(&format!("{mode} MODULUS - 1"), modulus - CurrentField::one(), mode);
+        check_assert_is_valid_scalar_fails(&format!("{mode} MODULUS"), modulus, mode);
+        check_assert_is_valid_scalar_fails(&format!("{mode} -1"), -CurrentField::one(), mode);
+    }
+
+    #[test]
+    fn test_assert_is_valid_scalar_constant() {
+        run_test(Mode::Constant);
+    }
+
+    #[test]
+    fn test_assert_is_valid_scalar_public() {
+        run_test(Mode::Public);
+    }
+
+    #[test]
+    fn test_assert_is_valid_scalar_private() {
+        run_test(Mode::Private);
+    }
+}
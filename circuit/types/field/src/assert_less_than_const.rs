@@ -0,0 +1,192 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> AssertLessThanConst<console::Field<E::Network>> for Field<E> {
+    /// Enforces that `self` is less than the given constant `bound`, i.e. `0 <= self < bound`,
+    /// where `self` is interpreted as an integer in `[0, BaseField::MODULUS)`.
+    ///
+    /// This method halts if `bound` is zero, or if `self` is a constant that is not less than `bound`.
+    fn assert_less_than_const(&self, bound: console::Field<E::Network>) {
+        // Ensure the bound is nonzero, as no field element is less than zero.
+        if bound.is_zero() {
+            E::halt("Attempted to assert that a field element is less than zero")
+        }
+
+        // Case 1: Constant < Constant
+        if self.is_constant() {
+            if self.eject_value() >= bound {
+                E::halt(format!("Field element {} is not less than {bound}", self.eject_value()))
+            }
+        }
+        // Case 2: Variable < Constant
+        else {
+            // Retrieve the significant bits of the bound.
+            let bound_bits_le = bound.to_bits_le();
+            let num_bits = bound_bits_le.iter().rposition(|bit| *bit).map_or(0, |index| index + 1);
+
+            // Decompose `self` into its lower `num_bits` bits, which enforces that `self < 2^num_bits`.
+            // Note: These bits need not be canonical, as any non-canonical decomposition encodes
+            // a value of at least `BaseField::MODULUS`, which fails the comparison below.
+            let bits_le = self.to_lower_bits_le(num_bits);
+
+            // Compute `bits_le < bound`.
+            // See `Compare::is_less_than` for the truth table and description of the logic.
+            let is_less_than = bits_le.iter().zip_eq(&bound_bits_le[..num_bits]).fold(
+                Boolean::constant(false),
+                |is_less_than, (this, that)| match that {
+                    true => (!this).bitor(&is_less_than),
+                    false => (!this).bitand(&is_less_than),
+                },
+            );
+
+            // Ensure `self` is less than `bound`.
+            E::assert(is_less_than);
+        }
+    }
+}
+
+impl<E: Environment> Metrics<dyn AssertLessThanConst<console::Field<E::Network>>> for Field<E> {
+    type Case = (Mode, u64);
+
+    /// Returns the number of constants, public variables, private variables, and constraints
+    /// to enforce `self < bound`, for the given mode of `self` and the number of significant bits in `bound`.
+    fn count(case: &Self::Case) -> Count {
+        match case {
+            (Mode::Constant, _) => Count::is(0, 0, 0, 0),
+            // The bit decomposition costs `num_bits` private variables and `num_bits + 1` constraints,
+            // the comparison costs at most `num_bits - 1` private variables and constraints,
+            // and the assertion costs 1 constraint.
+            (_, num_bits) => Count::less_than(0, 0, 2 * num_bits - 1, 2 * num_bits + 1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::{assert_count_fails, Circuit};
+
+    const ITERATIONS: u64 = 100;
+
+    /// Returns the number of significant bits in the given bound.
+    fn num_bits(bound: &console::Field<<Circuit as Environment>::Network>) -> u64 {
+        bound.to_bits_le().iter().rposition(|bit| *bit).map_or(0, |index| index as u64 + 1)
+    }
+
+    fn check_assert_less_than_const(name: &str, value: u64, bound: u64, mode: Mode) {
+        let bound = console::Field::from_u64(bound);
+        let a = Field::<Circuit>::new(mode, console::Field::from_u64(value));
+
+        Circuit::scope(name, || {
+            a.assert_less_than_const(bound);
+            assert_count!(
+                Field<Circuit>,
+                AssertLessThanConst<console::Field<<Circuit as Environment>::Network>>,
+                &(mode, num_bits(&bound))
+            );
+        });
+        Circuit::reset();
+    }
+
+    fn check_assert_less_than_const_fails(name: &str, value: u64, bound: u64, mode: Mode) {
+        let bound = console::Field::from_u64(bound);
+        let a = Field::<Circuit>::new(mode, console::Field::from_u64(value));
+
+        match mode.is_constant() {
+            true => {
+                let result = std::panic::catch_unwind(|| a.assert_less_than_const(bound));
+                assert!(result.is_err());
+            }
+            false => {
+                Circuit::scope(name, || {
+                    a.assert_less_than_const(bound);
+                    assert_count_fails!(
+                        Field<Circuit>,
+                        AssertLessThanConst<console::Field<<Circuit as Environment>::Network>>,
+                        &(mode, num_bits(&bound))
+                    );
+                });
+            }
+        }
+        Circuit::reset();
+    }
+
+    fn run_test(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random bound, and a value on each side of it.
+            let bound = u64::rand(&mut rng).max(1);
+            let below = u64::rand(&mut rng) % bound;
+            let above = bound + (u64::rand(&mut rng) % (u64::MAX - bound + 1));
+
+            check_assert_less_than_const(&format!("{mode} {below} < {bound} ({i})"), below, bound, mode);
+            check_assert_less_than_const_fails(&format!("{mode} {above} < {bound} ({i})"), above, bound, mode);
+        }
+
+        // Check the boundary cases.
+        for bound in [1, 2, 3, 255, 256, 1 << 32, u64::MAX] {
+            check_assert_less_than_const(&format!("{mode} 0 < {bound}"), 0, bound, mode);
+            check_assert_less_than_const(&format!("{mode} {} < {bound}", bound - 1), bound - 1, bound, mode);
+            check_assert_less_than_const_fails(&format!("{mode} {bound} < {bound}"), bound, bound, mode);
+        }
+    }
+
+    #[test]
+    fn test_assert_less_than_const_constant() {
+        run_test(Mode::Constant);
+    }
+
+    #[test]
+    fn test_assert_less_than_const_public() {
+        run_test(Mode::Public);
+    }
+
+    #[test]
+    fn test_assert_less_than_const_private() {
+        run_test(Mode::Private);
+    }
+
+    #[test]
+    fn test_assert_less_than_zero_halts() {
+        let zero = console::Field::<<Circuit as Environment>::Network>::zero();
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            let result = std::panic::catch_unwind(|| Field::<Circuit>::new(mode, zero).assert_less_than_const(zero));
+            assert!(result.is_err());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_assert_less_than_const_large_bound() {
+        // Ensure a value below a bound spanning the full field is satisfiable.
+        let bound = -console::Field::<<Circuit as Environment>::Network>::one();
+        let value = bound - console::Field::one();
+        for mode in [Mode::Public, Mode::Private] {
+            Circuit::scope(format!("{mode}"), || {
+                Field::<Circuit>::new(mode, value).assert_less_than_const(bound);
+                assert!(Circuit::is_satisfied_in_scope());
+            });
+            Circuit::scope(format!("{mode} fails"), || {
+                Field::<Circuit>::new(mode, bound).assert_less_than_const(bound);
+                assert!(!Circuit::is_satisfied_in_scope());
+            });
+            Circuit::reset();
+        }
+    }
+}
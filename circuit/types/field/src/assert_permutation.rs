@@ -0,0 +1,153 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> AssertPermutation for Field<E> {
+    type Output = Result<()>;
+
+    /// Enforces that `b` is a permutation of `a`, with the grand-product argument
+    /// `(challenge - a[0]) * ... * (challenge - a[n - 1]) == (challenge - b[0]) * ... * (challenge - b[n - 1])`.
+    ///
+    /// As both sides are polynomials of degree `n` in the challenge, a non-permutation satisfies the equality
+    /// with probability at most `n / |F|`. As such, the challenge must be sampled uniformly at random after
+    /// `a` and `b` are fixed, e.g. by hashing `a` and `b`, for this method to be sound.
+    ///
+    /// This method errors if `a` and `b` differ in length, and halts if both products are unequal constants.
+    fn assert_permutation(a: &[Field<E>], b: &[Field<E>], challenge: &Field<E>) -> Self::Output {
+        // Ensure the slices are of equal length.
+        ensure!(a.len() == b.len(), "Cannot assert a permutation of {} and {} field elements", a.len(), b.len());
+
+        // Compute the grand product `(challenge - values[0]) * ... * (challenge - values[n - 1])`.
+        let grand_product =
+            |values: &[Field<E>]| values.iter().fold(Field::one(), |product, value| product * (challenge - value));
+        // Enforce the grand products of `a` and `b` are equal.
+        E::assert_eq(grand_product(a), grand_product(b));
+        Ok(())
+    }
+}
+
+impl<E: Environment> Metrics<dyn AssertPermutation<Output = Result<()>>> for Field<E> {
+    /// The modes of `a`, `b`, and `challenge`, and the length of `a` and `b`.
+    type Case = (Mode, Mode, Mode, usize);
+
+    fn count(case: &Self::Case) -> Count {
+        let (mode_a, mode_b, mode_challenge, length) = case;
+        // A grand product is a constant if its values and the challenge are constants.
+        // Otherwise, the first factor is multiplied by one, for free, and each other factor costs 1 multiplication.
+        let product_count = |mode: &Mode| match mode.is_constant() && mode_challenge.is_constant() {
+            true => (true, 0),
+            false => (false, length.saturating_sub(1) as u64),
+        };
+        match (product_count(mode_a), product_count(mode_b)) {
+            // If the slices are empty, both grand products are the constant one.
+            _ if *length == 0 => Count::is(0, 0, 0, 0),
+            ((true, _), (true, _)) => Count::is(0, 0, 0, 0),
+            // The equality assertion costs 1 constraint.
+            ((_, num_a), (_, num_b)) => Count::is(0, 0, num_a + num_b, num_a + num_b + 1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::{assert_count_fails, Circuit};
+
+    const ITERATIONS: u64 = 10;
+
+    fn check_assert_permutation(
+        mode_a: Mode,
+        mode_b: Mode,
+        mode_challenge: Mode,
+        length: usize,
+        rng: &mut TestRng,
+    ) -> Result<()> {
+        for i in 0..ITERATIONS {
+            let given = (0..length).map(|_| Uniform::rand(rng)).collect::<Vec<_>>();
+            let challenge = Field::<Circuit>::new(mode_challenge, Uniform::rand(rng));
+            let case = (mode_a, mode_b, mode_challenge, length);
+
+            // Permute the values, by rotating them.
+            let mut permuted = given.clone();
+            permuted.rotate_left(i as usize % length.max(1));
+
+            let a = given.iter().map(|value| Field::<Circuit>::new(mode_a, *value)).collect::<Vec<_>>();
+            let b = permuted.iter().map(|value| Field::<Circuit>::new(mode_b, *value)).collect::<Vec<_>>();
+
+            // Ensure a permutation is satisfiable.
+            Circuit::scope(format!("{mode_a} {mode_b} {mode_challenge} {length} {i}"), || {
+                Field::assert_permutation(&a, &b, &challenge)?;
+                assert_count!(Field<Circuit>, AssertPermutation<Output = Result<()>>, &case);
+                Ok::<_, Error>(())
+            })?;
+            Circuit::reset();
+
+            if length == 0 {
+                continue;
+            }
+
+            // Change a single element of `b`, such that it is no longer a permutation of `a`.
+            let mut b = b;
+            let index = i as usize % length;
+            b[index] = Field::new(mode_b, permuted[index] + console::Field::one());
+
+            match (mode_a, mode_b, mode_challenge) {
+                // Ensure a violated constant assertion halts.
+                (Mode::Constant, Mode::Constant, Mode::Constant) => {
+                    let result = std::panic::catch_unwind(|| Field::assert_permutation(&a, &b, &challenge));
+                    assert!(result.is_err());
+                }
+                // Ensure a violated assertion is unsatisfiable.
+                _ => Circuit::scope(format!("{mode_a} {mode_b} {mode_challenge} {length} {i} (differs)"), || {
+                    Field::assert_permutation(&a, &b, &challenge)?;
+                    assert_count_fails!(Field<Circuit>, AssertPermutation<Output = Result<()>>, &case);
+                    Ok::<_, Error>(())
+                })?,
+            }
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_assert_permutation() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                for mode_challenge in [Mode::Constant, Mode::Public, Mode::Private] {
+                    for length in [0, 1, 2, 8] {
+                        check_assert_permutation(mode_a, mode_b, mode_challenge, length, &mut rng)?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_assert_permutation_fails() {
+        let a = vec![Field::<Circuit>::new(Mode::Private, console::Field::one()); 2];
+        let b = vec![Field::<Circuit>::new(Mode::Private, console::Field::one()); 3];
+        let challenge = Field::<Circuit>::new(Mode::Private, console::Field::one());
+
+        // Ensure slices of different lengths fail.
+        assert!(Field::assert_permutation(&a, &b, &challenge).is_err());
+        assert!(Field::assert_permutation(&b, &a, &challenge).is_err());
+        assert!(Field::assert_permutation(&a, &[], &challenge).is_err());
+    }
+}
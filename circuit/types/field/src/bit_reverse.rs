@@ -0,0 +1,102 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Field<E> {
+    /// Returns the given values reordered by bit-reversing their indices, such that the value at index `i`
+    /// moves to the index whose `log2(values.len())` bits are those of `i` in reverse order.
+    /// As this is a pure rearrangement, it incurs no constraints.
+    ///
+    /// This method errors if the number of values is not a power of two.
+    pub fn bit_reverse_permute(values: &[Field<E>]) -> Result<Vec<Field<E>>> {
+        // Ensure the number of values is a power of two.
+        ensure!(
+            values.len().is_power_of_two(),
+            "Cannot bit-reverse {} values, as it is not a power of two",
+            values.len()
+        );
+
+        // Retrieve the number of bits in each index.
+        let num_bits = values.len().trailing_zeros();
+        // Note: As `usize::reverse_bits` reverses all of the bits, shift the result down to `num_bits` bits.
+        let reverse = |index: usize| match num_bits {
+            0 => index,
+            _ => index.reverse_bits() >> (usize::BITS - num_bits),
+        };
+
+        Ok((0..values.len()).map(|index| values[reverse(index)].clone()).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    fn check_bit_reverse_permute(mode: Mode, expected_order: &[usize], rng: &mut TestRng) -> Result<()> {
+        // Sample random values.
+        let given = (0..expected_order.len()).map(|_| Uniform::rand(rng)).collect::<Vec<_>>();
+        let values = given.iter().map(|value| Field::<Circuit>::new(mode, *value)).collect::<Vec<_>>();
+
+        Circuit::scope(format!("{mode} {}", expected_order.len()), || {
+            let candidate = Field::bit_reverse_permute(&values)?;
+            assert_eq!(expected_order.len(), candidate.len());
+            for (index, candidate) in expected_order.iter().zip_eq(&candidate) {
+                assert_eq!(given[*index], candidate.eject_value());
+            }
+            assert_scope!(0, 0, 0, 0);
+            Ok::<_, Error>(())
+        })?;
+        Circuit::reset();
+        Ok(())
+    }
+
+    #[test]
+    fn test_bit_reverse_permute() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            check_bit_reverse_permute(mode, &[0], &mut rng)?;
+            check_bit_reverse_permute(mode, &[0, 1], &mut rng)?;
+            check_bit_reverse_permute(mode, &[0, 2, 1, 3], &mut rng)?;
+            check_bit_reverse_permute(mode, &[0, 4, 2, 6, 1, 5, 3, 7], &mut rng)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_bit_reverse_permute_is_involution() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Ensure permuting twice restores the original order.
+        let values = (0..16).map(|_| Field::<Circuit>::new(Mode::Private, Uniform::rand(&mut rng))).collect::<Vec<_>>();
+        let candidate = Field::bit_reverse_permute(&Field::bit_reverse_permute(&values)?)?;
+        assert_eq!(values.eject_value(), candidate.eject_value());
+        Ok(())
+    }
+
+    #[test]
+    fn test_bit_reverse_permute_fails() {
+        let one = Field::<Circuit>::one();
+
+        // Ensure a number of values that is not a power of two is rejected.
+        assert!(Field::bit_reverse_permute(&[]).is_err());
+        for num_values in [3, 5, 6, 7, 12] {
+            assert!(Field::bit_reverse_permute(&vec![one.clone(); num_values]).is_err());
+        }
+    }
+}
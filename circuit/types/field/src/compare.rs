@@ -92,7 +92,72 @@ impl<E: Environment> Compare<Field<E>> for Field<E> {
     }
 }
 
-// TODO: Implement `Metrics` and `OutputMode` for `Compare`. Waiting for PR#711  to land as it significantly changes the counts.
+impl<E: Environment> Field<E> {
+    /// Returns `true` if `self` is less than `other`, where the bits of each variable are enforced to be canonical.
+    ///
+    /// Note: `Compare::is_less_than` only enforces that the (cached) bits of each operand sum to its value,
+    /// such that a prover may witness the bits of `self + BaseField::MODULUS` instead, to flip the result.
+    pub(crate) fn is_less_than_canonical(&self, other: &Self) -> Boolean<E> {
+        // Ensure the bits of each variable are canonical, i.e. less than `BaseField::MODULUS`.
+        for field in [self, other] {
+            if !field.is_constant() {
+                field.to_bits_le_with_proof();
+            }
+        }
+        self.is_less_than(other)
+    }
+}
+
+impl<E: Environment> CompareWithEquality<Field<E>> for Field<E> {
+    type Boolean = Boolean<E>;
+
+    /// Returns `(is_less_than, is_equal)` for `self` and `other`, from which the remaining orderings follow,
+    /// e.g. `self > other` is `!(is_less_than | is_equal)`.
+    ///
+    /// The ordering is computed from the (cached) bit decompositions of `self` and `other`, which are enforced
+    /// to be canonical, and are shared with any other comparison of them, while the equality is computed
+    /// from their difference, without bits.
+    fn compare(&self, other: &Field<E>) -> (Self::Boolean, Self::Boolean) {
+        // Case 1: Constant <=> Constant
+        if self.is_constant() && other.is_constant() {
+            let (this, that) = (self.eject_value(), other.eject_value());
+            (Boolean::constant(this < that), Boolean::constant(this == that))
+        }
+        // Case 2: Constant <=> Variable, Variable <=> Constant, or Variable <=> Variable
+        else {
+            (self.is_less_than_canonical(other), self.is_equal(other))
+        }
+    }
+}
+
+impl<E: Environment> Metrics<dyn CompareWithEquality<Field<E>, Boolean = Boolean<E>>> for Field<E> {
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        match case {
+            (Mode::Constant, Mode::Constant) => Count::is(0, 0, 0, 0),
+            // The canonicity check on the variable costs 252 private variables and 253 constraints,
+            // the ordering against a constant costs at most 253 constants, 506 private variables, and 507 constraints,
+            // and the equality costs 2 private variables and 3 constraints.
+            (Mode::Constant, _) | (_, Mode::Constant) => Count::less_than(253, 0, 760, 763),
+            // The canonicity checks cost 504 private variables and 506 constraints,
+            // the ordering costs 1012 private variables and 1014 constraints,
+            // and the equality costs 2 private variables and 3 constraints.
+            (_, _) => Count::is(0, 0, 1518, 1523),
+        }
+    }
+}
+
+impl<E: Environment> OutputMode<dyn CompareWithEquality<Field<E>, Boolean = Boolean<E>>> for Field<E> {
+    type Case = (Mode, Mode);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case {
+            (Mode::Constant, Mode::Constant) => Mode::Constant,
+            _ => Mode::Private,
+        }
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -164,6 +229,66 @@ mod tests {
         }
     }
 
+    fn check_compare(mode_a: Mode, mode_b: Mode, rng: &mut TestRng) {
+        for i in 0..ITERATIONS {
+            let first: console::Field<<Circuit as Environment>::Network> = Uniform::rand(rng);
+            let second: console::Field<<Circuit as Environment>::Network> = Uniform::rand(rng);
+
+            // Check each ordering of the operands.
+            let (low, high) = (first.min(second), first.max(second));
+            for (name, this, that) in [("less", low, high), ("equal", low, low), ("greater", high, low)] {
+                let a = Field::<Circuit>::new(mode_a, this);
+                let b = Field::<Circuit>::new(mode_b, that);
+
+                Circuit::scope(format!("{mode_a} {mode_b} {name} {i}"), || {
+                    let (is_less_than, is_equal) = a.compare(&b);
+                    assert_eq!(this < that, is_less_than.eject_value());
+                    assert_eq!(this == that, is_equal.eject_value());
+                    assert_eq!(this > that, !is_less_than.eject_value() && !is_equal.eject_value());
+
+                    let case = (mode_a, mode_b);
+                    assert_count!(
+                        Field<Circuit>,
+                        CompareWithEquality<Field<Circuit>, Boolean = Boolean<Circuit>>,
+                        &case
+                    );
+                    let output_mode = output_mode!(
+                        Field<Circuit>,
+                        CompareWithEquality<Field<Circuit>, Boolean = Boolean<Circuit>>,
+                        &case
+                    );
+                    assert_eq!(output_mode, is_less_than.eject_mode());
+                    assert_eq!(output_mode, is_equal.eject_mode());
+                });
+                Circuit::reset();
+            }
+        }
+    }
+
+    #[test]
+    fn test_compare() {
+        let mut rng = TestRng::default();
+
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                check_compare(mode_a, mode_b, &mut rng);
+            }
+        }
+    }
+
+    #[test]
+    fn test_compare_forged_bits() {
+        // Sample `0` with the forged bits of `BaseField::MODULUS`, which would order it above `1`.
+        let a = crate::helpers::to_bits_with_proof::test_helpers::sample_zero_with_forged_bits();
+        let b = Field::<Circuit>::new(Mode::Private, console::Field::one());
+        assert!(Circuit::is_satisfied());
+
+        // Ensure the forged bits are rejected, as they are not less than the base field modulus.
+        let (_is_less_than, _is_equal) = a.compare(&b);
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
     #[test]
     fn test_constant_is_less_than_constant() {
         run_test(Mode::Constant, Mode::Constant, 0, 0, 0, 0);
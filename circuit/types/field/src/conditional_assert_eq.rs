@@ -0,0 +1,109 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> ConditionalAssertEq for Field<E> {
+    type Boolean = Boolean<E>;
+
+    /// Enforces that `a == b` if `condition` is `true`, and enforces nothing if `condition` is `false`.
+    /// This is enforced with the single constraint `condition * (a - b) == 0`.
+    ///
+    /// This method halts if `condition`, `a`, and `b` are constants, `condition` is `true`, and `a != b`.
+    fn conditional_assert_eq(condition: &Boolean<E>, a: &Field<E>, b: &Field<E>) {
+        // Ensure `condition * (a - b) == 0`.
+        E::enforce(|| (condition, a - b, E::zero()));
+    }
+}
+
+impl<E: Environment> Metrics<dyn ConditionalAssertEq<Boolean = Boolean<E>>> for Field<E> {
+    type Case = (Mode, Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        match case {
+            (Mode::Constant, Mode::Constant, Mode::Constant) => Count::is(0, 0, 0, 0),
+            _ => Count::is(0, 0, 0, 1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::{assert_count_fails, Circuit};
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_conditional_assert_eq(
+        name: &str,
+        condition: bool,
+        first: console::Field<<Circuit as Environment>::Network>,
+        second: console::Field<<Circuit as Environment>::Network>,
+        mode_condition: Mode,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let condition = Boolean::<Circuit>::new(mode_condition, condition);
+        let a = Field::<Circuit>::new(mode_a, first);
+        let b = Field::<Circuit>::new(mode_b, second);
+        let case = (mode_condition, mode_a, mode_b);
+
+        match (condition.eject_value() && first != second, case) {
+            // Ensure a violated constant assertion halts.
+            (true, (Mode::Constant, Mode::Constant, Mode::Constant)) => {
+                let result = std::panic::catch_unwind(|| Field::conditional_assert_eq(&condition, &a, &b));
+                assert!(result.is_err());
+            }
+            // Ensure a violated assertion is unsatisfiable.
+            (true, _) => Circuit::scope(name, || {
+                Field::conditional_assert_eq(&condition, &a, &b);
+                assert_count_fails!(Field<Circuit>, ConditionalAssertEq<Boolean = Boolean<Circuit>>, &case);
+            }),
+            // Ensure an assertion that holds, or is disabled, is satisfiable.
+            (false, _) => Circuit::scope(name, || {
+                Field::conditional_assert_eq(&condition, &a, &b);
+                assert_count!(Field<Circuit>, ConditionalAssertEq<Boolean = Boolean<Circuit>>, &case);
+            }),
+        }
+        Circuit::reset();
+    }
+
+    fn run_test(mode_condition: Mode, mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+
+            for condition in [true, false] {
+                let name = format!("{mode_condition} {mode_a} {mode_b} {condition} {i}");
+                check_conditional_assert_eq(&name, condition, first, first, mode_condition, mode_a, mode_b);
+                check_conditional_assert_eq(&name, condition, first, second, mode_condition, mode_a, mode_b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_conditional_assert_eq() {
+        for mode_condition in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+                for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                    run_test(mode_condition, mode_a, mode_b);
+                }
+            }
+        }
+    }
+}
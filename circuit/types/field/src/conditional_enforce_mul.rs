@@ -0,0 +1,117 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> ConditionalEnforceMul for Field<E> {
+    type Boolean = Boolean<E>;
+
+    /// Enforces that `a * b == product` if `condition` is `true`, and enforces nothing if `condition` is `false`.
+    /// This is enforced with the constraint `condition * (a * b - product) == 0`, where `a * b` costs
+    /// an additional constraint, unless `a` or `b` is a constant.
+    ///
+    /// This method halts if every input is a constant, `condition` is `true`, and `a * b != product`.
+    fn conditional_enforce_mul(condition: &Self::Boolean, a: &Field<E>, b: &Field<E>, product: &Field<E>) {
+        // Ensure `condition * (a * b - product) == 0`.
+        E::enforce(|| (condition, (a * b) - product, E::zero()));
+    }
+}
+
+impl<E: Environment> Metrics<dyn ConditionalEnforceMul<Boolean = Boolean<E>>> for Field<E> {
+    /// The modes of `condition`, `a`, `b`, and `product`.
+    type Case = (Mode, Mode, Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        match case {
+            (Mode::Constant, Mode::Constant, Mode::Constant, Mode::Constant) => Count::is(0, 0, 0, 0),
+            (_, Mode::Constant, _, _) | (_, _, Mode::Constant, _) => Count::is(0, 0, 0, 1),
+            (_, _, _, _) => Count::is(0, 0, 1, 2),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::{assert_count_fails, Circuit};
+
+    const ITERATIONS: u64 = 10;
+
+    fn check_conditional_enforce_mul(
+        name: &str,
+        condition: bool,
+        (first, second, product): (
+            console::Field<<Circuit as Environment>::Network>,
+            console::Field<<Circuit as Environment>::Network>,
+            console::Field<<Circuit as Environment>::Network>,
+        ),
+        case: (Mode, Mode, Mode, Mode),
+    ) {
+        let (mode_condition, mode_a, mode_b, mode_product) = case;
+        let condition = Boolean::<Circuit>::new(mode_condition, condition);
+        let a = Field::<Circuit>::new(mode_a, first);
+        let b = Field::<Circuit>::new(mode_b, second);
+        let c = Field::<Circuit>::new(mode_product, product);
+
+        match (condition.eject_value() && first * second != product, case) {
+            // Ensure a violated constant constraint halts.
+            (true, (Mode::Constant, Mode::Constant, Mode::Constant, Mode::Constant)) => {
+                let result = std::panic::catch_unwind(|| Field::conditional_enforce_mul(&condition, &a, &b, &c));
+                assert!(result.is_err());
+            }
+            // Ensure a violated constraint is unsatisfiable.
+            (true, _) => Circuit::scope(name, || {
+                Field::conditional_enforce_mul(&condition, &a, &b, &c);
+                assert_count_fails!(ConditionalEnforceMul<Boolean>() => Field, &case);
+            }),
+            // Ensure a constraint that holds, or is disabled, is satisfiable.
+            (false, _) => Circuit::scope(name, || {
+                Field::conditional_enforce_mul(&condition, &a, &b, &c);
+                assert_count!(ConditionalEnforceMul<Boolean>() => Field, &case);
+            }),
+        }
+        Circuit::reset();
+    }
+
+    fn run_test(case: (Mode, Mode, Mode, Mode)) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+            let wrong = Uniform::rand(&mut rng);
+
+            for condition in [true, false] {
+                let name = format!("{case:?} {condition} {i}");
+                check_conditional_enforce_mul(&name, condition, (first, second, first * second), case);
+                check_conditional_enforce_mul(&name, condition, (first, second, wrong), case);
+            }
+        }
+    }
+
+    #[test]
+    fn test_conditional_enforce_mul() {
+        for mode_condition in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+                for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                    for mode_product in [Mode::Constant, Mode::Public, Mode::Private] {
+                        run_test((mode_condition, mode_a, mode_b, mode_product));
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,129 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> ConstBasePow<console::Field<E::Network>> for Field<E> {
+    type Boolean = Boolean<E>;
+    type Output = Field<E>;
+
+    /// Returns `base^exponent`, for a constant `base` and an exponent given by its little-endian bits.
+    ///
+    /// As the base is a constant, the powers `base^(2^i)` are precomputed as constants, and each bit selects
+    /// its factor as `1 + bit * (base^(2^i) - 1)`, which is a linear combination of the bit, for free.
+    /// As such, this method incurs one multiplication for each variable bit after the first, which is fewer
+    /// constraints than `pow`, which squares and selects with a ternary for each bit of the exponent.
+    fn const_base_pow(base: console::Field<E::Network>, exp_bits: &[Self::Boolean]) -> Self::Output {
+        let one = console::Field::<E::Network>::one();
+
+        // Initialize the output, and the power `base^(2^i)`.
+        let mut output = Field::one();
+        let mut power = base;
+        for bit in exp_bits {
+            // Compute the factor as `1 + bit * (base^(2^i) - 1)`.
+            let factor: Field<E> = (&Field::from_boolean(bit).linear_combination * *(power - one)).into();
+            // Set the output to `output * factor`.
+            output *= factor.add_constant(one);
+            // Compute the next power `base^(2^(i + 1))`.
+            power = power.square();
+        }
+        output
+    }
+}
+
+impl<E: Environment> Metrics<dyn ConstBasePow<console::Field<E::Network>, Boolean = Boolean<E>, Output = Field<E>>>
+    for Field<E>
+{
+    /// The mode of the exponent bits, and the number of exponent bits.
+    type Case = (Mode, u64);
+
+    fn count(case: &Self::Case) -> Count {
+        match case {
+            (Mode::Constant, _) => Count::is(0, 0, 0, 0),
+            (_, num_bits) => Count::is(0, 0, num_bits.saturating_sub(1), num_bits.saturating_sub(1)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 10;
+
+    fn check_const_base_pow(mode: Mode, num_bits: usize, rng: &mut TestRng) {
+        for i in 0..ITERATIONS {
+            // Sample the base and the exponent.
+            let base: console::Field<<Circuit as Environment>::Network> = Uniform::rand(rng);
+            let exponent: console::Field<<Circuit as Environment>::Network> = Uniform::rand(rng);
+            let exponent = console::Field::from_bits_le(&exponent.to_bits_le()[..num_bits]).unwrap();
+            let exp_bits =
+                exponent.to_bits_le()[..num_bits].iter().map(|bit| Boolean::new(mode, *bit)).collect::<Vec<_>>();
+
+            Circuit::scope(format!("{mode} {num_bits} {i}"), || {
+                let candidate = Field::const_base_pow(base, &exp_bits);
+                assert_eq!(base.pow(exponent), candidate.eject_value());
+                assert_count!(
+                    Field<Circuit>,
+                    ConstBasePow<
+                        console::Field<<Circuit as Environment>::Network>,
+                        Boolean = Boolean<Circuit>,
+                        Output = Field<Circuit>,
+                    >,
+                    &(mode, num_bits as u64)
+                );
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_const_base_pow() {
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for num_bits in [0, 1, 2, 8, 64, console::Field::<<Circuit as Environment>::Network>::size_in_bits()] {
+                check_const_base_pow(mode, num_bits, &mut rng);
+            }
+        }
+    }
+
+    #[test]
+    fn test_const_base_pow_is_cheaper_than_pow() {
+        let mut rng = TestRng::default();
+
+        // Sample a constant base, and a variable exponent.
+        let base: console::Field<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+        let exponent = Field::<Circuit>::new(Mode::Private, Uniform::rand(&mut rng));
+
+        // Compute the power with `pow` and with `const_base_pow`, and ensure they match.
+        let expected = Circuit::scope("pow", || {
+            let output = Field::new(Mode::Constant, base).pow(&exponent);
+            (output, Circuit::num_constraints_in_scope())
+        });
+        let candidate = Circuit::scope("const_base_pow", || {
+            let output = Field::const_base_pow(base, &exponent.to_bits_le());
+            (output, Circuit::num_constraints_in_scope())
+        });
+        assert_eq!(expected.0.eject_value(), candidate.0.eject_value());
+        assert!(Circuit::is_satisfied());
+
+        // Ensure `const_base_pow` incurs fewer constraints, including the cost of decomposing the exponent.
+        assert!(candidate.1 < expected.1, "{} >= {}", candidate.1, expected.1);
+        Circuit::reset();
+    }
+}
@@ -0,0 +1,238 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> DivRem<Field<E>> for Field<E> {
+    type Output = Result<(Field<E>, Field<E>)>;
+
+    /// Returns the quotient and remainder `(q, r)` of the integer division of `self` by `divisor`,
+    /// such that `self == q * divisor + r`, with `0 <= r < divisor`, and `q`, `r`, and `divisor` all fit in `num_bits`.
+    ///
+    /// As `q * divisor + r < 2^(2 * num_bits)`, the identity holds over the integers if `2 * num_bits` is at most
+    /// the number of data bits in the base field. If any bound does not hold, the circuit is unsatisfiable
+    /// for variable inputs, and errors for constant inputs. In particular, a variable zero divisor is unsatisfiable.
+    ///
+    /// This method errors if `2 * num_bits` exceeds the number of data bits in the base field.
+    fn div_rem(&self, divisor: &Field<E>, num_bits: usize) -> Self::Output {
+        // Ensure the product of the quotient and divisor can not wrap around the modulus.
+        let num_data_bits = E::BaseField::size_in_data_bits();
+        ensure!(
+            2 * num_bits <= num_data_bits,
+            "Attempted to divide {num_bits}-bit integers, with {num_data_bits} data bits"
+        );
+
+        // Ensure a constant divisor is nonzero, and fits in `num_bits`.
+        if divisor.is_constant() {
+            let divisor = divisor.eject_value();
+            ensure!(!divisor.is_zero(), "Attempted to divide by zero");
+            ensure!(
+                divisor.to_bits_le()[num_bits..].iter().all(|bit| !bit),
+                "The divisor {divisor} does not fit in {num_bits} bits"
+            );
+        }
+
+        // Case 1: Constant / Constant
+        if self.is_constant() && divisor.is_constant() {
+            let (quotient, remainder) = Self::div_rem_values(self.eject_value(), divisor.eject_value());
+            ensure!(
+                quotient.to_bits_le()[num_bits..].iter().all(|bit| !bit),
+                "The quotient {quotient} does not fit in {num_bits} bits"
+            );
+            Ok((Field::new(Mode::Constant, quotient), Field::new(Mode::Constant, remainder)))
+        }
+        // Case 2: Variable / Constant or Variable / Variable
+        else {
+            // Witness the quotient and remainder.
+            let quotient: Field<E> = witness!(|self, divisor| Self::div_rem_values(self, divisor).0);
+            let remainder: Field<E> = witness!(|self, divisor| Self::div_rem_values(self, divisor).1);
+
+            // Ensure `quotient` and `remainder` fit in `num_bits`.
+            quotient.to_lower_bits_le(num_bits);
+            remainder.to_lower_bits_le(num_bits);
+            // Ensure a variable `divisor` fits in `num_bits`.
+            if !divisor.is_constant() {
+                divisor.to_lower_bits_le(num_bits);
+            }
+            // Ensure `remainder < divisor`, by enforcing `divisor - remainder - 1` fits in `num_bits`.
+            (&(divisor - &remainder) - &Field::one()).to_lower_bits_le(num_bits);
+
+            // Ensure `quotient * divisor == self - remainder`.
+            E::enforce(|| (&quotient, divisor, self - &remainder));
+
+            Ok((quotient, remainder))
+        }
+    }
+}
+
+impl<E: Environment> Field<E> {
+    /// Returns the quotient and remainder of the integer division of `dividend` by `divisor`, using long division.
+    /// If `divisor` is zero, this returns `(0, dividend)`.
+    pub(crate) fn div_rem_values(
+        dividend: console::Field<E::Network>,
+        divisor: console::Field<E::Network>,
+    ) -> (console::Field<E::Network>, console::Field<E::Network>) {
+        let (mut quotient, mut remainder) = (console::Field::zero(), console::Field::zero());
+        if divisor.is_zero() {
+            return (quotient, dividend);
+        }
+        for bit in dividend.to_bits_le().into_iter().rev() {
+            // Shift the next bit of the dividend into the remainder.
+            remainder = remainder.double();
+            quotient = quotient.double();
+            if bit {
+                remainder += console::Field::one();
+            }
+            // Subtract the divisor from the remainder, if possible.
+            if remainder >= divisor {
+                remainder -= divisor;
+                quotient += console::Field::one();
+            }
+        }
+        (quotient, remainder)
+    }
+}
+
+impl<E: Environment> Metrics<dyn DivRem<Field<E>, Output = Result<(Field<E>, Field<E>)>>> for Field<E> {
+    /// The modes of `self` and `divisor`, and the number of bits.
+    type Case = (Mode, Mode, u64);
+
+    fn count(case: &Self::Case) -> Count {
+        match case {
+            (Mode::Constant, Mode::Constant, _) => Count::is(2, 0, 0, 0),
+            // The quotient and remainder cost 2 private variables, the range checks on the quotient, the remainder,
+            // and the difference `divisor - remainder - 1` cost `num_bits` private variables and `num_bits + 1`
+            // constraints each, and the division identity costs 1 constraint.
+            (_, Mode::Constant, num_bits) => Count::is(0, 0, 3 * num_bits + 2, 3 * num_bits + 4),
+            // The range check on a variable divisor costs `num_bits` private variables and `num_bits + 1` constraints.
+            (_, _, num_bits) => Count::is(0, 0, 4 * num_bits + 2, 4 * num_bits + 5),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::{assert_count_fails, Circuit};
+
+    const ITERATIONS: u64 = 20;
+
+    fn check_div_rem(mode_a: Mode, mode_b: Mode, num_bits: usize, rng: &mut TestRng) {
+        for i in 0..ITERATIONS {
+            // Sample a nonzero divisor, and a quotient and remainder, that fit in `num_bits`.
+            let mask = u64::MAX >> (64 - num_bits);
+            let divisor = (u64::rand(rng) & mask).max(1);
+            let quotient = u64::rand(rng) & mask;
+            let remainder = u64::rand(rng) % divisor;
+            let dividend = quotient as u128 * divisor as u128 + remainder as u128;
+
+            let a = Field::<Circuit>::new(mode_a, console::Field::from_u128(dividend));
+            let b = Field::<Circuit>::new(mode_b, console::Field::from_u64(divisor));
+
+            Circuit::scope(format!("{mode_a} {mode_b} {num_bits} {i}"), || {
+                let (candidate_quotient, candidate_remainder) = a.div_rem(&b, num_bits).unwrap();
+                assert_eq!(console::Field::from_u64(quotient), candidate_quotient.eject_value());
+                assert_eq!(console::Field::from_u64(remainder), candidate_remainder.eject_value());
+                assert_count!(
+                    Field<Circuit>,
+                    DivRem<Field<Circuit>, Output = Result<(Field<Circuit>, Field<Circuit>)>>,
+                    &(mode_a, mode_b, num_bits as u64)
+                );
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_div_rem() {
+        let mut rng = TestRng::default();
+
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                for num_bits in [1, 8, 32, 64] {
+                    check_div_rem(mode_a, mode_b, num_bits, &mut rng);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_div_rem_out_of_range() {
+        let one = console::Field::one();
+
+        // Ensure a quotient that does not fit in `num_bits` is unsatisfiable.
+        let a = Field::<Circuit>::new(Mode::Private, console::Field::from_u64(1 << 8));
+        let b = Field::<Circuit>::new(Mode::Private, one);
+        Circuit::scope("div_rem quotient out of range", || {
+            assert!(a.div_rem(&b, 8).is_ok());
+            assert_count_fails!(
+                Field<Circuit>,
+                DivRem<Field<Circuit>, Output = Result<(Field<Circuit>, Field<Circuit>)>>,
+                &(Mode::Private, Mode::Private, 8)
+            );
+        });
+        Circuit::reset();
+
+        // Ensure a divisor that does not fit in `num_bits` is unsatisfiable.
+        let a = Field::<Circuit>::new(Mode::Private, one);
+        let b = Field::<Circuit>::new(Mode::Private, console::Field::from_u64(1 << 8));
+        Circuit::scope("div_rem divisor out of range", || {
+            assert!(a.div_rem(&b, 8).is_ok());
+            assert_count_fails!(
+                Field<Circuit>,
+                DivRem<Field<Circuit>, Output = Result<(Field<Circuit>, Field<Circuit>)>>,
+                &(Mode::Private, Mode::Private, 8)
+            );
+        });
+        Circuit::reset();
+
+        // Ensure a zero divisor is unsatisfiable.
+        let a = Field::<Circuit>::new(Mode::Private, one);
+        let b = Field::<Circuit>::new(Mode::Private, console::Field::zero());
+        Circuit::scope("div_rem by zero", || {
+            assert!(a.div_rem(&b, 8).is_ok());
+            assert_count_fails!(
+                Field<Circuit>,
+                DivRem<Field<Circuit>, Output = Result<(Field<Circuit>, Field<Circuit>)>>,
+                &(Mode::Private, Mode::Private, 8)
+            );
+        });
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_div_rem_errors() {
+        let one = Field::<Circuit>::new(Mode::Private, console::Field::one());
+
+        // Ensure a constant zero divisor errors.
+        let zero = Field::<Circuit>::new(Mode::Constant, console::Field::zero());
+        assert!(one.div_rem(&zero, 8).is_err());
+
+        // Ensure a constant divisor that does not fit in `num_bits` errors.
+        let b = Field::<Circuit>::new(Mode::Constant, console::Field::from_u64(1 << 8));
+        assert!(one.div_rem(&b, 8).is_err());
+
+        // Ensure a constant quotient that does not fit in `num_bits` errors.
+        let a = Field::<Circuit>::new(Mode::Constant, console::Field::from_u64(1 << 8));
+        let b = Field::<Circuit>::new(Mode::Constant, console::Field::one());
+        assert!(a.div_rem(&b, 8).is_err());
+
+        // Ensure a number of bits exceeding half the data capacity of the base field errors.
+        let num_bits = <Circuit as Environment>::BaseField::size_in_data_bits() / 2 + 1;
+        assert!(one.div_rem(&one, num_bits).is_err());
+        Circuit::reset();
+    }
+}
@@ -0,0 +1,206 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// A divisor whose inverse has been witnessed and constrained once,
+/// so that repeated divisions by it only cost a multiplication each.
+#[derive(Clone)]
+pub struct DivisorCache<E: Environment> {
+    /// The inverse of the divisor.
+    inverse: Field<E>,
+}
+
+impl<E: Environment> AsDivisor for Field<E> {
+    type Output = DivisorCache<E>;
+
+    /// Returns a divisor cache for `self`, which witnesses and constrains the inverse of `self` once.
+    fn as_divisor(&self) -> Self::Output {
+        match self.is_constant() {
+            // If `self` is a constant and zero, halt since the inverse of zero is undefined.
+            true if self.eject_value().is_zero() => E::halt("Attempted to divide by zero."),
+            // Otherwise, compute the inverse once, for 0 constraints if `self` is a constant, and 1 constraint otherwise.
+            _ => DivisorCache { inverse: self.inverse() },
+        }
+    }
+}
+
+impl<E: Environment> DivisorCache<E> {
+    /// Returns `numerator / divisor`, reusing the cached inverse of the divisor.
+    pub fn divide(&self, numerator: &Field<E>) -> Field<E> {
+        numerator / self
+    }
+}
+
+impl<E: Environment> Div<DivisorCache<E>> for Field<E> {
+    type Output = Field<E>;
+
+    /// Returns `self / divisor`, reusing the cached inverse of the divisor.
+    fn div(self, divisor: DivisorCache<E>) -> Self::Output {
+        self / &divisor
+    }
+}
+
+impl<E: Environment> Div<&DivisorCache<E>> for Field<E> {
+    type Output = Field<E>;
+
+    /// Returns `self / divisor`, reusing the cached inverse of the divisor.
+    fn div(self, divisor: &DivisorCache<E>) -> Self::Output {
+        &self / divisor
+    }
+}
+
+impl<E: Environment> Div<DivisorCache<E>> for &Field<E> {
+    type Output = Field<E>;
+
+    /// Returns `self / divisor`, reusing the cached inverse of the divisor.
+    fn div(self, divisor: DivisorCache<E>) -> Self::Output {
+        self / &divisor
+    }
+}
+
+impl<E: Environment> Div<&DivisorCache<E>> for &Field<E> {
+    type Output = Field<E>;
+
+    /// Returns `self / divisor`, reusing the cached inverse of the divisor.
+    fn div(self, divisor: &DivisorCache<E>) -> Self::Output {
+        self * &divisor.inverse
+    }
+}
+
+impl<E: Environment> Metrics<dyn AsDivisor<Output = DivisorCache<E>>> for Field<E> {
+    type Case = Mode;
+
+    fn count(case: &Self::Case) -> Count {
+        // The inverse is computed once, for 0 constraints if `self` is a constant, and 1 constraint otherwise.
+        <Self as Metrics<dyn Inverse<Output = Field<E>>>>::count(case)
+    }
+}
+
+impl<E: Environment> Metrics<dyn Div<DivisorCache<E>, Output = Field<E>>> for Field<E> {
+    /// The modes of the numerator and the divisor.
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        // Each division costs a single multiplication by the cached inverse, which is free if either is a constant.
+        <Self as Metrics<dyn Mul<Field<E>, Output = Field<E>>>>::count(case)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 10;
+    const NUM_DIVISIONS: u64 = 8;
+
+    fn check_divisor_cache(name: &str, mode_divisor: Mode, mode_numerator: Mode, rng: &mut TestRng) {
+        // Sample a non-zero divisor.
+        let divisor = loop {
+            let divisor: console::Field<<Circuit as Environment>::Network> = Uniform::rand(rng);
+            if !divisor.is_zero() {
+                break divisor;
+            }
+        };
+        let numerators: Vec<console::Field<<Circuit as Environment>::Network>> =
+            (0..NUM_DIVISIONS).map(|_| Uniform::rand(rng)).collect();
+
+        let expected: Vec<_> = numerators.iter().map(|numerator| *numerator / divisor).collect();
+
+        let b = Field::<Circuit>::new(mode_divisor, divisor);
+        let numerators: Vec<_> =
+            numerators.iter().map(|numerator| Field::<Circuit>::new(mode_numerator, *numerator)).collect();
+
+        // Divide using independent `/` operations.
+        let independent_constraints = Circuit::scope(format!("Independent {name}"), || {
+            for (a, expected) in numerators.iter().zip_eq(&expected) {
+                assert_eq!(*expected, (a / &b).eject_value());
+            }
+            assert_scope!();
+            Circuit::num_constraints_in_scope()
+        });
+
+        // Divide using the divisor cache.
+        let cached_constraints = Circuit::scope(format!("Cached {name}"), || {
+            let cache = b.as_divisor();
+            for (a, expected) in numerators.iter().zip_eq(&expected) {
+                assert_eq!(*expected, cache.divide(a).eject_value());
+            }
+            // Ensure the inverse is computed once, and each division costs a single multiplication.
+            let expected_count = count!(Field<Circuit>, AsDivisor<Output = DivisorCache<Circuit>>, &mode_divisor)
+                + count!(
+                    Field<Circuit>,
+                    Div<DivisorCache<Circuit>, Output = Field<Circuit>>,
+                    &(mode_numerator, mode_divisor)
+                ) * NUM_DIVISIONS;
+            let (num_constants, num_public, num_private, num_constraints, _) = Circuit::count_in_scope();
+            assert!(expected_count.matches(num_constants, num_public, num_private, num_constraints));
+            Circuit::num_constraints_in_scope()
+        });
+
+        // Ensure the cache never costs more than the independent divisions.
+        assert!(cached_constraints <= independent_constraints, "{cached_constraints} > {independent_constraints}");
+        Circuit::reset();
+    }
+
+    fn run_test(mode_divisor: Mode, mode_numerator: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let name = format!("DivisorCache {mode_divisor} / {mode_numerator} {i}");
+            check_divisor_cache(&name, mode_divisor, mode_numerator, &mut rng);
+        }
+    }
+
+    #[test]
+    fn test_divisor_cache() {
+        for mode_divisor in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_numerator in [Mode::Constant, Mode::Public, Mode::Private] {
+                run_test(mode_divisor, mode_numerator);
+            }
+        }
+    }
+
+    #[test]
+    fn test_divisor_cache_matches_single_division() {
+        let mut rng = TestRng::default();
+
+        for mode_divisor in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_numerator in [Mode::Constant, Mode::Public, Mode::Private] {
+                let numerator = Uniform::rand(&mut rng);
+                let divisor = Uniform::rand(&mut rng);
+                let a = Field::<Circuit>::new(mode_numerator, numerator);
+                let b = Field::<Circuit>::new(mode_divisor, divisor);
+
+                // A single cached division costs the same as a single `/`.
+                Circuit::scope("Cached single division", || {
+                    let candidate = b.as_divisor().divide(&a);
+                    assert_eq!(numerator / divisor, candidate.eject_value());
+                    assert_count!(Div(Field, Field) => Field, &(mode_numerator, mode_divisor));
+                });
+                Circuit::reset();
+            }
+        }
+    }
+
+    #[test]
+    fn test_constant_zero_divisor_halts() {
+        let zero = Field::<Circuit>::new(Mode::Constant, console::Field::zero());
+        let result = std::panic::catch_unwind(|| zero.as_divisor());
+        assert!(result.is_err());
+    }
+}
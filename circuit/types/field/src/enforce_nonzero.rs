@@ -0,0 +1,103 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> EnforceNonzero for Field<E> {
+    /// Enforces that `self` is non-zero, by witnessing its inverse and enforcing `self * self^(-1) == 1`.
+    /// As zero has no inverse, this is the minimal proof of non-zeroness, i.e. one private variable and one constraint.
+    ///
+    /// This method halts if `self` is a constant that is zero.
+    fn enforce_nonzero(&self) {
+        // Case 1: Constant
+        if self.is_constant() {
+            if self.eject_value().is_zero() {
+                E::halt("Attempted to enforce that a constant zero is non-zero")
+            }
+        }
+        // Case 2: Variable
+        else {
+            // Witness the inverse of `self`, where a zero `self` has no inverse, and is left unsatisfiable.
+            let inverse: Field<E> = witness!(|self| match self.inverse() {
+                Ok(inverse) => inverse,
+                _ => console::Field::zero(),
+            });
+            // Ensure `self * self^(-1) == 1`.
+            E::enforce(|| (self, inverse, E::one()));
+        }
+    }
+}
+
+impl<E: Environment> Metrics<dyn EnforceNonzero> for Field<E> {
+    type Case = Mode;
+
+    fn count(case: &Self::Case) -> Count {
+        match case.is_constant() {
+            true => Count::is(0, 0, 0, 0),
+            false => Count::is(0, 0, 1, 1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::{assert_count_fails, Circuit};
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_enforce_nonzero() {
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for i in 0..ITERATIONS {
+                // Sample a random non-zero element.
+                let given: console::Field<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+                if given.is_zero() {
+                    continue;
+                }
+                let candidate = Field::<Circuit>::new(mode, given);
+
+                Circuit::scope(format!("{mode} {i}"), || {
+                    candidate.enforce_nonzero();
+                    assert_count!(Field<Circuit>, EnforceNonzero, &mode);
+                });
+                Circuit::reset();
+            }
+        }
+    }
+
+    #[test]
+    fn test_enforce_nonzero_fails() {
+        let zero = console::Field::<<Circuit as Environment>::Network>::zero();
+
+        // Ensure a constant zero halts.
+        let result = std::panic::catch_unwind(|| Field::<Circuit>::new(Mode::Constant, zero).enforce_nonzero());
+        assert!(result.is_err());
+        Circuit::reset();
+
+        // Ensure a variable zero is unsatisfiable.
+        for mode in [Mode::Public, Mode::Private] {
+            let candidate = Field::<Circuit>::new(mode, zero);
+            Circuit::scope(format!("{mode}"), || {
+                candidate.enforce_nonzero();
+                assert_count_fails!(Field<Circuit>, EnforceNonzero, &mode);
+            });
+            Circuit::reset();
+        }
+    }
+}
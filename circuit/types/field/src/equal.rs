@@ -132,6 +132,25 @@ impl<E: Environment> Equal<Self> for Field<E> {
     }
 }
 
+impl<E: Environment> Equal<console::Field<E::Network>> for Field<E> {
+    type Output = Boolean<E>;
+
+    /// Returns `true` if `self` and the constant `other` are equal.
+    fn is_equal(&self, other: &console::Field<E::Network>) -> Self::Output {
+        match self.is_constant() {
+            // If `self` is a constant, fold the comparison into a constant, for 0 constraints.
+            true => Boolean::constant(self.eject_value() == *other),
+            // Otherwise, compare against the constant as a linear combination, which does not allocate it.
+            false => self.is_equal(&Field::from(E::one() * **other)),
+        }
+    }
+
+    /// Returns `true` if `self` and the constant `other` are *not* equal.
+    fn is_not_equal(&self, other: &console::Field<E::Network>) -> Self::Output {
+        !self.is_equal(other)
+    }
+}
+
 impl<E: Environment> Metrics<dyn Equal<Field<E>, Output = Boolean<E>>> for Field<E> {
     type Case = (Mode, Mode);
 
@@ -144,6 +163,17 @@ impl<E: Environment> Metrics<dyn Equal<Field<E>, Output = Boolean<E>>> for Field
     }
 }
 
+impl<E: Environment> Metrics<dyn Equal<console::Field<E::Network>, Output = Boolean<E>>> for Field<E> {
+    type Case = Mode;
+
+    fn count(case: &Self::Case) -> Count {
+        match case.is_constant() {
+            true => Count::is(0, 0, 0, 0),
+            false => Count::is(0, 0, 2, 3),
+        }
+    }
+}
+
 impl<E: Environment> OutputMode<dyn Equal<Field<E>, Output = Boolean<E>>> for Field<E> {
     type Case = (Mode, Mode);
 
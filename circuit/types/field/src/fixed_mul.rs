@@ -0,0 +1,172 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> FixedMul<Field<E>> for Field<E> {
+    type Output = Field<E>;
+
+    /// Returns the fixed-point product `floor(self * other / 2^scale_bits)`, for non-negative fixed-point values
+    /// that are represented as field elements scaled by `2^scale_bits`.
+    ///
+    /// Each operand is enforced to be less than `2^(Field::size_in_data_bits() / 2)`, so that the product fits in
+    /// the data bits of the base field, and cannot wrap around the modulus. If an operand does not fit, the circuit
+    /// is unsatisfiable for a variable operand, and halts for a constant one.
+    ///
+    /// The product is decomposed into `Field::size_in_data_bits()` bits, and the bits below `scale_bits` are dropped.
+    /// As such, the product is truncated (i.e. rounded down).
+    ///
+    /// This method halts if `scale_bits` is not less than the number of data bits in the base field.
+    fn fixed_mul(&self, other: &Field<E>, scale_bits: u32) -> Self::Output {
+        // Ensure the scale is less than the data capacity of the base field.
+        let num_data_bits = E::BaseField::size_in_data_bits();
+        if scale_bits as usize >= num_data_bits {
+            E::halt(format!(
+                "Attempted to scale a fixed-point product by {scale_bits} bits, with {num_data_bits} data bits"
+            ))
+        }
+
+        // Ensure each operand fits in half of the data bits, so that the product does not wrap around the modulus.
+        self.to_lower_bits_le(num_data_bits / 2);
+        other.to_lower_bits_le(num_data_bits / 2);
+
+        // Compute the product, and decompose it into bits.
+        let bits_le = (self * other).to_lower_bits_le(num_data_bits);
+        // Recompose the bits from `scale_bits` onwards, which is the product divided by `2^scale_bits`, rounded down.
+        Field::from_bits_le(&bits_le[scale_bits as usize..])
+    }
+}
+
+impl<E: Environment> Metrics<dyn FixedMul<Field<E>, Output = Field<E>>> for Field<E> {
+    /// The modes of `self` and `other`, and the number of scale bits.
+    type Case = (Mode, Mode, u32);
+
+    fn count(case: &Self::Case) -> Count {
+        // Note: The cost is independent of the number of scale bits, as the operands and the product are decomposed.
+        let num_data_bits = E::BaseField::size_in_data_bits() as u64;
+        let num_operand_bits = num_data_bits / 2;
+        match case {
+            (Mode::Constant, Mode::Constant, _) => Count::is(2 * num_operand_bits + num_data_bits, 0, 0, 0),
+            (Mode::Constant, _, _) | (_, Mode::Constant, _) => {
+                Count::is(num_operand_bits, 0, num_operand_bits + num_data_bits, num_operand_bits + num_data_bits + 2)
+            }
+            (_, _, _) => {
+                Count::is(0, 0, 2 * num_operand_bits + num_data_bits + 1, 2 * num_operand_bits + num_data_bits + 4)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::{assert_count_fails, Circuit};
+
+    const ITERATIONS: u64 = 20;
+
+    fn check_fixed_mul(mode_a: Mode, mode_b: Mode, scale_bits: u32, rng: &mut TestRng) {
+        for i in 0..ITERATIONS {
+            let (first, second) = (u64::rand(rng), u64::rand(rng));
+            let a = Field::<Circuit>::new(mode_a, console::Field::from_u64(first));
+            let b = Field::<Circuit>::new(mode_b, console::Field::from_u64(second));
+
+            // Compute the expected product, rounded down.
+            let expected = (first as u128 * second as u128) >> scale_bits;
+
+            Circuit::scope(format!("{mode_a} {mode_b} {scale_bits} {i}"), || {
+                let candidate = a.fixed_mul(&b, scale_bits);
+                assert_eq!(console::Field::from_u128(expected), candidate.eject_value());
+                assert_count!(
+                    Field<Circuit>,
+                    FixedMul<Field<Circuit>, Output = Field<Circuit>>,
+                    &(mode_a, mode_b, scale_bits)
+                );
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_fixed_mul() {
+        let mut rng = TestRng::default();
+
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                for scale_bits in [0, 1, 16, 64, 127] {
+                    check_fixed_mul(mode_a, mode_b, scale_bits, &mut rng);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_fixed_mul_rounding() {
+        // Ensure the product is rounded down, e.g. `1.5 * 1.5 = 2.25` is `2` with 1 scale bit, where `3` is `1.5`.
+        let three = Field::<Circuit>::new(Mode::Private, console::Field::from_u64(3));
+        assert_eq!(console::Field::from_u64(4), three.fixed_mul(&three, 1).eject_value());
+        // Ensure `1.5 * 1.5 = 2.25` is exact with 2 scale bits, where `6` is `1.5`.
+        let six = Field::<Circuit>::new(Mode::Private, console::Field::from_u64(6));
+        assert_eq!(console::Field::from_u64(9), six.fixed_mul(&six, 2).eject_value());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_fixed_mul_out_of_range() {
+        // Returns `2^exponent` as a field element.
+        let power_of_two = |exponent: u32| console::Field::new(Field::<Circuit>::power_of_two(exponent));
+        let num_operand_bits = <Circuit as Environment>::BaseField::size_in_data_bits() as u32 / 2;
+
+        // Ensure an operand that does not fit in half of the data bits is unsatisfiable, including when
+        // the product would wrap around the modulus, e.g. `2^200 * 2^100`, and when it would not, e.g. `-1 * 1`.
+        for (first, second) in [
+            (power_of_two(200), power_of_two(100)),
+            (-console::Field::one(), console::Field::one()),
+            (power_of_two(num_operand_bits), console::Field::one()),
+            (console::Field::one(), power_of_two(num_operand_bits)),
+        ] {
+            let a = Field::<Circuit>::new(Mode::Private, first);
+            let b = Field::<Circuit>::new(Mode::Private, second);
+
+            Circuit::scope("fixed_mul out of range", || {
+                let _ = a.fixed_mul(&b, 8);
+                assert_count_fails!(
+                    Field<Circuit>,
+                    FixedMul<Field<Circuit>, Output = Field<Circuit>>,
+                    &(Mode::Private, Mode::Private, 8)
+                );
+            });
+            Circuit::reset();
+        }
+
+        // Ensure the largest operands are satisfiable, as their product fits in the data bits.
+        let max = power_of_two(num_operand_bits) - console::Field::one();
+        let a = Field::<Circuit>::new(Mode::Private, max);
+        let _ = a.fixed_mul(&a, 8);
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_fixed_mul_halts() {
+        let a = Field::<Circuit>::new(Mode::Private, console::Field::one());
+
+        // Ensure a scale exceeding the data capacity of the base field halts.
+        let scale_bits = <Circuit as Environment>::BaseField::size_in_data_bits() as u32;
+        let result = std::panic::catch_unwind(|| a.fixed_mul(&a, scale_bits));
+        assert!(result.is_err());
+    }
+}
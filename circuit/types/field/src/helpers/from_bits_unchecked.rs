@@ -0,0 +1,160 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> FromBitsUnchecked for Field<E> {
+    type Boolean = Boolean<E>;
+
+    /// Initializes a new base field element from a list of **little-endian** bits, *without* enforcing
+    /// that the bits encode a value less than `BaseField::MODULUS`.
+    ///
+    /// # Warning
+    /// This method is only sound if `bits_le` has at most `E::BaseField::size_in_bits()` bits, and encodes
+    /// a value that is less than `BaseField::MODULUS`, which is the caller's responsibility to ensure.
+    /// Unlike `Field::from_bits_le`, no constraints are added to check this, and a list of bits encoding
+    /// a larger value yields the value reduced modulo `BaseField::MODULUS`, with the given (non-canonical) bits.
+    /// Use this method when the bits come from an already-bounded source, and `Field::from_bits_le` otherwise.
+    fn from_bits_le_unchecked(bits_le: &[Self::Boolean]) -> Self {
+        // Reconstruct the bits as a linear combination representing the original field value.
+        // `output` := (2^i * b_i + ... + 2^0 * b_0)
+        let mut output = Field::zero();
+        let mut coefficient = Field::one();
+        for bit in bits_le {
+            output += Field::from_boolean(bit) * &coefficient;
+            coefficient = coefficient.double();
+        }
+
+        // Construct the list of bits, resizing up to the base field size.
+        // Note: This relies on the precondition that the bits encode a value less than `BaseField::MODULUS`.
+        let mut bits_le = bits_le.to_vec();
+        bits_le.resize(E::BaseField::size_in_bits(), Boolean::constant(false));
+
+        // Store the little-endian bits in the output.
+        if output.bits_le.set(bits_le).is_err() {
+            E::halt("Detected corrupt internal state for the bits of a field element")
+        }
+
+        output
+    }
+}
+
+impl<E: Environment> Metrics<dyn FromBitsUnchecked<Boolean = Boolean<E>>> for Field<E> {
+    type Case = Vec<Mode>;
+
+    fn count(_modes: &Self::Case) -> Count {
+        Count::is(0, 0, 0, 0)
+    }
+}
+
+impl<E: Environment> OutputMode<dyn FromBitsUnchecked<Boolean = Boolean<E>>> for Field<E> {
+    type Case = Vec<Mode>;
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case.iter().all(|mode| mode.is_constant()) {
+            true => Mode::Constant,
+            false => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_from_bits_le_unchecked(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random element, using all of the bits of the base field.
+            // Note: As the bits exceed the capacity of the base field, `Field::from_bits_le` checks them against
+            // the modulus, while `Field::from_bits_le_unchecked` does not.
+            let expected: console::Field<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+            let given_bits = expected.to_bits_le();
+            assert_eq!(<Circuit as Environment>::BaseField::size_in_bits(), given_bits.len());
+
+            // Inject the bits.
+            let candidate_bits = given_bits.iter().map(|bit| Boolean::<Circuit>::new(mode, *bit)).collect::<Vec<_>>();
+            let modes = vec![mode; candidate_bits.len()];
+
+            Circuit::scope(format!("{mode} {i}"), || {
+                let candidate = Field::<Circuit>::from_bits_le_unchecked(&candidate_bits);
+                assert_eq!(expected, candidate.eject_value());
+                assert_count!(FromBitsUnchecked<Boolean>() => Field, &modes);
+                assert_output_mode!(FromBitsUnchecked<Boolean>() => Field, &modes, candidate);
+
+                // Ensure a subsequent call to `to_bits_le` returns the cached bits, and incurs no additional costs.
+                let bits_le = candidate.to_bits_le();
+                assert_eq!(expected.to_bits_le(), bits_le.eject_value());
+                assert_count!(FromBitsUnchecked<Boolean>() => Field, &modes);
+            });
+            Circuit::reset();
+
+            // Ensure the checked version incurs constraints for the same (non-constant) bits.
+            if !mode.is_constant() {
+                let candidate_bits =
+                    given_bits.iter().map(|bit| Boolean::<Circuit>::new(mode, *bit)).collect::<Vec<_>>();
+                Circuit::scope(format!("{mode} {i} (checked)"), || {
+                    let candidate = Field::<Circuit>::from_bits_le(&candidate_bits);
+                    assert_eq!(expected, candidate.eject_value());
+                    assert!(Circuit::num_constraints_in_scope() > 0);
+                });
+                Circuit::reset();
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_bits_le_unchecked_constant() {
+        check_from_bits_le_unchecked(Mode::Constant);
+    }
+
+    #[test]
+    fn test_from_bits_le_unchecked_public() {
+        check_from_bits_le_unchecked(Mode::Public);
+    }
+
+    #[test]
+    fn test_from_bits_le_unchecked_private() {
+        check_from_bits_le_unchecked(Mode::Private);
+    }
+
+    #[test]
+    fn test_from_bits_le_unchecked_modulus() {
+        // Injects the bits of `BaseField::MODULUS`, which violate the precondition.
+        let sample_modulus_bits = || {
+            let size_in_bits = <Circuit as Environment>::BaseField::size_in_bits();
+            <Circuit as Environment>::BaseField::modulus().to_bits_le()[..size_in_bits]
+                .iter()
+                .map(|bit| Boolean::<Circuit>::new(Mode::Private, *bit))
+                .collect::<Vec<_>>()
+        };
+
+        // Ensure the unchecked version does not enforce the precondition, and reduces the value to zero.
+        let candidate = Field::<Circuit>::from_bits_le_unchecked(&sample_modulus_bits());
+        assert!(candidate.eject_value().is_zero());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+
+        // Ensure the checked version rejects the bits.
+        let _candidate = Field::<Circuit>::from_bits_le(&sample_modulus_bits());
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset();
+    }
+}
@@ -17,9 +17,12 @@
 use super::*;
 
 pub mod from_bits;
+pub mod from_bits_unchecked;
 pub mod from_boolean;
 pub mod one;
 pub mod to_bits;
+pub mod to_bits_with_proof;
+pub mod to_exact_bits;
 pub mod to_lower_bits;
 pub mod to_upper_bits;
 pub mod zero;
@@ -24,7 +24,7 @@ impl<E: Environment> One for Field<E> {
     }
 
     fn is_one(&self) -> Self::Boolean {
-        self.is_equal(&Field::one())
+        self.is_equal(&console::Field::one())
     }
 }
 
@@ -70,4 +70,28 @@ mod tests {
         // Should not equal 0.
         assert!(!candidate.is_zero().eject_value());
     }
+
+    #[test]
+    fn test_is_one_metrics() {
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            let random = Uniform::rand(&mut rng);
+            for value in [console::Field::one(), console::Field::zero(), random] {
+                let candidate = Field::<Circuit>::new(mode, value);
+
+                Circuit::scope(format!("{mode} {value}"), || {
+                    let result = candidate.is_one();
+                    assert_eq!(value.is_one(), result.eject_value());
+                    assert_eq!(mode.is_constant(), result.is_constant());
+                    assert_count!(
+                        Field<Circuit>,
+                        Equal<console::Field<<Circuit as Environment>::Network>, Output = Boolean<Circuit>>,
+                        &mode
+                    );
+                });
+                Circuit::reset();
+            }
+        }
+    }
 }
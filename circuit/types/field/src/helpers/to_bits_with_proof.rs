@@ -0,0 +1,191 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// A handle to the witnesses proving that the bit decomposition of a field element is canonical,
+/// i.e. that the little-endian bits encode a value less than `BaseField::MODULUS`.
+#[derive(Clone)]
+pub struct CanonicityProof<E: Environment> {
+    /// The little-endian bits of the field element.
+    bits_le: Vec<Boolean<E>>,
+    /// The running comparisons `(BaseField::MODULUS - 1)[..=i] < bits_le[..=i]`, for each bit `i`.
+    comparisons: Vec<Boolean<E>>,
+    /// The enforced result `bits_le < BaseField::MODULUS`.
+    is_less_than_modulus: Boolean<E>,
+}
+
+impl<E: Environment> CanonicityProof<E> {
+    /// Returns the little-endian bits of the field element.
+    pub fn bits_le(&self) -> &[Boolean<E>] {
+        &self.bits_le
+    }
+
+    /// Returns the running comparisons of the bits against `BaseField::MODULUS - 1`, from the least-significant bit.
+    pub fn comparisons(&self) -> &[Boolean<E>] {
+        &self.comparisons
+    }
+
+    /// Returns the enforced result of `bits_le < BaseField::MODULUS`.
+    pub fn is_less_than_modulus(&self) -> &Boolean<E> {
+        &self.is_less_than_modulus
+    }
+
+    /// Consumes the proof, returning the little-endian bits of the field element.
+    pub fn into_bits_le(self) -> Vec<Boolean<E>> {
+        self.bits_le
+    }
+}
+
+impl<E: Environment> Field<E> {
+    /// Outputs the little-endian bit representation of `self`, along with a handle to the witnesses
+    /// enforcing that the bits are the canonical representation of `self`.
+    ///
+    /// Note: The proof is cached, such that only the first call on `self` incurs constraint costs.
+    pub fn to_bits_le_with_proof(&self) -> (Vec<Boolean<E>>, CanonicityProof<E>) {
+        let proof = self.canonicity_proof.get_or_init(|| self.prove_canonical_bits_le()).clone();
+        (proof.bits_le.clone(), proof)
+    }
+
+    /// Returns a proof that the (cached) little-endian bits of `self` are canonical.
+    fn prove_canonical_bits_le(&self) -> CanonicityProof<E> {
+        // Retrieve the (cached) little-endian bits of `self`.
+        let bits_le = self.to_bits_le();
+
+        // Retrieve the modulus & subtract by 1 as we'll check `bits_le` is less than or *equal* to this value.
+        // (For advanced users) BaseField::MODULUS - 1 is equivalent to -1 in the field.
+        let modulus_minus_one = -E::BaseField::one();
+
+        // Compute `(BaseField::MODULUS - 1) < bits_le`, retaining each intermediate comparison.
+        let mut comparisons = Vec::with_capacity(bits_le.len());
+        let mut rest_is_less = Boolean::constant(false);
+        for (this, that) in modulus_minus_one.to_bits_le().iter().zip_eq(&bits_le) {
+            rest_is_less = if *this { that.bitand(&rest_is_less) } else { that.bitor(&rest_is_less) };
+            comparisons.push(rest_is_less.clone());
+        }
+
+        // Compute `!((BaseField::MODULUS - 1) < bits_le)`, which is equivalent to `bits_le < BaseField::MODULUS`.
+        let is_less_than_modulus = !rest_is_less;
+
+        // Ensure the field element is less than `BaseField::MODULUS`.
+        E::assert(is_less_than_modulus.clone());
+
+        CanonicityProof { bits_le, comparisons, is_less_than_modulus }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    /// Returns a private field element for zero, whose cached bits are forged as the non-canonical bits
+    /// of `0 + BaseField::MODULUS`, as a malicious prover may witness them.
+    pub(crate) fn sample_zero_with_forged_bits() -> Field<Circuit> {
+        let zero = Field::<Circuit>::new(Mode::Private, console::Field::zero());
+
+        // Witness the bits of `BaseField::MODULUS`.
+        let size_in_bits = console::Field::<<Circuit as Environment>::Network>::size_in_bits();
+        let bits_le = <Circuit as Environment>::BaseField::modulus().to_bits_le()[..size_in_bits]
+            .iter()
+            .map(|bit| Boolean::new(Mode::Private, *bit))
+            .collect::<Vec<_>>();
+        // Ensure the bits reconstruct zero, as `BaseField::MODULUS == 0` in the field.
+        let mut accumulator = Field::zero();
+        let mut coefficient = Field::one();
+        for bit in &bits_le {
+            accumulator += Field::from_boolean(bit) * &coefficient;
+            coefficient = coefficient.double();
+        }
+        Circuit::assert_eq(&zero, accumulator);
+
+        // Cache the forged bits, so that they are used in place of the canonical bits of zero.
+        Field {
+            linear_combination: zero.linear_combination,
+            bits_le: OnceCell::from(bits_le),
+            canonicity_proof: Default::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 10;
+
+    fn check_to_bits_le_with_proof(mode: Mode) {
+        let expected_number_of_bits = console::Field::<<Circuit as Environment>::Network>::size_in_bits();
+
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random element.
+            let expected = Uniform::rand(&mut rng);
+            let candidate = Field::<Circuit>::new(mode, expected);
+
+            Circuit::scope(&format!("{mode} {i}"), || {
+                let (candidate_bits, proof) = candidate.to_bits_le_with_proof();
+                assert_eq!(expected_number_of_bits, candidate_bits.len());
+                assert_eq!(expected_number_of_bits, proof.comparisons().len());
+                for (expected_bit, candidate_bit) in expected.to_bits_le().iter().zip_eq(&candidate_bits) {
+                    assert_eq!(*expected_bit, candidate_bit.eject_value());
+                }
+                assert!(proof.is_less_than_modulus().eject_value());
+                assert_scope!();
+
+                let num_constants = Circuit::num_constants_in_scope();
+                let num_private = Circuit::num_private_in_scope();
+                let num_constraints = Circuit::num_constraints_in_scope();
+
+                // Ensure reusing the handle yields identical bits, and incurs no additional costs.
+                let reused_bits = candidate.to_bits_le();
+                for ((candidate_bit, proof_bit), reused_bit) in
+                    candidate_bits.iter().zip_eq(proof.bits_le()).zip_eq(&reused_bits)
+                {
+                    assert_eq!(candidate_bit.eject_value(), proof_bit.eject_value());
+                    assert_eq!(candidate_bit.eject_value(), reused_bit.eject_value());
+                }
+                let consumed_bits = proof.into_bits_le();
+                assert_eq!(candidate_bits.eject_value(), consumed_bits.eject_value());
+                assert_scope!(num_constants, 0, num_private, num_constraints);
+
+                // Ensure a second call reuses the cached proof, and incurs no additional costs.
+                let (repeated_bits, repeated_proof) = candidate.to_bits_le_with_proof();
+                assert_eq!(candidate_bits.eject_value(), repeated_bits.eject_value());
+                assert!(repeated_proof.is_less_than_modulus().eject_value());
+                assert_scope!(num_constants, 0, num_private, num_constraints);
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_to_bits_le_with_proof_constant() {
+        check_to_bits_le_with_proof(Mode::Constant);
+    }
+
+    #[test]
+    fn test_to_bits_le_with_proof_public() {
+        check_to_bits_le_with_proof(Mode::Public);
+    }
+
+    #[test]
+    fn test_to_bits_le_with_proof_private() {
+        check_to_bits_le_with_proof(Mode::Private);
+    }
+}
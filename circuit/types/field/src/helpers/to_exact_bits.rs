@@ -0,0 +1,153 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> ToExactBits for Field<E> {
+    type Boolean = Boolean<E>;
+    type Output = Result<Vec<Boolean<E>>>;
+
+    /// Outputs exactly `n` little-endian bits of `self`, enforcing that `self < 2^n`.
+    ///
+    /// As `n` is at most the data capacity of the base field, `2^n` is less than the modulus,
+    /// and as such, the decomposition is unique, and it is a tight range proof for `0 <= self < 2^n`.
+    /// Unlike `to_bits_le`, this does not decompose every bit of `self`, nor compare the bits to the modulus.
+    ///
+    /// This method errors if `n` exceeds the data capacity of the base field, or if `self` is a constant
+    /// that does not fit in `n` bits. For a variable `self` that does not fit, the circuit is unsatisfiable.
+    fn to_exact_bits_le(&self, n: usize) -> Self::Output {
+        // Ensure `n` is within the data capacity of the base field.
+        let num_data_bits = E::BaseField::size_in_data_bits();
+        ensure!(
+            n <= num_data_bits,
+            "Cannot decompose into {n} bits, as the base field holds {num_data_bits} data bits"
+        );
+
+        // Ensure a constant `self` fits in `n` bits.
+        if self.is_constant() {
+            let value = self.eject_value();
+            ensure!(!value.to_bits_le()[n..].iter().any(|bit| *bit), "The field element {value} exceeds {n} bits");
+        }
+
+        // Decompose `self` into its lower `n` bits, which enforces that the upper bits are zero.
+        Ok(self.to_lower_bits_le(n))
+    }
+}
+
+impl<E: Environment> Metrics<dyn ToExactBits<Boolean = Boolean<E>, Output = Result<Vec<Boolean<E>>>>> for Field<E> {
+    /// The mode of `self`, and the number of bits.
+    type Case = (Mode, u64);
+
+    fn count(case: &Self::Case) -> Count {
+        count!(Field<E>, ToLowerBits<Boolean = Boolean<E>>, case)
+    }
+}
+
+impl<E: Environment> OutputMode<dyn ToExactBits<Boolean = Boolean<E>, Output = Result<Vec<Boolean<E>>>>> for Field<E> {
+    /// The mode of `self`, and the number of bits.
+    type Case = (Mode, u64);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case {
+            // Note: An empty list of bits is regarded as a constant.
+            (_, 0) => Mode::Constant,
+            (mode, _) => output_mode!(Field<E>, ToLowerBits<Boolean = Boolean<E>>, mode),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::{assert_count_fails, Circuit};
+
+    const ITERATIONS: u64 = 10;
+
+    fn check_to_exact_bits_le(mode: Mode, n: usize, rng: &mut TestRng) -> Result<()> {
+        for i in 0..ITERATIONS {
+            // Sample a random value that fits in `n` bits.
+            let given: console::Field<<Circuit as Environment>::Network> = Uniform::rand(rng);
+            let expected = &given.to_bits_le()[..n];
+            let candidate = Field::<Circuit>::new(mode, console::Field::from_bits_le(expected)?);
+
+            Circuit::scope(format!("{mode} {n} {i}"), || {
+                let candidate = candidate.to_exact_bits_le(n)?;
+                assert_eq!(expected, candidate.eject_value());
+                let case = (mode, n as u64);
+                assert_count!(
+                    Field<Circuit>,
+                    ToExactBits<Boolean = Boolean<Circuit>, Output = Result<Vec<Boolean<Circuit>>>>,
+                    &case
+                );
+                assert_output_mode!(
+                    Field<Circuit>,
+                    ToExactBits<Boolean = Boolean<Circuit>, Output = Result<Vec<Boolean<Circuit>>>>,
+                    &case,
+                    candidate
+                );
+                Ok::<_, Error>(())
+            })?;
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_exact_bits_le() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for n in [0, 1, 8, 64, <Circuit as Environment>::BaseField::size_in_data_bits()] {
+                check_to_exact_bits_le(mode, n, &mut rng)?;
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_exact_bits_le_overflow() -> Result<()> {
+        let value = console::Field::from_u64(1 << 8);
+
+        // Ensure a variable that does not fit in the given number of bits is unsatisfiable.
+        for mode in [Mode::Public, Mode::Private] {
+            let candidate = Field::<Circuit>::new(mode, value);
+            Circuit::scope(format!("{mode} overflow"), || {
+                candidate.to_exact_bits_le(8)?;
+                assert_count_fails!(
+                    Field<Circuit>,
+                    ToExactBits<Boolean = Boolean<Circuit>, Output = Result<Vec<Boolean<Circuit>>>>,
+                    &(mode, 8)
+                );
+                Ok::<_, Error>(())
+            })?;
+            Circuit::reset();
+        }
+
+        // Ensure a constant that does not fit in the given number of bits fails.
+        assert!(Field::<Circuit>::new(Mode::Constant, value).to_exact_bits_le(8).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_exact_bits_le_exceeds_capacity() {
+        let candidate = Field::<Circuit>::new(Mode::Private, console::Field::one());
+
+        // Ensure a number of bits exceeding the data capacity of the base field fails.
+        let num_data_bits = <Circuit as Environment>::BaseField::size_in_data_bits();
+        assert!(candidate.to_exact_bits_le(num_data_bits + 1).is_err());
+        assert!(candidate.to_exact_bits_le(<Circuit as Environment>::BaseField::size_in_bits()).is_err());
+    }
+}
@@ -24,7 +24,7 @@ impl<E: Environment> Zero for Field<E> {
     }
 
     fn is_zero(&self) -> Self::Boolean {
-        self.is_equal(&Field::zero())
+        self.is_equal(&console::Field::zero())
     }
 }
 
@@ -70,4 +70,28 @@ mod tests {
         // Should not equal 1.
         assert!(!candidate.is_one().eject_value());
     }
+
+    #[test]
+    fn test_is_zero_metrics() {
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            let random = Uniform::rand(&mut rng);
+            for value in [console::Field::zero(), console::Field::one(), random] {
+                let candidate = Field::<Circuit>::new(mode, value);
+
+                Circuit::scope(format!("{mode} {value}"), || {
+                    let result = candidate.is_zero();
+                    assert_eq!(value.is_zero(), result.eject_value());
+                    assert_eq!(mode.is_constant(), result.is_constant());
+                    assert_count!(
+                        Field<Circuit>,
+                        Equal<console::Field<<Circuit as Environment>::Network>, Output = Boolean<Circuit>>,
+                        &mode
+                    );
+                });
+                Circuit::reset();
+            }
+        }
+    }
 }
@@ -0,0 +1,194 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Interpolate<console::Field<E::Network>> for Field<E> {
+    type Output = Result<Field<E>>;
+
+    /// Returns the evaluation at `x` of the unique polynomial of degree less than `points.len()`
+    /// that passes through the given `points`, using Lagrange interpolation.
+    ///
+    /// As the x-coordinates are constants, the barycentric weights `w_i = 1 / prod_{j != i} (x_i - x_j)`
+    /// are computed outside of the circuit, and the evaluation is `sum_i w_i * y_i * prod_{j != i} (x - x_j)`.
+    /// This method does not divide by `x - x_i`, and thus is well-defined when `x` is one of the x-coordinates.
+    ///
+    /// This method errors if there are no points, or if the x-coordinates are not distinct.
+    fn interpolate(points: &[(console::Field<E::Network>, Field<E>)], x: &Field<E>) -> Self::Output {
+        // Ensure there is at least one point.
+        ensure!(!points.is_empty(), "Cannot interpolate from an empty set of points");
+        // Ensure the x-coordinates are distinct.
+        ensure!(!has_duplicates(points.iter().map(|(x_i, _)| x_i)), "Cannot interpolate from duplicate x-coordinates");
+
+        // Compute the barycentric weights.
+        let weights = points
+            .iter()
+            .enumerate()
+            .map(|(i, (x_i, _))| {
+                points
+                    .iter()
+                    .enumerate()
+                    .filter(|(j, _)| i != *j)
+                    .fold(console::Field::one(), |product, (_, (x_j, _))| product * (*x_i - x_j))
+                    .inverse()
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Compute the differences `x - x_j`.
+        // Note: The constants are added directly into the linear combination of `x`, and do not allocate constants.
+        let differences = points.iter().map(|(x_j, _)| x.add_constant(-*x_j)).collect::<Vec<_>>();
+        let num_points = differences.len();
+
+        // Compute the prefix products `prod_{j < i} (x - x_j)` and the suffix products `prod_{j > i} (x - x_j)`,
+        // such that the basis for each point is the product of its prefix and suffix products.
+        // Note: An empty product is represented as `None`, to avoid multiplying by one.
+        let mut prefixes: Vec<Option<Field<E>>> = Vec::with_capacity(num_points);
+        prefixes.push(None);
+        for difference in &differences[..num_points - 1] {
+            let prefix = match prefixes.last() {
+                Some(Some(prefix)) => prefix * difference,
+                _ => difference.clone(),
+            };
+            prefixes.push(Some(prefix));
+        }
+        let mut suffixes: Vec<Option<Field<E>>> = Vec::with_capacity(num_points);
+        suffixes.push(None);
+        for difference in differences[1..].iter().rev() {
+            let suffix = match suffixes.last() {
+                Some(Some(suffix)) => difference * suffix,
+                _ => difference.clone(),
+            };
+            suffixes.push(Some(suffix));
+        }
+        suffixes.reverse();
+
+        // Compute `sum_i w_i * y_i * prod_{j != i} (x - x_j)`.
+        let mut output = Field::zero();
+        for ((_, y_i), (weight, (prefix, suffix))) in
+            points.iter().zip_eq(weights.iter().zip_eq(prefixes.into_iter().zip_eq(suffixes)))
+        {
+            let term = match (prefix, suffix) {
+                (Some(prefix), Some(suffix)) => y_i * (prefix * suffix),
+                (Some(basis), None) | (None, Some(basis)) => y_i * basis,
+                (None, None) => y_i.clone(),
+            };
+            // Scale the term by its weight, directly in the linear combination, without allocating a constant.
+            output += Field::from(&term.linear_combination * **weight);
+        }
+        Ok(output)
+    }
+}
+
+impl<E: Environment> Metrics<dyn Interpolate<console::Field<E::Network>, Output = Result<Field<E>>>> for Field<E> {
+    type Case = (Mode, Vec<Mode>);
+
+    /// Returns the number of constants, public variables, private variables, and constraints
+    /// to interpolate at `x`, for the given mode of `x` and the modes of the y-coordinates.
+    fn count(case: &Self::Case) -> Count {
+        let (mode_x, modes_y) = case;
+        let num_points = modes_y.len() as u64;
+
+        match mode_x.is_constant() || num_points < 2 {
+            // If `x` is constant, or there is a single point, every basis is a constant.
+            true => Count::is(0, 0, 0, 0),
+            // The prefix, suffix, and basis products each cost `num_points - 2` multiplications,
+            // and the product of each variable y-coordinate with its basis costs one multiplication.
+            false => {
+                let num_variable_ys = modes_y.iter().filter(|mode| !mode.is_constant()).count() as u64;
+                let num_multiplications = 3 * (num_points - 2) + num_variable_ys;
+                Count::is(0, 0, num_multiplications, num_multiplications)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 10;
+
+    /// Evaluates the polynomial with the given coefficients at `x`, using Horner's method.
+    fn evaluate(
+        coefficients: &[console::Field<<Circuit as Environment>::Network>],
+        x: console::Field<<Circuit as Environment>::Network>,
+    ) -> console::Field<<Circuit as Environment>::Network> {
+        coefficients.iter().rev().fold(console::Field::zero(), |acc, coefficient| acc * x + coefficient)
+    }
+
+    fn check_interpolate(mode_x: Mode, mode_y: Mode, num_points: usize, rng: &mut TestRng) -> Result<()> {
+        for i in 0..ITERATIONS {
+            // Sample a random polynomial of degree less than `num_points`.
+            let coefficients = (0..num_points).map(|_| Uniform::rand(rng)).collect::<Vec<_>>();
+
+            // Evaluate the polynomial at distinct x-coordinates.
+            let points = (0..num_points)
+                .map(|j| {
+                    let x_j = console::Field::from_u64(j as u64 * 7 + 3);
+                    (x_j, Field::<Circuit>::new(mode_y, evaluate(&coefficients, x_j)))
+                })
+                .collect::<Vec<_>>();
+            let modes_y = points.iter().map(|(_, y)| y.eject_mode()).collect::<Vec<_>>();
+
+            // Interpolate at a random point, and at one of the x-coordinates.
+            let random = Uniform::rand(rng);
+            for given in [random, points[num_points / 2].0] {
+                let x = Field::<Circuit>::new(mode_x, given);
+
+                Circuit::scope(format!("{mode_x} {mode_y} {num_points} {i}"), || {
+                    let candidate = Field::interpolate(&points, &x)?;
+                    assert_eq!(evaluate(&coefficients, given), candidate.eject_value());
+                    assert_count!(
+                        Field<Circuit>,
+                        Interpolate<console::Field<<Circuit as Environment>::Network>, Output = Result<Field<Circuit>>>,
+                        &(mode_x, modes_y.clone())
+                    );
+                    Ok::<_, Error>(())
+                })?;
+                Circuit::reset();
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_interpolate() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for mode_x in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_y in [Mode::Constant, Mode::Public, Mode::Private] {
+                for num_points in [1, 2, 3, 8] {
+                    check_interpolate(mode_x, mode_y, num_points, &mut rng)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_interpolate_fails() {
+        let x = Field::<Circuit>::new(Mode::Private, console::Field::one());
+        let y = Field::<Circuit>::new(Mode::Private, console::Field::one());
+
+        // Ensure interpolating from no points fails.
+        assert!(Field::interpolate(&[], &x).is_err());
+
+        // Ensure interpolating from duplicate x-coordinates fails.
+        let points = [(console::Field::one(), y.clone()), (console::Field::one(), y)];
+        assert!(Field::interpolate(&points, &x).is_err());
+    }
+}
@@ -19,20 +19,46 @@
 #![cfg_attr(test, allow(clippy::assertions_on_result_states))]
 
 mod helpers;
+pub use helpers::to_bits_with_proof::CanonicityProof;
 
+pub mod accumulator_update;
 pub mod add;
+pub mod assert_eq_mod;
+pub mod assert_eq_slice;
+pub mod assert_in_set;
+pub mod assert_is_root_of;
+pub mod assert_is_valid_scalar;
+pub mod assert_less_than_const;
+pub mod assert_permutation;
+pub mod bit_reverse;
 pub mod compare;
+pub mod conditional_assert_eq;
+pub mod conditional_enforce_mul;
+pub mod const_base_pow;
 pub mod div;
+pub mod div_rem;
 pub mod div_unchecked;
+pub mod divisor_cache;
 pub mod double;
+pub mod enforce_nonzero;
 pub mod equal;
+pub mod fixed_mul;
+pub mod interpolate;
 pub mod inverse;
+pub mod linear_combination_builder;
+pub mod masked_sum;
+pub mod min_max;
 pub mod mul;
+pub mod mul_add;
+pub mod mul_inverse_pair;
 pub mod neg;
 pub mod pow;
+pub mod pow_u64;
+pub mod shift_const;
 pub mod square;
 pub mod square_root;
 pub mod sub;
+pub mod sub_with_borrow;
 pub mod ternary;
 
 #[cfg(test)]
@@ -50,6 +76,9 @@ pub struct Field<E: Environment> {
     /// An optional secondary representation in little-endian bits is provided,
     /// so that calls to `ToBits` only incur constraint costs once.
     bits_le: OnceCell<Vec<Boolean<E>>>,
+    /// An optional proof that the secondary representation is canonical is provided,
+    /// so that calls to `Field::to_bits_le_with_proof` only incur constraint costs once.
+    canonicity_proof: OnceCell<CanonicityProof<E>>,
 }
 
 impl<E: Environment> FieldTrait for Field<E> {}
@@ -60,7 +89,11 @@ impl<E: Environment> Inject for Field<E> {
 
     /// Initializes a field circuit from a console field.
     fn new(mode: Mode, field: Self::Primitive) -> Self {
-        Self { linear_combination: E::new_variable(mode, *field).into(), bits_le: Default::default() }
+        Self {
+            linear_combination: E::new_variable(mode, *field).into(),
+            bits_le: Default::default(),
+            canonicity_proof: Default::default(),
+        }
     }
 }
 
@@ -140,7 +173,7 @@ impl<E: Environment> Display for Field<E> {
 
 impl<E: Environment> From<LinearCombination<E::BaseField>> for Field<E> {
     fn from(linear_combination: LinearCombination<E::BaseField>) -> Self {
-        Self { linear_combination, bits_le: Default::default() }
+        Self { linear_combination, bits_le: Default::default(), canonicity_proof: Default::default() }
     }
 }
 
@@ -0,0 +1,125 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// A builder for the weighted sum `Σ c_i * x_i` of constant coefficients `c_i` and fields `x_i`,
+/// which accumulates every term into a single linear combination, and thus incurs no constraints.
+#[derive(Clone)]
+pub struct LinearCombinationBuilder<E: Environment> {
+    /// The accumulated linear combination.
+    linear_combination: LinearCombination<E::BaseField>,
+}
+
+impl<E: Environment> LinearCombinationBuilder<E> {
+    /// Initializes a new builder for an empty sum.
+    pub fn new() -> Self {
+        Self { linear_combination: E::zero() }
+    }
+
+    /// Returns the sum of all terms as a field.
+    pub fn build(self) -> Field<E> {
+        Field::from(self.linear_combination)
+    }
+}
+
+impl<E: Environment> AddTerm<console::Field<E::Network>, Field<E>> for LinearCombinationBuilder<E> {
+    /// Adds the term `constant * field` to the sum.
+    fn add_term(&mut self, constant: console::Field<E::Network>, field: &Field<E>) {
+        self.linear_combination += &field.linear_combination * *constant;
+    }
+}
+
+impl<E: Environment> Default for LinearCombinationBuilder<E> {
+    /// Initializes a new builder for an empty sum.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E: Environment> Metrics<dyn AddTerm<console::Field<E::Network>, Field<E>>> for LinearCombinationBuilder<E> {
+    /// The number of terms in the sum.
+    type Case = usize;
+
+    fn count(_case: &Self::Case) -> Count {
+        Count::is(0, 0, 0, 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+    const NUM_TERMS: usize = 16;
+
+    fn check_linear_combination_builder(name: &str, mode: Mode, rng: &mut TestRng) {
+        // Sample the constant coefficients and fields.
+        let constants: Vec<console::Field<<Circuit as Environment>::Network>> =
+            (0..NUM_TERMS).map(|_| Uniform::rand(rng)).collect();
+        let values: Vec<console::Field<<Circuit as Environment>::Network>> =
+            (0..NUM_TERMS).map(|_| Uniform::rand(rng)).collect();
+        let fields: Vec<_> = values.iter().map(|value| Field::<Circuit>::new(mode, *value)).collect();
+
+        // Compute the expected sum.
+        let expected: console::Field<_> = constants.iter().zip_eq(&values).map(|(c, x)| *c * x).sum();
+
+        Circuit::scope(name, || {
+            let mut builder = LinearCombinationBuilder::new();
+            for (constant, field) in constants.iter().zip_eq(&fields) {
+                builder.add_term(*constant, field);
+            }
+            let candidate = builder.build();
+            assert_eq!(expected, candidate.eject_value());
+            assert_eq!(mode.is_constant(), candidate.is_constant());
+            assert_count!(
+                LinearCombinationBuilder<Circuit>,
+                AddTerm<console::Field<<Circuit as Environment>::Network>, Field<Circuit>>,
+                &NUM_TERMS
+            );
+        });
+
+        // Ensure the naive sum yields the same value.
+        Circuit::scope(format!("Naive {name}"), || {
+            let candidate = constants
+                .iter()
+                .zip_eq(&fields)
+                .fold(Field::zero(), |sum, (constant, field)| sum + Field::constant(*constant) * field);
+            assert_eq!(expected, candidate.eject_value());
+        });
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_linear_combination_builder() {
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for i in 0..ITERATIONS {
+                let name = format!("LinearCombinationBuilder {mode} {i}");
+                check_linear_combination_builder(&name, mode, &mut rng);
+            }
+        }
+    }
+
+    #[test]
+    fn test_linear_combination_builder_empty() {
+        let candidate = LinearCombinationBuilder::<Circuit>::default().build();
+        assert_eq!(console::Field::zero(), candidate.eject_value());
+        assert!(candidate.is_constant());
+    }
+}
@@ -0,0 +1,118 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> MaskedSum for Field<E> {
+    type Boolean = Boolean<E>;
+    type Output = Result<Field<E>>;
+
+    /// Returns `Σ flags_i * values_i`, i.e. the sum of the values whose flag is set.
+    /// Each term incurs one constraint, unless its value or its flag is a constant.
+    ///
+    /// This method errors if `values` and `flags` differ in length.
+    fn masked_sum(values: &[Field<E>], flags: &[Boolean<E>]) -> Self::Output {
+        // Ensure the number of values and flags match.
+        ensure!(
+            values.len() == flags.len(),
+            "Cannot compute a masked sum of {} values with {} flags",
+            values.len(),
+            flags.len()
+        );
+        // Compute `Σ flags_i * values_i`.
+        Ok(values
+            .iter()
+            .zip_eq(flags)
+            .fold(Field::zero(), |sum, (value, flag)| sum + Field::from_boolean(flag) * value))
+    }
+}
+
+impl<E: Environment> Metrics<dyn MaskedSum<Boolean = Boolean<E>, Output = Result<Field<E>>>> for Field<E> {
+    /// The mode of the values, the mode of the flags, and the number of terms.
+    type Case = (Mode, Mode, u64);
+
+    fn count(case: &Self::Case) -> Count {
+        match case {
+            (Mode::Constant, _, _) | (_, Mode::Constant, _) => Count::is(0, 0, 0, 0),
+            (_, _, length) => Count::is(0, 0, *length, *length),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 10;
+
+    fn check_masked_sum(mode_values: Mode, mode_flags: Mode, length: usize, rng: &mut TestRng) -> Result<()> {
+        for i in 0..ITERATIONS {
+            // Sample the values and flags.
+            let given_values: Vec<console::Field<<Circuit as Environment>::Network>> =
+                (0..length).map(|_| Uniform::rand(rng)).collect();
+            let given_flags: Vec<bool> = (0..length).map(|_| bool::rand(rng)).collect();
+
+            // Compute the expected masked sum.
+            let expected = given_values
+                .iter()
+                .zip_eq(&given_flags)
+                .filter(|(_, flag)| **flag)
+                .fold(console::Field::zero(), |sum, (value, _)| sum + value);
+
+            let values: Vec<_> = given_values.iter().map(|value| Field::<Circuit>::new(mode_values, *value)).collect();
+            let flags: Vec<_> = given_flags.iter().map(|flag| Boolean::<Circuit>::new(mode_flags, *flag)).collect();
+
+            Circuit::scope(format!("{mode_values} {mode_flags} {length} {i}"), || {
+                let candidate = Field::masked_sum(&values, &flags)?;
+                assert_eq!(expected, candidate.eject_value());
+                assert_count!(
+                    Field<Circuit>,
+                    MaskedSum<Boolean = Boolean<Circuit>, Output = Result<Field<Circuit>>>,
+                    &(mode_values, mode_flags, length as u64)
+                );
+                Ok::<_, Error>(())
+            })?;
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_masked_sum() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for mode_values in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_flags in [Mode::Constant, Mode::Public, Mode::Private] {
+                for length in [0, 1, 2, 8] {
+                    check_masked_sum(mode_values, mode_flags, length, &mut rng)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_masked_sum_fails() {
+        let values = vec![Field::<Circuit>::new(Mode::Private, console::Field::one()); 2];
+        let flags = vec![Boolean::<Circuit>::new(Mode::Private, true); 3];
+
+        // Ensure a mismatch in the number of values and flags fails.
+        assert!(Field::masked_sum(&values, &flags).is_err());
+        assert!(Field::masked_sum(&values, &flags[..1]).is_err());
+        assert!(Field::masked_sum(&[], &flags).is_err());
+    }
+}
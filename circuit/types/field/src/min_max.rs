@@ -0,0 +1,196 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> MinMax for Field<E> {
+    type Output = Field<E>;
+
+    /// Returns the smaller of `a` and `b`.
+    ///
+    /// Note: Field elements are compared by their canonical representatives in `[0, BaseField::MODULUS)`,
+    /// and *not* by any signed or modular interpretation. For example, `-1` is the largest field element.
+    fn min(a: &Field<E>, b: &Field<E>) -> Self::Output {
+        Field::ternary(&a.is_less_than_canonical(b), a, b)
+    }
+
+    /// Returns the larger of `a` and `b`.
+    ///
+    /// Note: Field elements are compared by their canonical representatives in `[0, BaseField::MODULUS)`,
+    /// and *not* by any signed or modular interpretation. For example, `-1` is the largest field element.
+    fn max(a: &Field<E>, b: &Field<E>) -> Self::Output {
+        Field::ternary(&a.is_less_than_canonical(b), b, a)
+    }
+}
+
+impl<E: Environment> Metrics<dyn MinMax<Output = Field<E>>> for Field<E> {
+    type Case = (Mode, Mode);
+
+    /// Returns the number of constants, public variables, private variables, and constraints
+    /// to compute either `Field::min` or `Field::max`, for the given modes of `a` and `b`.
+    fn count(case: &Self::Case) -> Count {
+        match case {
+            (Mode::Constant, Mode::Constant) => Count::is(0, 0, 0, 0),
+            // The canonicity check on the variable costs 252 private variables and 253 constraints,
+            // the comparison costs at most 253 constants, 506 private variables, and 507 constraints,
+            // and the selection costs at most 1 private variable and 1 constraint.
+            (Mode::Constant, _) | (_, Mode::Constant) => Count::less_than(253, 0, 759, 761),
+            // The canonicity checks cost 504 private variables and 506 constraints,
+            // the comparison costs 1012 private variables and 1014 constraints,
+            // and the selection costs 1 private variable and 1 constraint.
+            (_, _) => Count::is(0, 0, 1517, 1521),
+        }
+    }
+}
+
+impl<E: Environment> OutputMode<dyn MinMax<Output = Field<E>>> for Field<E> {
+    type Case = (Mode, Mode);
+
+    /// Returns the mode of the output of either `Field::min` or `Field::max`, for the given modes of `a` and `b`.
+    ///
+    /// Note: If `b` is the constant zero, the comparison `a < b` is the constant `false`,
+    /// in which case the output is `b` for `Field::min` and `a` for `Field::max`, in their respective modes.
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case {
+            (Mode::Constant, Mode::Constant) => Mode::Constant,
+            (_, _) => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_min_max(
+        name: &str,
+        first: console::Field<<Circuit as Environment>::Network>,
+        second: console::Field<<Circuit as Environment>::Network>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        // Compute the expected values by the canonical ordering of `console::Field`.
+        let (expected_min, expected_max) = match first < second {
+            true => (first, second),
+            false => (second, first),
+        };
+
+        // Note: The inputs are initialized for each operation, as their bits are cached on first use.
+        let a = Field::<Circuit>::new(mode_a, first);
+        let b = Field::<Circuit>::new(mode_b, second);
+        Circuit::scope(format!("Min {name}"), || {
+            let candidate = Field::min(&a, &b);
+            assert_eq!(expected_min, candidate.eject_value());
+            assert_count!(Field<Circuit>, MinMax<Output = Field<Circuit>>, &(mode_a, mode_b));
+            assert_output_mode!(Field<Circuit>, MinMax<Output = Field<Circuit>>, &(mode_a, mode_b), candidate);
+        });
+        Circuit::reset();
+
+        let a = Field::<Circuit>::new(mode_a, first);
+        let b = Field::<Circuit>::new(mode_b, second);
+        Circuit::scope(format!("Max {name}"), || {
+            let candidate = Field::max(&a, &b);
+            assert_eq!(expected_max, candidate.eject_value());
+            assert_count!(Field<Circuit>, MinMax<Output = Field<Circuit>>, &(mode_a, mode_b));
+            assert_output_mode!(Field<Circuit>, MinMax<Output = Field<Circuit>>, &(mode_a, mode_b), candidate);
+        });
+        Circuit::reset();
+    }
+
+    fn run_test(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+
+            let name = format!("{mode_a} {mode_b} {i}");
+            check_min_max(&name, first, second, mode_a, mode_b);
+            check_min_max(&name, second, first, mode_a, mode_b);
+            check_min_max(&name, first, first, mode_a, mode_b);
+        }
+
+        // Ensure `-1` is ordered as the largest field element.
+        let one = console::Field::one();
+        check_min_max("-1 and 1", -one, one, mode_a, mode_b);
+    }
+
+    fn check_forged_bits(operation: impl Fn(&Field<Circuit>, &Field<Circuit>) -> Field<Circuit>) {
+        // Sample `0` with the forged bits of `BaseField::MODULUS`, which would order it above `1`.
+        let a = crate::helpers::to_bits_with_proof::test_helpers::sample_zero_with_forged_bits();
+        let b = Field::<Circuit>::new(Mode::Private, console::Field::one());
+        assert!(Circuit::is_satisfied());
+
+        // Ensure the forged bits are rejected, as they are not less than the base field modulus.
+        let _candidate = operation(&a, &b);
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_min_max_forged_bits() {
+        check_forged_bits(|a, b| Field::min(a, b));
+        check_forged_bits(|a, b| Field::max(a, b));
+    }
+
+    #[test]
+    fn test_constant_min_max_constant() {
+        run_test(Mode::Constant, Mode::Constant);
+    }
+
+    #[test]
+    fn test_constant_min_max_public() {
+        run_test(Mode::Constant, Mode::Public);
+    }
+
+    #[test]
+    fn test_constant_min_max_private() {
+        run_test(Mode::Constant, Mode::Private);
+    }
+
+    #[test]
+    fn test_public_min_max_constant() {
+        run_test(Mode::Public, Mode::Constant);
+    }
+
+    #[test]
+    fn test_public_min_max_public() {
+        run_test(Mode::Public, Mode::Public);
+    }
+
+    #[test]
+    fn test_public_min_max_private() {
+        run_test(Mode::Public, Mode::Private);
+    }
+
+    #[test]
+    fn test_private_min_max_constant() {
+        run_test(Mode::Private, Mode::Constant);
+    }
+
+    #[test]
+    fn test_private_min_max_public() {
+        run_test(Mode::Private, Mode::Public);
+    }
+
+    #[test]
+    fn test_private_min_max_private() {
+        run_test(Mode::Private, Mode::Private);
+    }
+}
@@ -0,0 +1,112 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> MulAdd<Field<E>> for Field<E> {
+    type Output = Field<E>;
+
+    /// Returns `self * mul_by + add`, enforced with a single constraint.
+    fn mul_add(&self, mul_by: &Field<E>, add: &Field<E>) -> Self::Output {
+        match self.is_constant() || mul_by.is_constant() {
+            // If either factor is a constant, the product is a linear combination and incurs no constraints.
+            true => (self * mul_by) + add,
+            false => {
+                let output = witness!(|self, mul_by, add| self * mul_by + add);
+
+                // Ensure self * mul_by == output - add.
+                E::enforce(|| (self, mul_by, &output - add));
+
+                output
+            }
+        }
+    }
+}
+
+impl<E: Environment> Metrics<dyn MulAdd<Field<E>, Output = Field<E>>> for Field<E> {
+    type Case = (Mode, Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        match case.0.is_constant() || case.1.is_constant() {
+            true => Count::is(0, 0, 0, 0),
+            false => Count::is(0, 0, 1, 1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_mul_add(
+        name: &str,
+        expected: &console::Field<<Circuit as Environment>::Network>,
+        a: &Field<Circuit>,
+        b: &Field<Circuit>,
+        c: &Field<Circuit>,
+    ) {
+        Circuit::scope(name, || {
+            let candidate = a.mul_add(b, c);
+            assert_eq!(*expected, candidate.eject_value());
+            assert_count!(MulAdd(Field, Field) => Field, &(a.eject_mode(), b.eject_mode(), c.eject_mode()));
+        });
+        // Ensure the naive `a * b + c` yields the same value, at the same cost.
+        Circuit::scope(format!("Naive {name}"), || {
+            let candidate = a * b + c;
+            assert_eq!(*expected, candidate.eject_value());
+            assert_count!(MulAdd(Field, Field) => Field, &(a.eject_mode(), b.eject_mode(), c.eject_mode()));
+        });
+        Circuit::reset();
+    }
+
+    fn run_test(mode_a: Mode, mode_b: Mode, mode_c: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+            let third = Uniform::rand(&mut rng);
+
+            let a = Field::<Circuit>::new(mode_a, first);
+            let b = Field::<Circuit>::new(mode_b, second);
+            let c = Field::<Circuit>::new(mode_c, third);
+
+            let name = format!("MulAdd: a * b + c {i}");
+            check_mul_add(&name, &(first * second + third), &a, &b, &c);
+
+            // Test zero.
+            let zero = console::Field::<<Circuit as Environment>::Network>::zero();
+            let name = format!("MulAdd: a * 0 + c {i}");
+            check_mul_add(&name, &third, &a, &Field::new(mode_b, zero), &c);
+            let name = format!("MulAdd: a * b + 0 {i}");
+            check_mul_add(&name, &(first * second), &a, &b, &Field::new(mode_c, zero));
+        }
+    }
+
+    #[test]
+    fn test_mul_add() {
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                for mode_c in [Mode::Constant, Mode::Public, Mode::Private] {
+                    run_test(mode_a, mode_b, mode_c);
+                }
+            }
+        }
+    }
+}
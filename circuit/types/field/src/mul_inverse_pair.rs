@@ -0,0 +1,124 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> MulInversePair<Field<E>> for Field<E> {
+    type Output = (Field<E>, Field<E>, Boolean<E>);
+
+    /// Returns `(self * other, (self * other)^(-1), self * other != 0)`, where the inverse is zero if the product is zero.
+    ///
+    /// The product is computed once, and a single inverse is witnessed for it.
+    fn mul_inverse_pair(&self, other: &Field<E>) -> Self::Output {
+        // Compute the product.
+        let product = self * other;
+
+        // Compute a boolean that is `true` if the product is non-zero.
+        let is_nonzero: Boolean<E> = witness!(|product| !product.is_zero());
+        // Witness the inverse of the product, which is zero if the product is zero.
+        let inverse: Field<E> = witness!(|product| match product.inverse() {
+            Ok(inverse) => inverse,
+            _ => console::Field::zero(),
+        });
+
+        // Negate `is_nonzero`.
+        let is_zero = !is_nonzero.clone();
+
+        // Ensure `product * inverse == is_nonzero`.
+        E::enforce(|| (&product, &inverse, &is_nonzero));
+        // Ensure `product * !is_nonzero == 0`, so that `is_nonzero` is set if the product is non-zero.
+        E::enforce(|| (&product, &is_zero, E::zero()));
+        // Ensure `inverse * !is_nonzero == 0`, so that the inverse is zero if the product is zero.
+        E::enforce(|| (&inverse, &is_zero, E::zero()));
+
+        (product, inverse, is_nonzero)
+    }
+}
+
+impl<E: Environment> Metrics<dyn MulInversePair<Field<E>, Output = (Field<E>, Field<E>, Boolean<E>)>> for Field<E> {
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        match case {
+            (Mode::Constant, Mode::Constant) => Count::is(2, 0, 0, 0),
+            (Mode::Constant, _) | (_, Mode::Constant) => Count::is(0, 0, 2, 4),
+            (_, _) => Count::is(0, 0, 3, 5),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_mul_inverse_pair(
+        name: &str,
+        first: console::Field<<Circuit as Environment>::Network>,
+        second: console::Field<<Circuit as Environment>::Network>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Field::<Circuit>::new(mode_a, first);
+        let b = Field::<Circuit>::new(mode_b, second);
+
+        let expected_product = first * second;
+        let expected_inverse = expected_product.inverse().unwrap_or_else(|_| console::Field::zero());
+
+        Circuit::scope(name, || {
+            let (product, inverse, is_nonzero) = a.mul_inverse_pair(&b);
+            assert_eq!(expected_product, product.eject_value());
+            assert_eq!(expected_inverse, inverse.eject_value());
+            assert_eq!(!expected_product.is_zero(), is_nonzero.eject_value());
+            assert_count!(
+                Field<Circuit>,
+                MulInversePair<Field<Circuit>, Output = (Field<Circuit>, Field<Circuit>, Boolean<Circuit>)>,
+                &(mode_a, mode_b)
+            );
+        });
+        Circuit::reset();
+    }
+
+    fn run_test(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+
+            let name = format!("MulInversePair: a * b {i}");
+            check_mul_inverse_pair(&name, first, second, mode_a, mode_b);
+
+            // Check a zero product.
+            let zero = console::Field::<<Circuit as Environment>::Network>::zero();
+            let name = format!("MulInversePair: a * 0 {i}");
+            check_mul_inverse_pair(&name, first, zero, mode_a, mode_b);
+            let name = format!("MulInversePair: 0 * b {i}");
+            check_mul_inverse_pair(&name, zero, second, mode_a, mode_b);
+        }
+    }
+
+    #[test]
+    fn test_mul_inverse_pair() {
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                run_test(mode_a, mode_b);
+            }
+        }
+    }
+}
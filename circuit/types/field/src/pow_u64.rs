@@ -0,0 +1,209 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> PowU64 for Field<E> {
+    type Output = Field<E>;
+
+    /// Returns `self^exponent`, for a constant `exponent`, using square-and-multiply.
+    /// For a variable `self`, this incurs one multiplication for each bit after the most significant set bit,
+    /// and one for each set bit after the most significant set bit.
+    fn pow_u64(&self, exponent: u64) -> Self::Output {
+        // Initialize the output.
+        let mut output = Field::one();
+        // Note: The leading zero bits are skipped, as squaring `1` is redundant.
+        for i in (0..u64::BITS - exponent.leading_zeros()).rev() {
+            // Square the output.
+            output = output.square();
+            // If the bit is set, set the output to `output * self`.
+            if (exponent >> i) & 1 == 1 {
+                output *= self;
+            }
+        }
+        output
+    }
+}
+
+impl<E: Environment> PowU64Windowed for Field<E> {
+    type Output = Field<E>;
+
+    /// Returns `self^exponent`, for a constant `exponent`, using fixed-window exponentiation.
+    ///
+    /// The exponent is split into digits of `window` bits, and `self^d` is precomputed for each digit value `d`
+    /// up to the largest digit in the exponent. Then, for each digit, the output is squared `window` times
+    /// and multiplied by the precomputed power for the digit. For exponents with many set bits, this incurs
+    /// fewer multiplications than `pow_u64`.
+    ///
+    /// This method halts if `window` is zero or exceeds `MAX_POW_WINDOW`.
+    fn pow_u64_windowed(&self, exponent: u64, window: u32) -> Self::Output {
+        // Ensure the window size is valid.
+        if window == 0 || window > Self::MAX_POW_WINDOW {
+            E::halt(format!("Attempted to exponentiate with a window of {window} bits (max {})", Self::MAX_POW_WINDOW))
+        }
+
+        // Split the exponent into digits, from the most significant digit.
+        let digits = Self::pow_window_digits(exponent, window);
+
+        // Precompute `self^d` for each `d` in `1..=max_digit`.
+        let max_digit = digits.iter().max().copied().unwrap_or(0);
+        let mut powers: Vec<Field<E>> = Vec::with_capacity(max_digit as usize);
+        for _ in 0..max_digit {
+            let power = match powers.last() {
+                Some(previous) => previous * self,
+                None => self.clone(),
+            };
+            powers.push(power);
+        }
+
+        // Initialize the output.
+        let mut output = Field::one();
+        for (i, digit) in digits.into_iter().enumerate() {
+            // Square the output `window` times, which is redundant for the most significant digit.
+            if i > 0 {
+                for _ in 0..window {
+                    output = output.square();
+                }
+            }
+            // If the digit is nonzero, set the output to `output * self^digit`.
+            if digit > 0 {
+                output *= &powers[digit as usize - 1];
+            }
+        }
+        output
+    }
+}
+
+impl<E: Environment> Field<E> {
+    /// The maximum window size (in bits) for `pow_u64_windowed`.
+    const MAX_POW_WINDOW: u32 = 8;
+
+    /// Returns the digits of `window` bits of the given exponent, from the most significant digit,
+    /// where the most significant digit is nonzero.
+    fn pow_window_digits(exponent: u64, window: u32) -> Vec<u64> {
+        let num_bits = u64::BITS - exponent.leading_zeros();
+        let num_digits = (num_bits + window - 1) / window;
+        (0..num_digits).rev().map(|i| (exponent >> (i * window)) & ((1 << window) - 1)).collect()
+    }
+}
+
+impl<E: Environment> Metrics<dyn PowU64<Output = Field<E>>> for Field<E> {
+    /// The mode of `self`, and the exponent.
+    type Case = (Mode, u64);
+
+    fn count(case: &Self::Case) -> Count {
+        match case {
+            (Mode::Constant, _) | (_, 0) => Count::is(0, 0, 0, 0),
+            (_, exponent) => {
+                // Count the squares and multiplications after the most significant set bit.
+                let num_squares = (u64::BITS - exponent.leading_zeros() - 1) as u64;
+                let num_multiplications = (exponent.count_ones() - 1) as u64;
+                let num_private = num_squares + num_multiplications;
+                Count::is(0, 0, num_private, num_private)
+            }
+        }
+    }
+}
+
+impl<E: Environment> Metrics<dyn PowU64Windowed<Output = Field<E>>> for Field<E> {
+    /// The mode of `self`, the exponent, and the window size.
+    type Case = (Mode, u64, u32);
+
+    fn count(case: &Self::Case) -> Count {
+        match case {
+            (Mode::Constant, _, _) | (_, 0, _) => Count::is(0, 0, 0, 0),
+            (_, exponent, window) => {
+                let digits = Self::pow_window_digits(*exponent, *window);
+                // Count the multiplications to precompute the powers, after `self^1`.
+                let num_precomputations = digits.iter().max().copied().unwrap_or(0).saturating_sub(1);
+                // Count the squares for each digit after the most significant digit.
+                let num_squares = (digits.len() as u64 - 1) * *window as u64;
+                // Count the multiplications for each nonzero digit after the most significant digit.
+                let num_multiplications = digits.iter().skip(1).filter(|digit| **digit > 0).count() as u64;
+                let num_private = num_precomputations + num_squares + num_multiplications;
+                Count::is(0, 0, num_private, num_private)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::{Circuit, Measurement};
+
+    const ITERATIONS: u64 = 10;
+
+    fn check_pow_u64(mode: Mode, exponent: u64, rng: &mut TestRng) {
+        let given: console::Field<<Circuit as Environment>::Network> = Uniform::rand(rng);
+        let expected = given.pow(console::Field::from_u64(exponent));
+        let a = Field::<Circuit>::new(mode, given);
+
+        Circuit::scope(format!("{mode} {exponent}"), || {
+            let candidate = a.pow_u64(exponent);
+            assert_eq!(expected, candidate.eject_value());
+            assert_count!(Field<Circuit>, PowU64<Output = Field<Circuit>>, &(mode, exponent));
+        });
+        Circuit::reset();
+
+        for window in 1..=6 {
+            Circuit::scope(format!("{mode} {exponent} {window}"), || {
+                let candidate = a.pow_u64_windowed(exponent, window);
+                assert_eq!(expected, candidate.eject_value());
+                assert_count!(Field<Circuit>, PowU64Windowed<Output = Field<Circuit>>, &(mode, exponent, window));
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_pow_u64() {
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for exponent in [0, 1, 2, 3, 5, 16, 255, 0xdead_beef, u64::MAX - 1, u64::MAX] {
+                check_pow_u64(mode, exponent, &mut rng);
+            }
+            for _ in 0..ITERATIONS {
+                check_pow_u64(mode, u64::rand(&mut rng), &mut rng);
+            }
+        }
+    }
+
+    #[test]
+    fn test_pow_u64_windowed_is_cheaper_for_dense_exponents() {
+        for exponent in [u64::MAX, u64::MAX - 1, 0x7fff_ffff_ffff_fffe, 0xffff_ffff] {
+            let binary = count!(Field<Circuit>, PowU64<Output = Field<Circuit>>, &(Mode::Private, exponent));
+            let windowed =
+                count!(Field<Circuit>, PowU64Windowed<Output = Field<Circuit>>, &(Mode::Private, exponent, 4));
+            match (binary.2, windowed.2) {
+                (Measurement::Exact(binary), Measurement::Exact(windowed)) => {
+                    assert!(windowed < binary, "{exponent}: {windowed} >= {binary}")
+                }
+                _ => panic!("Expected exact counts for {exponent}"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_pow_u64_windowed_halts() {
+        let a = Field::<Circuit>::new(Mode::Private, console::Field::one());
+        for window in [0, 9] {
+            let result = std::panic::catch_unwind(|| a.pow_u64_windowed(3, window));
+            assert!(result.is_err());
+        }
+    }
+}
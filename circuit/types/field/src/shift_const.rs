@@ -0,0 +1,199 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> ShlConst for Field<E> {
+    type Output = Field<E>;
+
+    /// Returns `self * 2^k`, i.e. the left shift of `self` by `k` bits (modulo `BaseField::MODULUS`).
+    /// As `2^k` is a constant, this method incurs no constraints.
+    ///
+    /// This method halts if `k` is not less than the number of bits in the base field.
+    fn shl_const(&self, k: u32) -> Self::Output {
+        (&self.linear_combination * Self::power_of_two(k)).into()
+    }
+}
+
+impl<E: Environment> ShrConst for Field<E> {
+    type Output = Field<E>;
+
+    /// Returns `self / 2^k`, i.e. the right shift of `self` by `k` bits, for a `self` that is divisible by `2^k`.
+    /// As `2^k` is a constant, this method incurs no constraints.
+    ///
+    /// Note: The divisibility of `self` by `2^k` is *not* enforced for a variable `self`, and must be
+    /// guaranteed by the caller. Otherwise, the output is `self * 2^{-k}` in the field, and not a shift.
+    ///
+    /// This method halts if `k` is not less than the number of bits in the base field,
+    /// or if `self` is a constant that is not divisible by `2^k`.
+    fn shr_const(&self, k: u32) -> Self::Output {
+        // Ensure the constant is divisible by `2^k`, i.e. its lower `k` bits are zero.
+        if self.is_constant() && self.eject_value().to_bits_le().iter().take(k as usize).any(|bit| *bit) {
+            E::halt(format!("Field element {} is not divisible by 2^{k}", self.eject_value()))
+        }
+
+        // Compute `2^{-k}`.
+        let inverse = match Self::power_of_two(k).inverse() {
+            Some(inverse) => inverse,
+            None => E::halt("Failed to invert a power of two in the base field"),
+        };
+        (&self.linear_combination * inverse).into()
+    }
+}
+
+impl<E: Environment> Field<E> {
+    /// Returns `2^k` as a base field element.
+    ///
+    /// This method halts if `k` is not less than the number of bits in the base field.
+    pub(crate) fn power_of_two(k: u32) -> E::BaseField {
+        // Ensure the shift is within the size of the base field.
+        if k as usize >= E::BaseField::size_in_bits() {
+            E::halt(format!("Attempted to shift by {k} bits in a {}-bit base field", E::BaseField::size_in_bits()))
+        }
+
+        let mut power = E::BaseField::one();
+        for _ in 0..k {
+            power.double_in_place();
+        }
+        power
+    }
+}
+
+impl<E: Environment> Metrics<dyn ShlConst<Output = Field<E>>> for Field<E> {
+    type Case = (Mode, u32);
+
+    fn count(_case: &Self::Case) -> Count {
+        Count::is(0, 0, 0, 0)
+    }
+}
+
+impl<E: Environment> OutputMode<dyn ShlConst<Output = Field<E>>> for Field<E> {
+    type Case = (Mode, u32);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case {
+            (Mode::Constant, _) => Mode::Constant,
+            (Mode::Public, 0) => Mode::Public,
+            _ => Mode::Private,
+        }
+    }
+}
+
+impl<E: Environment> Metrics<dyn ShrConst<Output = Field<E>>> for Field<E> {
+    type Case = (Mode, u32);
+
+    fn count(_case: &Self::Case) -> Count {
+        Count::is(0, 0, 0, 0)
+    }
+}
+
+impl<E: Environment> OutputMode<dyn ShrConst<Output = Field<E>>> for Field<E> {
+    type Case = (Mode, u32);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case {
+            (Mode::Constant, _) => Mode::Constant,
+            (Mode::Public, 0) => Mode::Public,
+            _ => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_shl_const(name: &str, mode: Mode, rng: &mut TestRng) {
+        for k in [0, 1, 7, 64, 128, 252] {
+            for _ in 0..ITERATIONS {
+                // Sample a random element.
+                let given: console::Field<<Circuit as Environment>::Network> = Uniform::rand(rng);
+                let candidate = Field::<Circuit>::new(mode, given);
+
+                // Compute the expected value as `given * 2^k`.
+                let expected = (0..k).fold(given, |acc, _| acc.double());
+
+                Circuit::scope(name, || {
+                    let result = candidate.shl_const(k);
+                    assert_eq!(expected, result.eject_value());
+                    assert_count!(Field<Circuit>, ShlConst<Output = Field<Circuit>>, &(mode, k));
+                    assert_output_mode!(Field<Circuit>, ShlConst<Output = Field<Circuit>>, &(mode, k), result);
+                });
+                Circuit::reset();
+            }
+        }
+    }
+
+    fn check_shr_const(name: &str, mode: Mode, rng: &mut TestRng) {
+        for k in [0, 1, 7, 64, 128] {
+            for _ in 0..ITERATIONS {
+                // Sample a random element that fits in `128` bits, and shift it left to be divisible by `2^k`.
+                let value = console::Field::<<Circuit as Environment>::Network>::from_u128(Uniform::rand(rng));
+                let given = (0..k).fold(value, |acc, _| acc.double());
+                let candidate = Field::<Circuit>::new(mode, given);
+
+                Circuit::scope(name, || {
+                    let result = candidate.shr_const(k);
+                    assert_eq!(value, result.eject_value());
+                    assert_count!(Field<Circuit>, ShrConst<Output = Field<Circuit>>, &(mode, k));
+                    assert_output_mode!(Field<Circuit>, ShrConst<Output = Field<Circuit>>, &(mode, k), result);
+                });
+                Circuit::reset();
+            }
+        }
+    }
+
+    #[test]
+    fn test_shl_const() {
+        let mut rng = TestRng::default();
+
+        check_shl_const("Constant", Mode::Constant, &mut rng);
+        check_shl_const("Public", Mode::Public, &mut rng);
+        check_shl_const("Private", Mode::Private, &mut rng);
+    }
+
+    #[test]
+    fn test_shr_const() {
+        let mut rng = TestRng::default();
+
+        check_shr_const("Constant", Mode::Constant, &mut rng);
+        check_shr_const("Public", Mode::Public, &mut rng);
+        check_shr_const("Private", Mode::Private, &mut rng);
+    }
+
+    #[test]
+    fn test_shr_const_not_divisible_halts() {
+        let one = console::Field::<<Circuit as Environment>::Network>::one();
+        let result = std::panic::catch_unwind(|| Field::<Circuit>::new(Mode::Constant, one).shr_const(1));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_shift_const_out_of_range_halts() {
+        let one = console::Field::<<Circuit as Environment>::Network>::one();
+        let k = <Circuit as Environment>::BaseField::size_in_bits() as u32;
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            let result = std::panic::catch_unwind(|| Field::<Circuit>::new(mode, one).shl_const(k));
+            assert!(result.is_err());
+            let result = std::panic::catch_unwind(|| Field::<Circuit>::new(mode, one).shr_const(k));
+            assert!(result.is_err());
+            Circuit::reset();
+        }
+    }
+}
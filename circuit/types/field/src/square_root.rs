@@ -32,6 +32,59 @@ impl<E: Environment> SquareRoot for Field<E> {
     }
 }
 
+impl<E: Environment> IsSquare for Field<E> {
+    type Boolean = Boolean<E>;
+
+    /// Returns `true` if `self` is a quadratic residue (including zero), without returning its square root.
+    ///
+    /// For a fixed quadratic non-residue `n`, the square root `r` of `self` (if `self` is a residue)
+    /// or of `n * self` (otherwise) is witnessed, and `r * r == self * (is_square ? 1 : n)` is enforced.
+    /// As `n * self` is a residue if and only if `self` is a non-zero non-residue, the flag is sound,
+    /// except for zero, which satisfies both cases. As such, `r * w == !is_square` is also enforced
+    /// for a witness `w`, which requires `r` (and hence `self`) to be non-zero if the flag is `false`.
+    fn is_square_only(&self) -> Self::Boolean {
+        // Case 1: Constant
+        if self.is_constant() {
+            return Boolean::constant(self.eject_value().is_quadratic_residue());
+        }
+
+        // Find the smallest quadratic non-residue `n`.
+        let one = console::Field::<E::Network>::one();
+        let mut non_residue = one + one;
+        while non_residue.is_quadratic_residue() {
+            non_residue += one;
+        }
+
+        // Case 2: Variable
+        let is_square: Boolean<E> = witness!(|self| self.is_quadratic_residue());
+        // Compute the factor as `is_square ? 1 : n`, i.e. `n + (1 - n) * is_square`.
+        let factor: Field<E> = (&Field::from_boolean(&is_square).linear_combination * *(one - non_residue)).into();
+        let factor = factor.add_constant(non_residue);
+        // Compute the residue as `self * factor`.
+        let residue = self * factor;
+
+        // Witness the square root of the residue.
+        let root: Field<E> = witness!(|residue| match residue.square_root() {
+            Ok(root) => root,
+            _ => console::Field::zero(),
+        });
+        // Ensure `root * root == residue`.
+        E::enforce(|| (&root, &root, &residue));
+
+        // Negate `is_square`.
+        let is_not_square = !is_square.clone();
+        // Witness the inverse of the root if `is_square` is `false`, and zero otherwise.
+        let multiplier: Field<E> = witness!(|root, is_square| match (is_square, root.inverse()) {
+            (false, Ok(inverse)) => inverse,
+            _ => console::Field::zero(),
+        });
+        // Ensure `root * multiplier == is_not_square`.
+        E::enforce(|| (&root, &multiplier, &is_not_square));
+
+        is_square
+    }
+}
+
 impl<E: Environment> Metrics<dyn SquareRoot<Output = Field<E>>> for Field<E> {
     type Case = Mode;
 
@@ -43,6 +96,17 @@ impl<E: Environment> Metrics<dyn SquareRoot<Output = Field<E>>> for Field<E> {
     }
 }
 
+impl<E: Environment> Metrics<dyn IsSquare<Boolean = Boolean<E>>> for Field<E> {
+    type Case = Mode;
+
+    fn count(case: &Self::Case) -> Count {
+        match case.is_constant() {
+            true => Count::is(0, 0, 0, 0),
+            false => Count::is(0, 0, 4, 4),
+        }
+    }
+}
+
 impl<E: Environment> OutputMode<dyn SquareRoot<Output = Field<E>>> for Field<E> {
     type Case = Mode;
 
@@ -80,6 +144,31 @@ mod tests {
         }
     }
 
+    fn check_is_square_only(name: &str, mode: Mode, rng: &mut TestRng) {
+        let zero = console::Field::<<Circuit as Environment>::Network>::zero();
+        for given in std::iter::once(zero).chain((0..ITERATIONS).map(|_| Uniform::rand(rng))) {
+            // Compute the expected flag, where zero is a square.
+            let expected = given.square_root().is_ok();
+            let input = Field::<Circuit>::new(mode, given);
+
+            Circuit::scope(name, || {
+                let candidate = input.is_square_only();
+                assert_eq!(expected, candidate.eject_value());
+                assert_count!(IsSquare<Boolean>() => Field, &mode);
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_is_square_only() {
+        let mut rng = TestRng::default();
+
+        check_is_square_only("Constant", Mode::Constant, &mut rng);
+        check_is_square_only("Public", Mode::Public, &mut rng);
+        check_is_square_only("Private", Mode::Private, &mut rng);
+    }
+
     #[test]
     fn test_square_root() {
         let mut rng = TestRng::default();
@@ -0,0 +1,157 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> SubWithBorrow<Field<E>> for Field<E> {
+    type Output = (Field<E>, Boolean<E>);
+
+    /// Returns `(self - other) mod 2^num_bits` and a borrow flag that is `true` if `self < other`,
+    /// for `self` and `other` that are `num_bits`-bit values, which is enforced.
+    ///
+    /// This is computed by decomposing `self - other + 2^num_bits` into `num_bits + 1` bits, which enforces
+    /// the difference fits in `num_bits` bits, and sets the borrow flag to the negation of the uppermost bit.
+    ///
+    /// This method halts if `num_bits` is not less than the number of data bits in the base field.
+    fn sub_with_borrow(&self, other: &Field<E>, num_bits: usize) -> Self::Output {
+        // Ensure `2^(num_bits + 1)` does not exceed the data capacity of the base field,
+        // so the offset difference does not wrap around the modulus.
+        if num_bits >= E::BaseField::size_in_data_bits() {
+            E::halt(format!(
+                "Attempted to subtract {num_bits}-bit values in a field with {} data bits",
+                E::BaseField::size_in_data_bits()
+            ))
+        }
+
+        // Ensure `self` and `other` are `num_bits`-bit values.
+        self.to_lower_bits_le(num_bits);
+        other.to_lower_bits_le(num_bits);
+
+        // Compute `self - other + 2^num_bits`, which is in `[0, 2^(num_bits + 1))` for `num_bits`-bit values.
+        let offset_difference = (self - other).add_constant(console::Field::new(Self::power_of_two(num_bits as u32)));
+        // Decompose the offset difference into `num_bits + 1` bits, enforcing the upper bits are zero.
+        let bits_le = offset_difference.to_lower_bits_le(num_bits + 1);
+
+        // Recompose the lower `num_bits` bits as the difference.
+        let difference = Field::from_bits_le(&bits_le[..num_bits]);
+        // The difference borrowed `2^num_bits` if and only if the uppermost bit is not set.
+        let borrow = !bits_le[num_bits].clone();
+
+        (difference, borrow)
+    }
+}
+
+impl<E: Environment> Metrics<dyn SubWithBorrow<Field<E>, Output = (Field<E>, Boolean<E>)>> for Field<E> {
+    /// The modes of `self` and `other`, and the number of bits.
+    type Case = (Mode, Mode, u64);
+
+    fn count(case: &Self::Case) -> Count {
+        match case {
+            (Mode::Constant, Mode::Constant, num_bits) => Count::is(3 * num_bits + 1, 0, 0, 0),
+            (Mode::Constant, _, num_bits) | (_, Mode::Constant, num_bits) => {
+                Count::is(*num_bits, 0, 2 * num_bits + 1, 2 * num_bits + 3)
+            }
+            (_, _, num_bits) => Count::is(0, 0, 3 * num_bits + 1, 3 * num_bits + 4),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::{assert_count_fails, Circuit};
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_sub_with_borrow(mode_a: Mode, mode_b: Mode, num_bits: usize, rng: &mut TestRng) {
+        // Retrieve the mask for `num_bits`-bit values.
+        let mask = match num_bits {
+            64 => u64::MAX,
+            _ => (1u64 << num_bits) - 1,
+        };
+
+        for i in 0..ITERATIONS {
+            let (x, y) = (u64::rand(rng) & mask, u64::rand(rng) & mask);
+            // Alternate the order of the values, to cover both the no-borrow and borrow cases.
+            let (first, second) = match i % 2 == 0 {
+                true => (x.max(y), x.min(y)),
+                false => (x.min(y), x.max(y)),
+            };
+
+            let a = Field::<Circuit>::new(mode_a, console::Field::from_u64(first));
+            let b = Field::<Circuit>::new(mode_b, console::Field::from_u64(second));
+
+            // Compute the expected difference modulo `2^num_bits`, and the expected borrow.
+            let expected = ((first as u128 + (1u128 << num_bits) - second as u128) & mask as u128) as u64;
+            let expected_borrow = first < second;
+
+            Circuit::scope(format!("{mode_a} {mode_b} {num_bits} {i}"), || {
+                let (difference, borrow) = a.sub_with_borrow(&b, num_bits);
+                assert_eq!(console::Field::from_u64(expected), difference.eject_value());
+                assert_eq!(expected_borrow, borrow.eject_value());
+                assert_count!(
+                    Field<Circuit>,
+                    SubWithBorrow<Field<Circuit>, Output = (Field<Circuit>, Boolean<Circuit>)>,
+                    &(mode_a, mode_b, num_bits as u64)
+                );
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_sub_with_borrow() {
+        let mut rng = TestRng::default();
+
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                for num_bits in [1, 8, 32, 64] {
+                    check_sub_with_borrow(mode_a, mode_b, num_bits, &mut rng);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_sub_with_borrow_out_of_range() {
+        // Ensure an input that does not fit in `num_bits` bits is unsatisfiable, even if the difference does.
+        for (first, second) in [(1 << 8, 0), (256, 200), (0, 1 << 8), (1 << 9, 1 << 9)] {
+            let a = Field::<Circuit>::new(Mode::Private, console::Field::from_u64(first));
+            let b = Field::<Circuit>::new(Mode::Private, console::Field::from_u64(second));
+
+            Circuit::scope(format!("sub_with_borrow out of range ({first}, {second})"), || {
+                let _ = a.sub_with_borrow(&b, 8);
+                assert_count_fails!(
+                    Field<Circuit>,
+                    SubWithBorrow<Field<Circuit>, Output = (Field<Circuit>, Boolean<Circuit>)>,
+                    &(Mode::Private, Mode::Private, 8)
+                );
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_sub_with_borrow_halts() {
+        let a = Field::<Circuit>::new(Mode::Private, console::Field::one());
+        let b = Field::<Circuit>::new(Mode::Private, console::Field::zero());
+
+        // Ensure a number of bits exceeding the data capacity of the base field halts.
+        let num_bits = <Circuit as Environment>::BaseField::size_in_data_bits();
+        let result = std::panic::catch_unwind(|| a.sub_with_borrow(&b, num_bits));
+        assert!(result.is_err());
+    }
+}
@@ -92,6 +92,38 @@ impl<E: Environment> Ternary for Field<E> {
     }
 }
 
+impl<E: Environment> ConditionalSelectMany for Field<E> {
+    type Boolean = Boolean<E>;
+    type Output = Result<Vec<Field<E>>>;
+
+    /// Returns `first` if `condition` is `true`, otherwise returns `second`, element-wise over the given vectors.
+    /// The condition is shared across all elements, and costs the same as `first.len()` ternary operations.
+    fn conditional_select_many(condition: &Boolean<E>, first: &[Field<E>], second: &[Field<E>]) -> Self::Output {
+        // Ensure the vectors are of equal length.
+        ensure!(
+            first.len() == second.len(),
+            "Cannot select between vectors of different lengths ({} and {})",
+            first.len(),
+            second.len()
+        );
+        // Select each element, sharing the condition.
+        Ok(first.iter().zip_eq(second).map(|(first, second)| Field::ternary(condition, first, second)).collect())
+    }
+}
+
+impl<E: Environment> Metrics<dyn ConditionalSelectMany<Boolean = Boolean<E>, Output = Result<Vec<Field<E>>>>>
+    for Field<E>
+{
+    /// The modes of `condition`, `first`, and `second`, and the number of elements.
+    type Case = (Mode, Mode, Mode, u64);
+
+    fn count(case: &Self::Case) -> Count {
+        let (mode_condition, mode_a, mode_b, num_elements) = *case;
+        let case = (mode_condition, mode_a, mode_b);
+        <Self as Metrics<dyn Ternary<Boolean = Boolean<E>, Output = Field<E>>>>::count(&case) * num_elements
+    }
+}
+
 impl<E: Environment> Metrics<dyn Ternary<Boolean = Boolean<E>, Output = Field<E>>> for Field<E> {
     type Case = (Mode, Mode, Mode);
 
@@ -478,4 +510,45 @@ mod tests {
         let b = Field::<Circuit>::new(Mode::Private, second);
         check_ternary("true ? Private : Private", expected, condition, a, b);
     }
+
+    #[test]
+    fn test_conditional_select_many() {
+        let mut rng = TestRng::default();
+
+        for condition_mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+                for num_elements in 0..4 {
+                    let first: Vec<console::Field<<Circuit as Environment>::Network>> =
+                        (0..num_elements).map(|_| Uniform::rand(&mut rng)).collect();
+                    let second: Vec<console::Field<<Circuit as Environment>::Network>> =
+                        (0..num_elements).map(|_| Uniform::rand(&mut rng)).collect();
+                    let a = first.iter().map(|value| Field::<Circuit>::new(mode, *value)).collect::<Vec<_>>();
+                    let b = second.iter().map(|value| Field::<Circuit>::new(mode, *value)).collect::<Vec<_>>();
+
+                    for flag in [true, false] {
+                        let condition = Boolean::<Circuit>::new(condition_mode, flag);
+                        let expected = if flag { &first } else { &second };
+
+                        Circuit::scope(format!("{condition_mode} {mode} {num_elements} {flag}"), || {
+                            let candidate = Field::conditional_select_many(&condition, &a, &b).unwrap();
+                            assert_eq!(expected, &candidate.eject_value());
+                            assert_count!(
+                                Field<Circuit>,
+                                ConditionalSelectMany<Boolean = Boolean<Circuit>, Output = Result<Vec<Field<Circuit>>>>,
+                                &(condition_mode, mode, mode, num_elements)
+                            );
+                        });
+                        Circuit::reset();
+                    }
+
+                    // Ensure a length mismatch is rejected.
+                    let condition = Boolean::<Circuit>::new(condition_mode, true);
+                    let mut longer = b.clone();
+                    longer.push(Field::new(mode, Uniform::rand(&mut rng)));
+                    assert!(Field::conditional_select_many(&condition, &a, &longer).is_err());
+                    Circuit::reset();
+                }
+            }
+        }
+    }
 }
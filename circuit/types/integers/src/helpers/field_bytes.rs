@@ -0,0 +1,228 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// Unary operator for converting to a list of little-endian bytes.
+///
+/// Note: This operator is declared here, rather than in the environment, as it is implemented for `Field`,
+/// and is named apart from `snarkvm_utilities::ToBytes`, so that the two may be imported together.
+pub trait ToBytesCircuit {
+    type Byte;
+
+    /// Returns the circuit as a list of little-endian bytes.
+    fn to_bytes_le(&self) -> Vec<Self::Byte>;
+}
+
+/// Unary operator for instantiating from a list of little-endian bytes.
+///
+/// Note: This operator is declared here, rather than in the environment, as it is implemented for `Field`,
+/// and is named apart from `snarkvm_utilities::FromBytes`, so that the two may be imported together.
+pub trait FromBytesCircuit {
+    type Byte;
+
+    /// Returns a circuit from a list of little-endian bytes.
+    fn from_bytes_le(bytes: &[Self::Byte]) -> Self
+    where
+        Self: Sized;
+}
+
+impl<E: Environment> ToBytesCircuit for Field<E> {
+    type Byte = U8<E>;
+
+    /// Outputs the little-endian byte representation of `self` *with* trailing zeros.
+    /// Enforces that the underlying bits are the canonical representation of `self`,
+    /// such that the bytes are unique for every field element.
+    fn to_bytes_le(&self) -> Vec<Self::Byte> {
+        // Retrieve the little-endian bits of `self`, enforced to be less than `BaseField::MODULUS`.
+        let (mut bits_le, _) = self.to_bits_le_with_proof();
+        // Pad the bits up to a multiple of 8.
+        bits_le.resize((bits_le.len() + 7) / 8 * 8, Boolean::constant(false));
+        // Pack the bits into bytes.
+        bits_le.chunks(8).map(U8::from_bits_le).collect()
+    }
+}
+
+impl<E: Environment> FromBytesCircuit for Field<E> {
+    type Byte = U8<E>;
+
+    /// Initializes a new base field element from a list of little-endian bytes.
+    /// Enforces that the bytes encode a value less than `BaseField::MODULUS`,
+    /// such that a byte sequence for a value that wraps the modulus is unsatisfiable.
+    fn from_bytes_le(bytes: &[Self::Byte]) -> Self {
+        // Unpack the bytes into little-endian bits.
+        let bits_le = bytes.iter().flat_map(|byte| byte.to_bits_le()).collect::<Vec<_>>();
+        // Note: `Field::from_bits_le` enforces that any bits beyond the size of the base field are zero,
+        // and that the bits encode a value less than `BaseField::MODULUS`.
+        Field::from_bits_le(&bits_le)
+    }
+}
+
+impl<E: Environment> Metrics<dyn ToBytesCircuit<Byte = U8<E>>> for Field<E> {
+    type Case = Mode;
+
+    fn count(case: &Self::Case) -> Count {
+        match case {
+            Mode::Constant => Count::is(253, 0, 0, 0),
+            // The bit decomposition costs 253 private variables and 254 constraints,
+            // and the canonicity check costs 252 private variables and 253 constraints.
+            _ => Count::is(0, 0, 505, 507),
+        }
+    }
+}
+
+impl<E: Environment> Metrics<dyn FromBytesCircuit<Byte = U8<E>>> for Field<E> {
+    type Case = Vec<Mode>;
+
+    fn count(case: &Self::Case) -> Count {
+        let num_bits = case.len() as u64 * 8;
+        let size_in_bits = E::BaseField::size_in_bits() as u64;
+
+        // If the bytes are constant, or fit within the data bits of the base field, no checks are required.
+        if case.iter().all(|mode| mode.is_constant()) || num_bits <= E::BaseField::size_in_data_bits() as u64 {
+            return Count::is(0, 0, 0, 0);
+        }
+
+        // Checking the excess bits are zero costs `num_excess_bits - 1` private variables
+        // and `num_excess_bits` constraints.
+        let num_excess_bits = num_bits.saturating_sub(size_in_bits);
+        let (excess_private, excess_constraints) = match num_excess_bits {
+            0 => (0, 0),
+            n => (n - 1, n),
+        };
+        // Checking the bits are less than `BaseField::MODULUS` costs `size_in_bits - 1` private variables
+        // and `size_in_bits` constraints, as the least-significant bit of `BaseField::MODULUS - 1` is zero.
+        let num_private = excess_private + size_in_bits - 1;
+        let num_constraints = excess_constraints + size_in_bits;
+
+        match case.iter().any(|mode| mode.is_constant()) {
+            true => Count::less_than(0, 0, num_private, num_constraints),
+            false => Count::is(0, 0, num_private, num_constraints),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    /// Returns the little-endian bytes of the given bits, padded up to a multiple of 8 bits.
+    fn bytes_from_bits_le(bits_le: &[bool]) -> Vec<u8> {
+        bits_le.chunks(8).map(|bits| bits.iter().rev().fold(0u8, |byte, bit| (byte << 1) | (*bit as u8))).collect()
+    }
+
+    /// Returns the little-endian bytes of `BaseField::MODULUS`.
+    fn modulus_bytes_le() -> Vec<u8> {
+        // Compute `BaseField::MODULUS` as `(BaseField::MODULUS - 1) + 1`, over the bits.
+        let mut bits_le = (-console::Field::<<Circuit as Environment>::Network>::one()).to_bits_le();
+        for bit in bits_le.iter_mut() {
+            *bit = !*bit;
+            // Stop once the carry is absorbed.
+            if *bit {
+                break;
+            }
+        }
+        bytes_from_bits_le(&bits_le)
+    }
+
+    fn check_to_bytes_le(mode: Mode, rng: &mut TestRng) {
+        for i in 0..ITERATIONS {
+            // Sample a random element.
+            let expected = Uniform::rand(rng);
+            let candidate = Field::<Circuit>::new(mode, expected);
+
+            Circuit::scope(format!("{mode} {i}"), || {
+                let candidate = candidate.to_bytes_le();
+                assert_eq!(32, candidate.len());
+                let candidate = candidate.iter().map(|byte| *byte.eject_value()).collect::<Vec<_>>();
+                assert_eq!(bytes_from_bits_le(&expected.to_bits_le()), candidate);
+                assert_count!(Field<Circuit>, ToBytesCircuit<Byte = U8<Circuit>>, &mode);
+            });
+            Circuit::reset();
+        }
+    }
+
+    fn check_from_bytes_le(mode: Mode, rng: &mut TestRng) {
+        for i in 0..ITERATIONS {
+            // Sample a random element.
+            let expected = Uniform::rand(rng);
+            let given = Field::<Circuit>::new(mode, expected).to_bytes_le();
+            let modes = given.iter().map(|byte| byte.eject_mode()).collect::<Vec<_>>();
+
+            Circuit::scope(format!("{mode} {i}"), || {
+                let candidate = Field::from_bytes_le(&given);
+                assert_eq!(expected, candidate.eject_value());
+                assert_count!(Field<Circuit>, FromBytesCircuit<Byte = U8<Circuit>>, &modes);
+            });
+            Circuit::reset();
+        }
+    }
+
+    fn check_from_bytes_le_overflow(mode: Mode, bytes: &[u8]) {
+        let given = bytes.iter().map(|byte| U8::<Circuit>::new(mode, console::Integer::new(*byte))).collect::<Vec<_>>();
+        let modes = given.iter().map(|byte| byte.eject_mode()).collect::<Vec<_>>();
+
+        match mode.is_constant() {
+            true => {
+                let result = std::panic::catch_unwind(|| Field::<Circuit>::from_bytes_le(&given));
+                assert!(result.is_err());
+            }
+            false => {
+                Circuit::scope(format!("{mode}"), || {
+                    let _candidate = Field::<Circuit>::from_bytes_le(&given);
+                    assert_count_fails!(Field<Circuit>, FromBytesCircuit<Byte = U8<Circuit>>, &modes);
+                });
+            }
+        }
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_to_bytes_le() {
+        let mut rng = TestRng::default();
+
+        check_to_bytes_le(Mode::Constant, &mut rng);
+        check_to_bytes_le(Mode::Public, &mut rng);
+        check_to_bytes_le(Mode::Private, &mut rng);
+    }
+
+    #[test]
+    fn test_from_bytes_le() {
+        let mut rng = TestRng::default();
+
+        check_from_bytes_le(Mode::Constant, &mut rng);
+        check_from_bytes_le(Mode::Public, &mut rng);
+        check_from_bytes_le(Mode::Private, &mut rng);
+    }
+
+    #[test]
+    fn test_from_bytes_le_overflow() {
+        // Ensure the modulus itself is rejected.
+        let modulus = modulus_bytes_le();
+        check_from_bytes_le_overflow(Mode::Constant, &modulus);
+        check_from_bytes_le_overflow(Mode::Public, &modulus);
+        check_from_bytes_le_overflow(Mode::Private, &modulus);
+
+        // Ensure a value exceeding the size of the base field is rejected.
+        let maximum = [u8::MAX; 32];
+        check_from_bytes_le_overflow(Mode::Constant, &maximum);
+        check_from_bytes_le_overflow(Mode::Public, &maximum);
+        check_from_bytes_le_overflow(Mode::Private, &maximum);
+    }
+}
@@ -16,6 +16,7 @@
 
 use super::*;
 
+pub mod field_bytes;
 pub mod from_bits;
 pub mod from_field;
 pub mod msb;
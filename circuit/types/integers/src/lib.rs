@@ -19,6 +19,7 @@
 #![cfg_attr(test, allow(clippy::assertions_on_result_states))]
 
 mod helpers;
+pub use helpers::field_bytes::{FromBytesCircuit, ToBytesCircuit};
 
 pub mod abs_checked;
 pub mod abs_wrapped;
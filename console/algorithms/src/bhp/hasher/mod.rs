@@ -46,16 +46,6 @@ impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHPHasher<E,
 
     /// Initializes a new instance of BHP with the given domain.
     pub fn setup(domain: &str) -> Result<Self> {
-        // Calculate the maximum window size.
-        let mut maximum_window_size = 0;
-        let mut range = E::BigInteger::from(2_u64);
-        while range < E::Scalar::modulus_minus_one_div_two() {
-            // range < (p-1)/2
-            range.muln(4); // range * 2^4
-            maximum_window_size += 1;
-        }
-        ensure!(WINDOW_SIZE <= maximum_window_size, "The maximum BHP window size is {maximum_window_size}");
-
         // Compute the bases.
         let bases = (0..NUM_WINDOWS)
             .map(|index| {
@@ -75,10 +65,45 @@ impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHPHasher<E,
                 powers
             })
             .collect::<Vec<Vec<Group<E>>>>();
+
+        // Next, compute the random base.
+        let (generator, _, _) =
+            Blake2Xs::hash_to_curve::<E::Affine>(&format!("Aleo.BHP.{NUM_WINDOWS}.{WINDOW_SIZE}.{domain}.Randomizer"));
+        let mut base_power = Group::<E>::new(generator);
+        let mut random_base = Vec::with_capacity(Scalar::<E>::size_in_bits());
+        for _ in 0..Scalar::<E>::size_in_bits() {
+            random_base.push(base_power);
+            base_power = base_power.double();
+        }
+
+        Self::from_bases(bases, random_base)
+    }
+
+    /// Initializes a new instance of BHP from the given (precomputed) bases and random base.
+    ///
+    /// Note: The bases are **not** checked to be derived from a domain, and must come from a trusted source,
+    /// such as the bases of an instance that was initialized with `setup`.
+    pub fn from_bases(bases: Vec<Vec<Group<E>>>, random_base: Vec<Group<E>>) -> Result<Self> {
+        // Calculate the maximum window size.
+        let mut maximum_window_size = 0;
+        let mut range = E::BigInteger::from(2_u64);
+        while range < E::Scalar::modulus_minus_one_div_two() {
+            // range < (p-1)/2
+            range.muln(4); // range * 2^4
+            maximum_window_size += 1;
+        }
+        ensure!(WINDOW_SIZE <= maximum_window_size, "The maximum BHP window size is {maximum_window_size}");
+
+        // Ensure the bases and random base are of the expected sizes.
         ensure!(bases.len() == NUM_WINDOWS as usize, "Incorrect number of BHP windows ({})", bases.len());
         for window in &bases {
             ensure!(window.len() == WINDOW_SIZE as usize, "Incorrect BHP window size ({})", window.len());
         }
+        ensure!(
+            random_base.len() == Scalar::<E>::size_in_bits(),
+            "Incorrect number of BHP random base powers ({})",
+            random_base.len()
+        );
 
         // Compute the bases lookup.
         let bases_lookup = cfg_iter!(bases)
@@ -108,21 +133,6 @@ impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHPHasher<E,
             ensure!(window.len() == WINDOW_SIZE as usize, "Incorrect BHP lookup window size ({})", window.len());
         }
 
-        // Next, compute the random base.
-        let (generator, _, _) =
-            Blake2Xs::hash_to_curve::<E::Affine>(&format!("Aleo.BHP.{NUM_WINDOWS}.{WINDOW_SIZE}.{domain}.Randomizer"));
-        let mut base_power = Group::<E>::new(generator);
-        let mut random_base = Vec::with_capacity(Scalar::<E>::size_in_bits());
-        for _ in 0..Scalar::<E>::size_in_bits() {
-            random_base.push(base_power);
-            base_power = base_power.double();
-        }
-        ensure!(
-            random_base.len() == Scalar::<E>::size_in_bits(),
-            "Incorrect number of BHP random base powers ({})",
-            random_base.len()
-        );
-
         Ok(Self { bases: Arc::new(bases), bases_lookup: Arc::new(bases_lookup), random_base: Arc::new(random_base) })
     }
 
@@ -62,13 +62,29 @@ pub struct BHP<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> {
 impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
     /// Initializes a new instance of BHP with the given domain.
     pub fn setup(domain: &str) -> Result<Self> {
+        Self::from_hasher(domain, || BHPHasher::<E, NUM_WINDOWS, WINDOW_SIZE>::setup(domain))
+    }
+
+    /// Initializes a new instance of BHP with the given domain, from the given (precomputed) bases and random base.
+    ///
+    /// Note: The bases are **not** checked to be derived from the domain, and must come from a trusted source,
+    /// such as the bases of an instance that was initialized with `setup` on the same domain.
+    pub fn from_bases(domain: &str, bases: Vec<Vec<Group<E>>>, random_base: Vec<Group<E>>) -> Result<Self> {
+        Self::from_hasher(domain, || BHPHasher::<E, NUM_WINDOWS, WINDOW_SIZE>::from_bases(bases, random_base))
+    }
+
+    /// Initializes a new instance of BHP with the given domain, and the BHP hasher from the given closure.
+    fn from_hasher<F>(domain: &str, hasher: F) -> Result<Self>
+    where
+        F: FnOnce() -> Result<BHPHasher<E, NUM_WINDOWS, WINDOW_SIZE>>,
+    {
         // Ensure the given domain is within the allowed size in bits.
         let num_bits = domain.len().saturating_mul(8);
         let max_bits = Field::<E>::size_in_data_bits() - 64; // 64 bits encode the length.
         ensure!(num_bits <= max_bits, "Domain cannot exceed {max_bits} bits, found {num_bits} bits");
 
         // Initialize the BHP hasher.
-        let hasher = BHPHasher::<E, NUM_WINDOWS, WINDOW_SIZE>::setup(domain)?;
+        let hasher = hasher()?;
 
         // Convert the domain into a boolean vector.
         let mut domain = domain.as_bytes().to_bits_le();
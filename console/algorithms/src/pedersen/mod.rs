@@ -71,6 +71,26 @@ impl<E: Environment, const NUM_BITS: u8> Pedersen<E, NUM_BITS> {
         Self { base_window: Arc::new(base_window.to_vec()), random_base_window: Arc::new(random_base) }
     }
 
+    /// Initializes a new instance of Pedersen from the given (precomputed) base window and random base window.
+    ///
+    /// Note: The windows are **not** checked to be derived from a setup message, and must come from a trusted source,
+    /// such as the windows of an instance that was initialized with `setup`.
+    pub fn from_windows(base_window: Vec<Group<E>>, random_base_window: Vec<Group<E>>) -> Result<Self> {
+        // Ensure the windows are of the expected sizes.
+        ensure!(
+            base_window.len() == NUM_BITS as usize,
+            "Incorrect Pedersen base window size ({}), expected {NUM_BITS}",
+            base_window.len()
+        );
+        ensure!(
+            random_base_window.len() == Scalar::<E>::size_in_bits(),
+            "Incorrect Pedersen random base window size ({}), expected {}",
+            random_base_window.len(),
+            Scalar::<E>::size_in_bits()
+        );
+        Ok(Self { base_window: Arc::new(base_window), random_base_window: Arc::new(random_base_window) })
+    }
+
     /// Returns the base window.
     pub fn base_window(&self) -> &Arc<Vec<Group<E>>> {
         &self.base_window
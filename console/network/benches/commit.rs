@@ -0,0 +1,71 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+#[macro_use]
+extern crate criterion;
+
+use snarkvm_console_network::{
+    prelude::{TestRng, Uniform},
+    Network,
+    Testnet3,
+};
+use snarkvm_console_types::{Field, Scalar};
+
+use criterion::Criterion;
+
+const NUM_ITEMS: &[usize] = &[10, 100, 1000];
+
+/// Generates the specified number of random BHP256 commitment openings.
+fn generate_openings(num_items: usize, rng: &mut TestRng) -> Vec<(Field<Testnet3>, Vec<bool>, Scalar<Testnet3>)> {
+    (0..num_items)
+        .map(|_| {
+            let input = (0..256).map(|_| bool::rand(rng)).collect::<Vec<_>>();
+            let randomizer = Scalar::rand(rng);
+            let commitment = Testnet3::commit_bhp256(&input, &randomizer).unwrap();
+            (commitment, input, randomizer)
+        })
+        .collect()
+}
+
+fn verify_commit_bhp256(c: &mut Criterion) {
+    let mut rng = TestRng::default();
+
+    for num_items in NUM_ITEMS {
+        let items = generate_openings(*num_items, &mut rng);
+
+        c.bench_function(&format!("verify_commit_bhp256 loop ({num_items} items)"), |b| {
+            b.iter(|| {
+                for (commitment, input, randomizer) in &items {
+                    assert!(Testnet3::verify_commit_bhp256(commitment, input, randomizer).unwrap());
+                }
+            })
+        });
+
+        c.bench_function(&format!("verify_commit_bhp256_batch ({num_items} items)"), |b| {
+            b.iter(|| {
+                assert!(Testnet3::verify_commit_bhp256_batch(&items).unwrap().into_iter().all(|is_valid| is_valid));
+            })
+        });
+    }
+}
+
+criterion_group! {
+    name = commit;
+    config = Criterion::default().sample_size(10);
+    targets = verify_commit_bhp256
+}
+
+criterion_main!(commit);
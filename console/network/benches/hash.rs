@@ -0,0 +1,87 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+#[macro_use]
+extern crate criterion;
+
+use snarkvm_console_network::{
+    prelude::{TestRng, Uniform},
+    Network,
+    Testnet3,
+};
+use snarkvm_console_types::Field;
+
+use criterion::Criterion;
+
+const NUM_ITEMS: &[usize] = &[10, 100, 1000];
+const INPUT_SIZE: usize = 8;
+
+/// Generates the specified number of random Poseidon inputs.
+fn generate_inputs(num_items: usize, rng: &mut TestRng) -> Vec<Vec<Field<Testnet3>>> {
+    (0..num_items).map(|_| (0..INPUT_SIZE).map(|_| Field::rand(rng)).collect()).collect()
+}
+
+fn hash_psd8(c: &mut Criterion) {
+    let mut rng = TestRng::default();
+
+    for num_items in NUM_ITEMS {
+        let inputs = generate_inputs(*num_items, &mut rng);
+
+        c.bench_function(&format!("hash_psd8 loop ({num_items} items)"), |b| {
+            b.iter(|| {
+                for input in &inputs {
+                    Testnet3::hash_psd8(input).unwrap();
+                }
+            })
+        });
+
+        c.bench_function(&format!("hash_psd8_batch ({num_items} items)"), |b| {
+            b.iter(|| {
+                assert!(Testnet3::hash_psd8_batch(&inputs).into_iter().all(|hash| hash.is_ok()));
+            })
+        });
+    }
+}
+
+fn hash_to_scalar_psd2(c: &mut Criterion) {
+    let mut rng = TestRng::default();
+
+    for num_items in NUM_ITEMS {
+        let inputs = generate_inputs(*num_items, &mut rng);
+
+        c.bench_function(&format!("hash_to_scalar_psd2 loop ({num_items} items)"), |b| {
+            b.iter(|| {
+                for input in &inputs {
+                    Testnet3::hash_to_scalar_psd2(input).unwrap();
+                }
+            })
+        });
+
+        c.bench_function(&format!("hash_to_scalar_psd2_batch ({num_items} items)"), |b| {
+            b.iter(|| {
+                assert!(Testnet3::hash_to_scalar_psd2_batch(&inputs).into_iter().all(|hash| hash.is_ok()));
+            })
+        });
+    }
+}
+
+criterion_group! {
+    name = hash;
+    config = Criterion::default().sample_size(10);
+    targets = hash_psd8, hash_to_scalar_psd2
+}
+
+criterion_main!(hash);
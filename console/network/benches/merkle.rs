@@ -0,0 +1,77 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+#[macro_use]
+extern crate criterion;
+
+use snarkvm_console_collections::merkle_tree::MerklePath;
+use snarkvm_console_network::{
+    prelude::{TestRng, Uniform},
+    Network,
+    Testnet3,
+};
+use snarkvm_console_types::Field;
+
+use criterion::Criterion;
+
+const DEPTH: u8 = 32;
+const NUM_ITEMS: &[usize] = &[10, 100, 1000];
+
+/// Generates the specified number of random leaves, and returns the root with each leaf and its Merkle path.
+#[allow(clippy::type_complexity)]
+fn generate_paths(
+    num_items: usize,
+    rng: &mut TestRng,
+) -> (Field<Testnet3>, Vec<(Vec<Field<Testnet3>>, MerklePath<Testnet3, DEPTH>)>) {
+    let leaves = (0..num_items).map(|_| vec![Field::rand(rng), Field::rand(rng)]).collect::<Vec<_>>();
+    let tree = Testnet3::merkle_tree_psd::<DEPTH>(&leaves).unwrap();
+    let items = leaves
+        .iter()
+        .enumerate()
+        .map(|(index, leaf)| (leaf.clone(), tree.prove(index, leaf).unwrap()))
+        .collect();
+    (*tree.root(), items)
+}
+
+fn verify_merkle_path_psd(c: &mut Criterion) {
+    let mut rng = TestRng::default();
+
+    for num_items in NUM_ITEMS {
+        let (root, items) = generate_paths(*num_items, &mut rng);
+
+        c.bench_function(&format!("verify_merkle_path_psd loop ({num_items} items)"), |b| {
+            b.iter(|| {
+                for (leaf, path) in &items {
+                    assert!(Testnet3::verify_merkle_path_psd(path, &root, leaf));
+                }
+            })
+        });
+
+        c.bench_function(&format!("verify_merkle_paths_psd ({num_items} items)"), |b| {
+            b.iter(|| {
+                assert!(Testnet3::verify_merkle_paths_psd(&root, &items).unwrap().into_iter().all(|is_valid| is_valid));
+            })
+        });
+    }
+}
+
+criterion_group! {
+    name = merkle;
+    config = Criterion::default().sample_size(10);
+    targets = verify_merkle_path_psd
+}
+
+criterion_main!(merkle);
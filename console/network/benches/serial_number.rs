@@ -0,0 +1,58 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+#[macro_use]
+extern crate criterion;
+
+use snarkvm_console_network::{
+    prelude::{TestRng, Uniform},
+    Network,
+    Testnet3,
+};
+use snarkvm_console_types::{Field, Scalar};
+
+use criterion::Criterion;
+
+const NUM_ITEMS: &[usize] = &[10, 100, 1000];
+
+fn compute_serial_numbers_batch(c: &mut Criterion) {
+    let mut rng = TestRng::default();
+    let sk = Scalar::<Testnet3>::rand(&mut rng);
+
+    for num_items in NUM_ITEMS {
+        let commitments = (0..*num_items).map(|_| Field::rand(&mut rng)).collect::<Vec<_>>();
+
+        c.bench_function(&format!("compute_serial_number loop ({num_items} items)"), |b| {
+            b.iter(|| {
+                for commitment in &commitments {
+                    Testnet3::compute_serial_number(&sk, commitment).unwrap();
+                }
+            })
+        });
+
+        c.bench_function(&format!("compute_serial_numbers_batch ({num_items} items)"), |b| {
+            b.iter(|| Testnet3::compute_serial_numbers_batch(&sk, &commitments).unwrap())
+        });
+    }
+}
+
+criterion_group! {
+    name = serial_number;
+    config = Criterion::default().sample_size(10);
+    targets = compute_serial_numbers_batch
+}
+
+criterion_main!(serial_number);
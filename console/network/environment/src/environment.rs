@@ -54,6 +54,9 @@ pub trait Environment:
     /// The coefficient `B` of the Montgomery curve.
     const MONTGOMERY_B: Self::Field;
 
+    /// The human-readable prefix of an account address.
+    const ADDRESS_PREFIX: &'static str;
+
     /// The maximum number of bytes allowed in a string.
     const MAX_STRING_BYTES: u32 = u8::MAX as u32;
 
@@ -82,4 +85,7 @@ impl Environment for Console {
     const MONTGOMERY_A: Self::Field = <EdwardsParameters as MontgomeryParameters>::MONTGOMERY_A;
     /// The coefficient `B` of the Montgomery curve.
     const MONTGOMERY_B: Self::Field = <EdwardsParameters as MontgomeryParameters>::MONTGOMERY_B;
+
+    /// The human-readable prefix of an account address.
+    const ADDRESS_PREFIX: &'static str = "aleo";
 }
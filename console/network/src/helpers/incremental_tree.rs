@@ -0,0 +1,196 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::prelude::*;
+
+use snarkvm_console_types::Field;
+
+/// An append-only Poseidon Merkle tree, which stores only the frontier of the tree,
+/// such that appending a leaf costs `O(DEPTH)` hashes, instead of a rebuild of the tree.
+///
+/// The root matches that of `Network::merkle_tree_psd` on the same leaves (each as a single field element).
+/// That is, the leaves are padded up to the next power of two with the empty hash,
+/// and the root of the padded tree is hashed with the empty hash for each remaining level up to `DEPTH`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IncrementalPoseidonTree<N: Network, const DEPTH: u8> {
+    /// The hashes of the complete subtrees along the frontier, indexed by height.
+    /// Only the heights of the set bits in `number_of_leaves` (and the height of a complete tree) are current.
+    frontier: Vec<Field<N>>,
+    /// The hashes of the empty subtrees, indexed by height.
+    empty_hashes: Vec<Field<N>>,
+    /// The root of the tree.
+    root: Field<N>,
+    /// The number of leaves in the tree.
+    number_of_leaves: u64,
+}
+
+impl<N: Network, const DEPTH: u8> IncrementalPoseidonTree<N, DEPTH> {
+    /// Initializes a new, empty tree.
+    pub fn new() -> Result<Self> {
+        // Ensure the Merkle tree depth is greater than 0.
+        ensure!(DEPTH > 0, "Merkle tree depth must be greater than 0");
+        // Ensure the Merkle tree depth is less than or equal to 64.
+        ensure!(DEPTH <= 64u8, "Merkle tree depth must be less than or equal to 64");
+
+        // Compute the hashes of the empty subtrees, where the empty hash is the hash of two zero children.
+        let mut empty_hashes = Vec::with_capacity(DEPTH as usize + 1);
+        empty_hashes.push(Self::hash_children(&Field::zero(), &Field::zero())?);
+        for height in 0..DEPTH as usize {
+            empty_hashes.push(Self::hash_children(&empty_hashes[height], &empty_hashes[height])?);
+        }
+
+        // Initialize the frontier with empty hashes.
+        let frontier = empty_hashes.clone();
+        // Compute the root of the empty tree.
+        let root = Self::compute_root(&frontier, &empty_hashes, 0)?;
+
+        Ok(Self { frontier, empty_hashes, root, number_of_leaves: 0 })
+    }
+
+    /// Appends the given leaf to the tree, and returns the new root.
+    ///
+    /// This method errors if the tree is full. On error, the tree is left unchanged.
+    pub fn append_field(&mut self, leaf: Field<N>) -> Result<Field<N>> {
+        // Ensure the tree is not full.
+        ensure!(
+            (self.number_of_leaves as u128) < (1u128 << DEPTH),
+            "The Merkle tree is full, as it contains {} leaves",
+            self.number_of_leaves
+        );
+
+        // Compute the leaf hash, by prepending the leaf with a `0field` element.
+        let mut node = N::hash_psd4(&[Field::zero(), leaf])?;
+        // Merge the leaf hash with each complete subtree to its left, until it reaches an empty position.
+        let mut index = self.number_of_leaves;
+        let mut height = 0;
+        while index & 1 == 1 {
+            node = Self::hash_children(&self.frontier[height], &node)?;
+            index >>= 1;
+            height += 1;
+        }
+
+        // Compute the updated frontier and root.
+        let mut frontier = self.frontier.clone();
+        frontier[height] = node;
+        let root = Self::compute_root(&frontier, &self.empty_hashes, self.number_of_leaves + 1)?;
+
+        // Update the tree at the very end, so the original tree is not altered in case of failure.
+        self.frontier = frontier;
+        self.root = root;
+        self.number_of_leaves += 1;
+        Ok(root)
+    }
+
+    /// Returns the root of the tree.
+    pub const fn root(&self) -> &Field<N> {
+        &self.root
+    }
+
+    /// Returns the number of leaves in the tree.
+    pub const fn number_of_leaves(&self) -> u64 {
+        self.number_of_leaves
+    }
+
+    /// Returns the root of a tree with the given frontier and number of leaves.
+    fn compute_root(frontier: &[Field<N>], empty_hashes: &[Field<N>], number_of_leaves: u64) -> Result<Field<N>> {
+        // Compute the height of the tree, with its leaves padded up to the next power of two.
+        let height = match number_of_leaves.max(1).checked_next_power_of_two() {
+            Some(num_leaves) => num_leaves.trailing_zeros() as usize,
+            None => bail!("Integer overflow when computing the height of the Merkle tree"),
+        };
+
+        // Compute the root of the padded tree.
+        let mut root = match number_of_leaves.is_power_of_two() {
+            // If the tree is complete, its root is on the frontier.
+            true => frontier[height],
+            // Otherwise, hash up from the first empty position, with each complete subtree to its left,
+            // and with each empty subtree to its right.
+            false => {
+                let mut node = empty_hashes[0];
+                for (height, empty_hash) in empty_hashes.iter().enumerate().take(height) {
+                    node = match (number_of_leaves >> height) & 1 == 1 {
+                        true => Self::hash_children(&frontier[height], &node)?,
+                        false => Self::hash_children(&node, empty_hash)?,
+                    };
+                }
+                node
+            }
+        };
+
+        // Hash the root with the empty hash for each remaining level up to `DEPTH`.
+        for _ in height..DEPTH as usize {
+            root = Self::hash_children(&root, &empty_hashes[0])?;
+        }
+        Ok(root)
+    }
+
+    /// Returns the hash of the given child nodes, by prepending the nodes with a `1field` element.
+    fn hash_children(left: &Field<N>, right: &Field<N>) -> Result<Field<N>> {
+        N::hash_psd2(&[Field::one(), *left, *right])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    fn check_append_field<const DEPTH: u8>(num_leaves: usize) -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Ensure the empty tree matches a full rebuild.
+        let mut tree = IncrementalPoseidonTree::<CurrentNetwork, DEPTH>::new()?;
+        assert_eq!(CurrentNetwork::merkle_tree_psd::<DEPTH>(&[])?.root(), tree.root());
+
+        // Interleave each append with a full rebuild.
+        let mut leaves = Vec::with_capacity(num_leaves);
+        for _ in 0..num_leaves {
+            let leaf = Field::rand(&mut rng);
+            leaves.push(vec![leaf]);
+
+            let candidate = tree.append_field(leaf)?;
+            let expected = CurrentNetwork::merkle_tree_psd::<DEPTH>(&leaves)?;
+            assert_eq!(expected.root(), &candidate);
+            assert_eq!(expected.root(), tree.root());
+            assert_eq!(leaves.len() as u64, tree.number_of_leaves());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_append_field() -> Result<()> {
+        check_append_field::<1>(2)?;
+        check_append_field::<2>(4)?;
+        check_append_field::<8>(33)?;
+        check_append_field::<32>(20)
+    }
+
+    #[test]
+    fn test_append_field_full() -> Result<()> {
+        let mut tree = IncrementalPoseidonTree::<CurrentNetwork, 2>::new()?;
+        for i in 0..4 {
+            tree.append_field(Field::from_u64(i))?;
+        }
+
+        // Ensure appending to a full tree fails, and leaves the tree unchanged.
+        let expected = tree.clone();
+        assert!(tree.append_field(Field::from_u64(4)).is_err());
+        assert_eq!(expected, tree);
+        Ok(())
+    }
+}
@@ -19,3 +19,9 @@ pub use id::*;
 
 mod object;
 pub use object::*;
+
+mod incremental_tree;
+pub use incremental_tree::*;
+
+mod vrf_proof;
+pub use vrf_proof::*;
@@ -0,0 +1,53 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::prelude::*;
+
+use snarkvm_console_types::{Group, Scalar};
+
+/// A proof that a VRF output was correctly evaluated for a given public key and input.
+/// See `Network::vrf_evaluate` for the construction.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct VrfProof<N: Network> {
+    /// The VRF point `gamma`, as `sk * H`, where `H` is the hash of the input to the group.
+    gamma: Group<N>,
+    /// The challenge `c` of the proof.
+    challenge: Scalar<N>,
+    /// The response `s` of the proof.
+    response: Scalar<N>,
+}
+
+impl<N: Network> VrfProof<N> {
+    /// Initializes a new VRF proof from the given VRF point, challenge, and response.
+    pub const fn new(gamma: Group<N>, challenge: Scalar<N>, response: Scalar<N>) -> Self {
+        Self { gamma, challenge, response }
+    }
+
+    /// Returns the VRF point `gamma`.
+    pub const fn gamma(&self) -> &Group<N> {
+        &self.gamma
+    }
+
+    /// Returns the challenge `c`.
+    pub const fn challenge(&self) -> &Scalar<N> {
+        &self.challenge
+    }
+
+    /// Returns the response `s`.
+    pub const fn response(&self) -> &Scalar<N> {
+        &self.response
+    }
+}
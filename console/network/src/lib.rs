@@ -48,6 +48,7 @@ use snarkvm_curves::PairingEngine;
 use indexmap::IndexMap;
 use once_cell::sync::OnceCell;
 use std::sync::Arc;
+use subtle::Choice;
 
 /// A helper type for the BHP Merkle tree.
 pub type BHPMerkleTree<N, const DEPTH: u8> = MerkleTree<N, BHP1024<N>, BHP512<N>, DEPTH>;
@@ -78,11 +79,12 @@ pub trait Network:
     + Send
     + Sync
 {
-    /// The network ID.
+    /// The network ID, which uniquely identifies the network.
     const ID: u16;
     /// The network name.
     const NAME: &'static str;
-    /// The network edition.
+    /// The network edition, which versions the protocol rules of the network under the same network ID.
+    /// Note: The edition is independent of the network ID, as no rule constrains one by the other.
     const EDITION: u16;
 
     /// The function name for the inclusion circuit.
@@ -130,6 +132,13 @@ pub trait Network:
     /// The maximum number of outputs per transition.
     const MAX_OUTPUTS: usize = 8;
 
+    /// The estimated cost of a transition in an execution, excluding its inputs and outputs.
+    const COST_PER_TRANSITION: u64 = 10_000;
+    /// The estimated cost of an input of a transition in an execution.
+    const COST_PER_INPUT: u64 = 500;
+    /// The estimated cost of an output of a transition in an execution.
+    const COST_PER_OUTPUT: u64 = 500;
+
     /// The state root type.
     type StateRoot: Bech32ID<Field<Self>>;
     /// The block hash type.
@@ -157,9 +166,70 @@ pub trait Network:
     /// Returns the powers of `G`.
     fn g_powers() -> &'static Vec<Group<Self>>;
 
+    /// Writes the precomputed generator tables, i.e. the powers of `G`, and the bases of the BHP and Pedersen
+    /// hash functions, to the given writer. If the tables have not been computed or loaded yet, this computes
+    /// them without caching them, so that they may still be loaded afterwards.
+    #[cfg(feature = "std")]
+    fn save_precomputed_tables<W: Write>(writer: W) -> Result<()>;
+
+    /// Loads the precomputed generator tables from the given reader, and sets them for all uses of the tables.
+    ///
+    /// This method errors if the tables have already been computed or loaded, and thus must be called
+    /// before any use of the tables. As the points are not checked to be in the prime-order subgroup,
+    /// the loaded tables are recomputed and compared, and this method errors if they do not match.
+    #[cfg(feature = "std")]
+    fn load_precomputed_tables<R: Read>(reader: R) -> Result<()>;
+
     /// Returns the scalar multiplication on the generator `G`.
     fn g_scalar_multiply(scalar: &Scalar<Self>) -> Group<Self>;
 
+    /// Returns a `Choice` of `1` if the given scalars are equal, comparing them in constant time.
+    /// This is intended for comparing secret scalars, such as spend keys, without leaking timing information.
+    fn ct_eq_scalar(a: &Scalar<Self>, b: &Scalar<Self>) -> Choice;
+
+    /// Returns a deterministic `(secret, public)` key pair for the given index, for reproducible tests.
+    /// The secret is derived as `HashToScalar(test_account_domain || index)`, and the public key is `secret * G`.
+    ///
+    /// Note: The secret keys are derivable by anyone, and must never be used to hold value.
+    #[cfg(any(test, feature = "test-utils"))]
+    fn derive_test_account(index: u64) -> Result<(Scalar<Self>, Group<Self>)>;
+
+    /// Returns the window bases of the BHP hasher with an input hasher of 256-bits.
+    fn bhp256_bases() -> &'static Vec<Vec<Group<Self>>>;
+
+    /// Returns the powers of the random base of the BHP commitment with an input hasher of 256-bits.
+    fn bhp256_random_base() -> &'static Vec<Group<Self>>;
+
+    /// Returns the window bases of the BHP hasher with an input hasher of 512-bits.
+    fn bhp512_bases() -> &'static Vec<Vec<Group<Self>>>;
+
+    /// Returns the powers of the random base of the BHP commitment with an input hasher of 512-bits.
+    fn bhp512_random_base() -> &'static Vec<Group<Self>>;
+
+    /// Returns the window bases of the BHP hasher with an input hasher of 768-bits.
+    fn bhp768_bases() -> &'static Vec<Vec<Group<Self>>>;
+
+    /// Returns the powers of the random base of the BHP commitment with an input hasher of 768-bits.
+    fn bhp768_random_base() -> &'static Vec<Group<Self>>;
+
+    /// Returns the window bases of the BHP hasher with an input hasher of 1024-bits.
+    fn bhp1024_bases() -> &'static Vec<Vec<Group<Self>>>;
+
+    /// Returns the powers of the random base of the BHP commitment with an input hasher of 1024-bits.
+    fn bhp1024_random_base() -> &'static Vec<Group<Self>>;
+
+    /// Returns the bases of the Pedersen hasher for a given (up to) 64-bit input.
+    fn pedersen64_bases() -> &'static Vec<Group<Self>>;
+
+    /// Returns the powers of the random base of the Pedersen commitment for a given (up to) 64-bit input.
+    fn pedersen64_random_base() -> &'static Vec<Group<Self>>;
+
+    /// Returns the bases of the Pedersen hasher for a given (up to) 128-bit input.
+    fn pedersen128_bases() -> &'static Vec<Group<Self>>;
+
+    /// Returns the powers of the random base of the Pedersen commitment for a given (up to) 128-bit input.
+    fn pedersen128_random_base() -> &'static Vec<Group<Self>>;
+
     /// Returns the sponge parameters for Marlin.
     fn marlin_fs_parameters() -> &'static FiatShamirParameters<Self>;
 
@@ -199,6 +269,47 @@ pub trait Network:
     /// Returns a Pedersen commitment for the given (up to) 128-bit input and randomizer.
     fn commit_ped128(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>>;
 
+    /// Returns `true` if the given commitment is a BHP commitment (with an input hasher of 256-bits) of the given input and randomizer.
+    fn verify_commit_bhp256(commitment: &Field<Self>, input: &[bool], randomizer: &Scalar<Self>) -> Result<bool>;
+
+    /// Returns `true` if the given commitment is a BHP commitment (with an input hasher of 512-bits) of the given input and randomizer.
+    fn verify_commit_bhp512(commitment: &Field<Self>, input: &[bool], randomizer: &Scalar<Self>) -> Result<bool>;
+
+    /// Returns `true` if the given commitment is a BHP commitment (with an input hasher of 768-bits) of the given input and randomizer.
+    fn verify_commit_bhp768(commitment: &Field<Self>, input: &[bool], randomizer: &Scalar<Self>) -> Result<bool>;
+
+    /// Returns `true` if the given commitment is a BHP commitment (with an input hasher of 1024-bits) of the given input and randomizer.
+    fn verify_commit_bhp1024(commitment: &Field<Self>, input: &[bool], randomizer: &Scalar<Self>) -> Result<bool>;
+
+    /// Returns `true` if the given commitment is a Pedersen commitment of the given (up to) 64-bit input and randomizer.
+    fn verify_commit_ped64(commitment: &Group<Self>, input: &[bool], randomizer: &Scalar<Self>) -> Result<bool>;
+
+    /// Returns `true` if the given commitment is a Pedersen commitment of the given (up to) 128-bit input and randomizer.
+    fn verify_commit_ped128(commitment: &Group<Self>, input: &[bool], randomizer: &Scalar<Self>) -> Result<bool>;
+
+    /// Returns the sum of the given Pedersen commitments (for a given (up to) 64-bit input).
+    /// As Pedersen commitments are additively homomorphic, the result is a commitment to the sum
+    /// of the inputs, whose randomizer is the sum of the individual randomizers.
+    fn add_commitments_ped64(a: &Group<Self>, b: &Group<Self>) -> Group<Self>;
+
+    /// Returns the sum of the given Pedersen commitments (for a given (up to) 64-bit input).
+    /// As Pedersen commitments are additively homomorphic, the result is a commitment to the sum
+    /// of the inputs, whose randomizer is the sum of the individual randomizers.
+    fn aggregate_commitments_ped64(commitments: &[Group<Self>]) -> Group<Self>;
+
+    /// Returns `true` if the given aggregate commitment is the sum of the Pedersen commitments of the given
+    /// (up to) 64-bit inputs and randomizers, without requiring the individual commitments to be published.
+    /// This method errors if the number of inputs and randomizers do not match.
+    fn verify_aggregate_ped64(
+        aggregate_commitment: &Group<Self>,
+        inputs: &[Vec<bool>],
+        randomizers: &[Scalar<Self>],
+    ) -> Result<bool>;
+
+    /// Returns, for each `(commitment, input, randomizer)`, `true` if the commitment is a BHP commitment
+    /// (with an input hasher of 256-bits) of the given input and randomizer, in the order given.
+    fn verify_commit_bhp256_batch(items: &[(Field<Self>, Vec<bool>, Scalar<Self>)]) -> Result<Vec<bool>>;
+
     /// Returns the BHP hash with an input hasher of 256-bits.
     fn hash_bhp256(input: &[bool]) -> Result<Field<Self>>;
 
@@ -217,6 +328,18 @@ pub trait Network:
     /// Returns the Pedersen hash for a given (up to) 128-bit input.
     fn hash_ped128(input: &[bool]) -> Result<Field<Self>>;
 
+    /// Returns the sponge state width (the rate plus the capacity) of the Poseidon hash function with the given rate.
+    /// This method errors if the network does not instantiate Poseidon with the given rate.
+    fn poseidon_state_width(rate: usize) -> Result<usize>;
+
+    /// Returns the sponge capacity of the Poseidon hash function with the given rate.
+    /// This method errors if the network does not instantiate Poseidon with the given rate.
+    fn poseidon_capacity(rate: usize) -> Result<usize>;
+
+    /// Returns the number of full rounds and partial rounds of the Poseidon hash function with the given rate.
+    /// This method errors if the network does not instantiate Poseidon with the given rate.
+    fn poseidon_rounds(rate: usize) -> Result<(usize, usize)>;
+
     /// Returns the Poseidon hash with an input rate of 2.
     fn hash_psd2(input: &[Field<Self>]) -> Result<Field<Self>>;
 
@@ -226,6 +349,25 @@ pub trait Network:
     /// Returns the Poseidon hash with an input rate of 8.
     fn hash_psd8(input: &[Field<Self>]) -> Result<Field<Self>>;
 
+    /// Returns the Poseidon hash with an input rate of 8, for each of the given inputs, in the order given.
+    fn hash_psd8_batch(inputs: &[Vec<Field<Self>>]) -> Vec<Result<Field<Self>>>;
+
+    /// Returns the Poseidon hash with an input rate of 8, on the given input prefixed by the given domain.
+    fn hash_with_domain_psd8(domain: &Field<Self>, input: &[Field<Self>]) -> Result<Field<Self>>;
+
+    /// Returns the Poseidon hash with an input rate of 2, on the given bytes.
+    fn hash_bytes_psd2(input: &[u8]) -> Result<Field<Self>>;
+
+    /// Returns the Poseidon hash with an input rate of 4, on the given bytes.
+    fn hash_bytes_psd4(input: &[u8]) -> Result<Field<Self>>;
+
+    /// Returns the Poseidon hash with an input rate of 8, on the given bytes.
+    fn hash_bytes_psd8(input: &[u8]) -> Result<Field<Self>>;
+
+    /// Returns the Poseidon hash with an input rate of 8 on the little-endian bytes of the given object,
+    /// under the object domain.
+    fn hash_object_psd8<T: ToBytes>(object: &T) -> Result<Field<Self>>;
+
     /// Returns the extended Poseidon hash with an input rate of 2.
     fn hash_many_psd2(input: &[Field<Self>], num_outputs: u16) -> Vec<Field<Self>>;
 
@@ -235,6 +377,12 @@ pub trait Network:
     /// Returns the extended Poseidon hash with an input rate of 8.
     fn hash_many_psd8(input: &[Field<Self>], num_outputs: u16) -> Vec<Field<Self>>;
 
+    /// Returns `num_outputs` field elements, deterministically derived from the given seed under the given domain.
+    fn hash_to_fields(seed: &[Field<Self>], domain: &str, num_outputs: u16) -> Result<Vec<Field<Self>>>;
+
+    /// Returns the Poseidon hash with an input rate of 8 of the given root and metadata, under the header domain.
+    fn hash_header(root: &Field<Self>, metadata: &[Field<Self>]) -> Result<Field<Self>>;
+
     /// Returns the Poseidon hash with an input rate of 2 on the affine curve.
     fn hash_to_group_psd2(input: &[Field<Self>]) -> Result<Group<Self>>;
 
@@ -244,15 +392,104 @@ pub trait Network:
     /// Returns the Poseidon hash with an input rate of 8 on the affine curve.
     fn hash_to_group_psd8(input: &[Field<Self>]) -> Result<Group<Self>>;
 
+    /// Returns `true` if the given point is the Poseidon hash with an input rate of 2 on the affine curve
+    /// of the given input, i.e. `point == hash_to_group_psd2(input)`. The comparison is in constant time.
+    fn verify_hash_to_group_psd2(point: &Group<Self>, input: &[Field<Self>]) -> Result<bool>;
+
+    /// Returns a "nothing-up-my-sleeve" point in the prime-order subgroup, derived from the given label.
+    /// The label is hashed to the curve with Blake2Xs, and the cofactor is cleared, such that the point
+    /// is reproducible from the label, and its discrete logarithm with respect to any generator is unknown.
+    fn hash_to_curve_nums(label: &str) -> Result<Group<Self>>;
+
     /// Returns the Poseidon hash with an input rate of 2 on the scalar field.
     fn hash_to_scalar_psd2(input: &[Field<Self>]) -> Result<Scalar<Self>>;
 
+    /// Returns the Poseidon hash with an input rate of 2 on the scalar field, for each of the given inputs,
+    /// in the order given.
+    fn hash_to_scalar_psd2_batch(inputs: &[Vec<Field<Self>>]) -> Vec<Result<Scalar<Self>>>;
+
     /// Returns the Poseidon hash with an input rate of 4 on the scalar field.
     fn hash_to_scalar_psd4(input: &[Field<Self>]) -> Result<Scalar<Self>>;
 
     /// Returns the Poseidon hash with an input rate of 8 on the scalar field.
     fn hash_to_scalar_psd8(input: &[Field<Self>]) -> Result<Scalar<Self>>;
 
+    /// Returns the Poseidon hash with an input rate of 2 on the scalar field, on the given bytes.
+    fn hash_bytes_to_scalar_psd2(input: &[u8]) -> Result<Scalar<Self>>;
+
+    /// Returns the Poseidon hash with an input rate of 4 on the scalar field, on the given bytes.
+    fn hash_bytes_to_scalar_psd4(input: &[u8]) -> Result<Scalar<Self>>;
+
+    /// Returns the Poseidon hash with an input rate of 8 on the scalar field, on the given bytes.
+    fn hash_bytes_to_scalar_psd8(input: &[u8]) -> Result<Scalar<Self>>;
+
+    /// Returns the serial number for the given secret key and record commitment.
+    fn compute_serial_number(sk: &Scalar<Self>, commitment: &Field<Self>) -> Result<Field<Self>>;
+
+    /// Returns the serial number for the given `gamma`, i.e. `sk * H`, and record commitment.
+    fn serial_number_from_gamma(gamma: &Group<Self>, commitment: &Field<Self>) -> Result<Field<Self>>;
+
+    /// Returns the serial number for the given secret key and each of the given record commitments, in the order given.
+    fn compute_serial_numbers_batch(sk: &Scalar<Self>, commitments: &[Field<Self>]) -> Result<Vec<Field<Self>>>;
+
+    /// Returns the view key derived from the given spend secret.
+    ///
+    /// The view key is derived as `HashToScalar(view_key_domain || secret)`, where `HashToScalar` is
+    /// the Poseidon hash with an input rate of 2 on the scalar field, `view_key_domain` is the domain
+    /// separator `"AleoViewKey0"`, and `secret` is the spend secret encoded as a base field element.
+    fn derive_view_key(secret: &Scalar<Self>) -> Result<Scalar<Self>>;
+
+    /// Returns the tag for the given tag secret key and serial number.
+    ///
+    /// The tag is computed as `Hash(tag_domain || sk_tag || serial_number)`, where `Hash` is
+    /// the Poseidon hash with an input rate of 4, `tag_domain` is the domain separator `"AleoTag0"`,
+    /// and `sk_tag` is the tag secret key encoded as a base field element.
+    fn compute_tag(sk_tag: &Scalar<Self>, serial_number: &Field<Self>) -> Result<Field<Self>>;
+
+    /// Returns the random beacon for the given public seed and round.
+    ///
+    /// The beacon is computed as `Hash(beacon_domain || seed || round)`, where `Hash` is the Poseidon hash
+    /// with an input rate of 4, and `beacon_domain` is the domain separator `"AleoRandomBeacon0"`.
+    /// As such, anyone holding the seed can recompute, and thus verify, the beacon for any round.
+    fn random_beacon(seed: &Field<Self>, round: u64) -> Result<Field<Self>>;
+
+    /// Returns the encryption randomizer for the given view key and record nonce.
+    ///
+    /// The randomizer is computed as `HashMany(encryption_domain || (nonce * view_key).x, 1)`, where `HashMany`
+    /// is the extended Poseidon hash with an input rate of 8, and `(nonce * view_key).x` is the record view key.
+    /// This is the randomizer that masks the first encrypted field element of a record, i.e. its owner.
+    ///
+    /// Note: As the record view key is a Diffie-Hellman shared secret, the nonce is a group element.
+    fn compute_encryption_randomizer(view_key: &Scalar<Self>, nonce: &Group<Self>) -> Result<Field<Self>>;
+
+    /// Returns the VRF output for the given secret key and input, with a proof of its correct evaluation.
+    ///
+    /// The output is computed as `Hash(vrf_domain || (COFACTOR * gamma).x)`, where `gamma = sk * H`,
+    /// and `H = HashToGroup(vrf_domain || input)`. The proof shows `log_G(pk) == log_H(gamma)` as follows:
+    /// for a nonce `k = HashToScalar(vrf_domain || sk || H.x)`, the challenge is
+    /// `c = HashToScalar(vrf_domain || pk.x || H.x || gamma.x || (k * G).x || (k * H).x)`,
+    /// and the response is `s = k + c * sk`.
+    fn vrf_evaluate(sk: &Scalar<Self>, input: &[Field<Self>]) -> Result<(Field<Self>, VrfProof<Self>)>;
+
+    /// Returns `true` if the given VRF output and proof are valid for the given public key and input.
+    fn vrf_verify(
+        pk: &Group<Self>,
+        input: &[Field<Self>],
+        output: &Field<Self>,
+        proof: &VrfProof<Self>,
+    ) -> Result<bool>;
+
+    /// Returns the account address for the given public key, as a bech32m string.
+    ///
+    /// The address encodes the x-coordinate of the public key, with the network's `ADDRESS_PREFIX`
+    /// as the human-readable prefix, and matches the string format of an account `Address`.
+    fn public_key_to_address(pk: &Group<Self>) -> Result<String>;
+
+    /// Returns the public key for the given account address.
+    ///
+    /// This method errors if the address is malformed, or if it is not prefixed with the network's `ADDRESS_PREFIX`.
+    fn address_to_public_key(address: &str) -> Result<Group<Self>>;
+
     /// Returns a Merkle tree with a BHP leaf hasher of 1024-bits and a BHP path hasher of 512-bits.
     fn merkle_tree_bhp<const DEPTH: u8>(leaves: &[Vec<bool>]) -> Result<BHPMerkleTree<Self, DEPTH>>;
 
@@ -274,4 +511,11 @@ pub trait Network:
         root: &Field<Self>,
         leaf: &Vec<Field<Self>>,
     ) -> bool;
+
+    /// Returns, for each `(leaf, path)`, `true` if the given Merkle path is valid for the given root and leaf,
+    /// in the order given.
+    fn verify_merkle_paths_psd<const DEPTH: u8>(
+        root: &Field<Self>,
+        items: &[(Vec<Field<Self>>, MerklePath<Self, DEPTH>)],
+    ) -> Result<Vec<bool>>;
 }
@@ -17,20 +17,32 @@
 use super::*;
 use snarkvm_console_algorithms::{
     Blake2Xs,
+    Pedersen,
     Pedersen128,
     Pedersen64,
     Poseidon2,
     Poseidon4,
     Poseidon8,
+    BHP,
     BHP1024,
     BHP256,
     BHP512,
     BHP768,
 };
+use snarkvm_curves::AffineCurve;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use snarkvm_fields::{PoseidonParameters, PrimeField};
+use subtle::ConstantTimeEq;
+
+/// The generator tables, which are computed on first use, unless loaded with `Testnet3::load_precomputed_tables`.
+static PRECOMPUTED_TABLES: OnceCell<PrecomputedTables> = OnceCell::new();
 
 lazy_static! {
     /// The group bases for the Aleo signature and encryption schemes.
-    pub static ref GENERATOR_G: Vec<Group<Testnet3>> = Testnet3::new_bases("AleoAccountEncryptionAndSignatureScheme0");
+    pub static ref GENERATOR_G: Vec<Group<Testnet3>> = Testnet3::precomputed_tables().g_powers.clone();
 
     /// The Marlin sponge parameters.
     pub static ref MARLIN_FS_PARAMETERS: FiatShamirParameters<Testnet3> = FiatShamir::<Testnet3>::sample_parameters();
@@ -47,20 +59,32 @@ lazy_static! {
     pub static ref R_BCM_DOMAIN: Field<Testnet3> = Field::<Testnet3>::new_domain_separator("AleoBalanceRandomizer0");
     /// The serial number domain as a constant field element.
     pub static ref SERIAL_NUMBER_DOMAIN: Field<Testnet3> = Field::<Testnet3>::new_domain_separator("AleoSerialNumber0");
+    /// The object domain as a constant field element.
+    pub static ref OBJECT_DOMAIN: Field<Testnet3> = Field::<Testnet3>::new_domain_separator("AleoObject0");
+    /// The header domain as a constant field element.
+    pub static ref HEADER_DOMAIN: Field<Testnet3> = Field::<Testnet3>::new_domain_separator("AleoHeader0");
+    /// The view key domain as a constant field element.
+    pub static ref VIEW_KEY_DOMAIN: Field<Testnet3> = Field::<Testnet3>::new_domain_separator("AleoViewKey0");
+    /// The tag domain as a constant field element.
+    pub static ref TAG_DOMAIN: Field<Testnet3> = Field::<Testnet3>::new_domain_separator("AleoTag0");
+    /// The random beacon domain as a constant field element.
+    pub static ref BEACON_DOMAIN: Field<Testnet3> = Field::<Testnet3>::new_domain_separator("AleoRandomBeacon0");
+    /// The VRF domain as a constant field element.
+    pub static ref VRF_DOMAIN: Field<Testnet3> = Field::<Testnet3>::new_domain_separator("AleoVRF0");
 
     /// The BHP hash function, which can take an input of up to 256 bits.
-    pub static ref BHP_256: BHP256<Testnet3> = BHP256::<Testnet3>::setup("AleoBHP256").expect("Failed to setup BHP256");
+    pub static ref BHP_256: BHP256<Testnet3> = Testnet3::precomputed_tables().bhp_256.clone();
     /// The BHP hash function, which can take an input of up to 512 bits.
-    pub static ref BHP_512: BHP512<Testnet3> = BHP512::<Testnet3>::setup("AleoBHP512").expect("Failed to setup BHP512");
+    pub static ref BHP_512: BHP512<Testnet3> = Testnet3::precomputed_tables().bhp_512.clone();
     /// The BHP hash function, which can take an input of up to 768 bits.
-    pub static ref BHP_768: BHP768<Testnet3> = BHP768::<Testnet3>::setup("AleoBHP768").expect("Failed to setup BHP768");
+    pub static ref BHP_768: BHP768<Testnet3> = Testnet3::precomputed_tables().bhp_768.clone();
     /// The BHP hash function, which can take an input of up to 1024 bits.
-    pub static ref BHP_1024: BHP1024<Testnet3> = BHP1024::<Testnet3>::setup("AleoBHP1024").expect("Failed to setup BHP1024");
+    pub static ref BHP_1024: BHP1024<Testnet3> = Testnet3::precomputed_tables().bhp_1024.clone();
 
     /// The Pedersen hash function, which can take an input of up to 64 bits.
-    pub static ref PEDERSEN_64: Pedersen64<Testnet3> = Pedersen64::<Testnet3>::setup("AleoPedersen64");
+    pub static ref PEDERSEN_64: Pedersen64<Testnet3> = Testnet3::precomputed_tables().pedersen_64.clone();
     /// The Pedersen hash function, which can take an input of up to 128 bits.
-    pub static ref PEDERSEN_128: Pedersen128<Testnet3> = Pedersen128::<Testnet3>::setup("AleoPedersen128");
+    pub static ref PEDERSEN_128: Pedersen128<Testnet3> = Testnet3::precomputed_tables().pedersen_128.clone();
 
     /// The Poseidon hash function, using a rate of 2.
     pub static ref POSEIDON_2: Poseidon2<Testnet3> = Poseidon2::<Testnet3>::setup("AleoPoseidon2").expect("Failed to setup Poseidon2");
@@ -81,6 +105,158 @@ lazy_static! {
     };
 }
 
+/// The domain for the group bases of the Aleo signature and encryption schemes.
+const GENERATOR_G_DOMAIN: &str = "AleoAccountEncryptionAndSignatureScheme0";
+/// The domains for the BHP hash functions.
+const BHP_DOMAINS: [&str; 4] = ["AleoBHP256", "AleoBHP512", "AleoBHP768", "AleoBHP1024"];
+/// The setup messages for the Pedersen hash functions.
+const PEDERSEN_MESSAGES: [&str; 2] = ["AleoPedersen64", "AleoPedersen128"];
+
+/// The generator tables of Testnet3, i.e. the powers of `G`, and the bases of the BHP and Pedersen hash functions.
+struct PrecomputedTables {
+    g_powers: Vec<Group<Testnet3>>,
+    bhp_256: BHP256<Testnet3>,
+    bhp_512: BHP512<Testnet3>,
+    bhp_768: BHP768<Testnet3>,
+    bhp_1024: BHP1024<Testnet3>,
+    pedersen_64: Pedersen64<Testnet3>,
+    pedersen_128: Pedersen128<Testnet3>,
+}
+
+impl PrecomputedTables {
+    /// The version of the byte serialization of the tables.
+    const VERSION: u16 = 0u16;
+
+    /// Computes the generator tables.
+    fn setup() -> Self {
+        Self {
+            g_powers: Testnet3::new_bases(GENERATOR_G_DOMAIN),
+            bhp_256: BHP256::setup(BHP_DOMAINS[0]).expect("Failed to setup BHP256"),
+            bhp_512: BHP512::setup(BHP_DOMAINS[1]).expect("Failed to setup BHP512"),
+            bhp_768: BHP768::setup(BHP_DOMAINS[2]).expect("Failed to setup BHP768"),
+            bhp_1024: BHP1024::setup(BHP_DOMAINS[3]).expect("Failed to setup BHP1024"),
+            pedersen_64: Pedersen64::setup(PEDERSEN_MESSAGES[0]),
+            pedersen_128: Pedersen128::setup(PEDERSEN_MESSAGES[1]),
+        }
+    }
+
+    /// Writes the given points to the writer, as their number followed by their affine coordinates.
+    fn write_points<W: Write>(points: &[Group<Testnet3>], mut writer: W) -> IoResult<()> {
+        (points.len() as u32).write_le(&mut writer)?;
+        for point in points {
+            let (x, y) = point.to_xy_coordinates();
+            x.write_le(&mut writer)?;
+            y.write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+
+    /// Reads points from the reader, as their number followed by their affine coordinates.
+    ///
+    /// Note: Each point is checked to be on the curve, but is **not** checked to be in the prime-order subgroup,
+    /// as the check costs about as much as recomputing the point. As such, the tables must be checked
+    /// against the canonical tables before they are used, as in `Testnet3::load_precomputed_tables`.
+    fn read_points<R: Read>(mut reader: R) -> IoResult<Vec<Group<Testnet3>>> {
+        // Read the number of points.
+        let num_points = u32::read_le(&mut reader)?;
+        // Ensure the number of points does not exceed the size of the largest table.
+        if num_points as usize > Scalar::<Testnet3>::size_in_bits() {
+            return Err(error(format!("Invalid number of points in a precomputed table ({num_points})")));
+        }
+        // Read the points.
+        (0..num_points)
+            .map(|_| {
+                let x = Field::<Testnet3>::read_le(&mut reader)?;
+                let y = Field::<Testnet3>::read_le(&mut reader)?;
+                let point = <Testnet3 as Environment>::Affine::from_coordinates_unchecked((*x, *y));
+                match point.is_on_curve() {
+                    true => Ok(Group::new(point)),
+                    false => Err(error("Invalid point in a precomputed table")),
+                }
+            })
+            .collect()
+    }
+
+    /// Writes the bases of the given BHP hash function to the writer.
+    fn write_bhp<W: Write, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8>(
+        bhp: &BHP<Testnet3, NUM_WINDOWS, WINDOW_SIZE>,
+        mut writer: W,
+    ) -> IoResult<()> {
+        for window in bhp.bases().iter() {
+            Self::write_points(window, &mut writer)?;
+        }
+        Self::write_points(bhp.random_base(), &mut writer)
+    }
+
+    /// Reads the bases of a BHP hash function from the reader, and initializes it with the given domain.
+    fn read_bhp<R: Read, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8>(
+        domain: &str,
+        mut reader: R,
+    ) -> IoResult<BHP<Testnet3, NUM_WINDOWS, WINDOW_SIZE>> {
+        let bases = (0..NUM_WINDOWS).map(|_| Self::read_points(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        let random_base = Self::read_points(&mut reader)?;
+        BHP::from_bases(domain, bases, random_base).map_err(|e| error(e.to_string()))
+    }
+
+    /// Writes the windows of the given Pedersen hash function to the writer.
+    fn write_pedersen<W: Write, const NUM_BITS: u8>(
+        pedersen: &Pedersen<Testnet3, NUM_BITS>,
+        mut writer: W,
+    ) -> IoResult<()> {
+        Self::write_points(pedersen.base_window(), &mut writer)?;
+        Self::write_points(pedersen.random_base_window(), &mut writer)
+    }
+
+    /// Reads the windows of a Pedersen hash function from the reader.
+    fn read_pedersen<R: Read, const NUM_BITS: u8>(mut reader: R) -> IoResult<Pedersen<Testnet3, NUM_BITS>> {
+        let base_window = Self::read_points(&mut reader)?;
+        let random_base_window = Self::read_points(&mut reader)?;
+        Pedersen::from_windows(base_window, random_base_window).map_err(|e| error(e.to_string()))
+    }
+}
+
+impl FromBytes for PrecomputedTables {
+    /// Reads the generator tables from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u16::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != Self::VERSION {
+            return Err(error(format!("Invalid precomputed tables version '{version}'")));
+        }
+        // Read the tables.
+        let g_powers = Self::read_points(&mut reader)?;
+        if g_powers.len() != Scalar::<Testnet3>::size_in_bits() {
+            return Err(error(format!("Invalid number of powers of G ({})", g_powers.len())));
+        }
+        Ok(Self {
+            g_powers,
+            bhp_256: Self::read_bhp(BHP_DOMAINS[0], &mut reader)?,
+            bhp_512: Self::read_bhp(BHP_DOMAINS[1], &mut reader)?,
+            bhp_768: Self::read_bhp(BHP_DOMAINS[2], &mut reader)?,
+            bhp_1024: Self::read_bhp(BHP_DOMAINS[3], &mut reader)?,
+            pedersen_64: Self::read_pedersen(&mut reader)?,
+            pedersen_128: Self::read_pedersen(&mut reader)?,
+        })
+    }
+}
+
+impl ToBytes for PrecomputedTables {
+    /// Writes the generator tables to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        Self::VERSION.write_le(&mut writer)?;
+        // Write the tables.
+        Self::write_points(&self.g_powers, &mut writer)?;
+        Self::write_bhp(&self.bhp_256, &mut writer)?;
+        Self::write_bhp(&self.bhp_512, &mut writer)?;
+        Self::write_bhp(&self.bhp_768, &mut writer)?;
+        Self::write_bhp(&self.bhp_1024, &mut writer)?;
+        Self::write_pedersen(&self.pedersen_64, &mut writer)?;
+        Self::write_pedersen(&self.pedersen_128, &mut writer)
+    }
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Testnet3;
 
@@ -99,6 +275,68 @@ impl Testnet3 {
         }
         g_bases
     }
+
+    /// Returns a `Choice` of `1` if the canonical bytes of the given values are equal, comparing them in constant time.
+    ///
+    /// Note: As the bytes of a group element only encode its x-coordinate, group elements should be given
+    /// as their affine coordinates, i.e. `group.to_xy_coordinates()`.
+    fn ct_eq_bytes<T: ToBytes>(a: &T, b: &T) -> Result<Choice> {
+        Ok(a.to_bytes_le()?.ct_eq(&b.to_bytes_le()?))
+    }
+
+    /// Returns the generator tables, computing them if they have not been computed or loaded yet.
+    fn precomputed_tables() -> &'static PrecomputedTables {
+        PRECOMPUTED_TABLES.get_or_init(PrecomputedTables::setup)
+    }
+
+    /// Packs the given bytes into field elements, for hashing with Poseidon.
+    ///
+    /// The bytes are encoded as little-endian bits, followed by one final `true` bit to serve as a
+    /// terminus indicator, and packed into field elements of `Field::size_in_data_bits()` bits each.
+    /// The terminus ensures inputs that differ only in trailing zero bytes are packed distinctly.
+    fn pack_bytes(input: &[u8]) -> Result<Vec<Field<Self>>> {
+        // Encode the bytes as little-endian bits.
+        let mut bits_le = input.to_bits_le();
+        // Adds one final bit to the data, to serve as a terminus indicator.
+        bits_le.push(true);
+        // Pack the bits into field elements.
+        bits_le.chunks(Field::<Self>::size_in_data_bits()).map(Field::from_bits_le).collect()
+    }
+
+    /// Returns the VRF challenge as `HashToScalar(vrf_domain || pk.x || H.x || gamma.x || u.x || v.x)`.
+    fn vrf_challenge(
+        pk: &Group<Self>,
+        h: &Group<Self>,
+        gamma: &Group<Self>,
+        u: &Group<Self>,
+        v: &Group<Self>,
+    ) -> Result<Scalar<Self>> {
+        let input = [pk, h, gamma, u, v].map(|group| group.to_x_coordinate());
+        Self::hash_to_scalar_psd8(&[&[*VRF_DOMAIN][..], &input[..]].concat())
+    }
+
+    /// Returns the VRF output as `Hash(vrf_domain || (COFACTOR * gamma).x)`.
+    fn vrf_output(gamma: &Group<Self>) -> Result<Field<Self>> {
+        Self::hash_psd2(&[*VRF_DOMAIN, gamma.mul_by_cofactor().to_x_coordinate()])
+    }
+
+    /// Returns the sponge state width, capacity, number of full rounds, and number of partial rounds
+    /// of the Poseidon hash function with the given input rate.
+    fn poseidon_sizes(rate: usize) -> Result<(usize, usize, usize, usize)> {
+        /// Returns the sizes of the given Poseidon parameters.
+        fn sizes<F: PrimeField, const RATE: usize, const CAPACITY: usize>(
+            parameters: &PoseidonParameters<F, RATE, CAPACITY>,
+        ) -> (usize, usize, usize, usize) {
+            (RATE + CAPACITY, CAPACITY, parameters.full_rounds, parameters.partial_rounds)
+        }
+
+        match rate {
+            2 => Ok(sizes(&**POSEIDON_2.parameters())),
+            4 => Ok(sizes(&**POSEIDON_4.parameters())),
+            8 => Ok(sizes(&**POSEIDON_8.parameters())),
+            _ => bail!("Poseidon is not instantiated with an input rate of {rate}"),
+        }
+    }
 }
 
 impl Environment for Testnet3 {
@@ -117,6 +355,9 @@ impl Environment for Testnet3 {
     const MONTGOMERY_A: Self::Field = Console::MONTGOMERY_A;
     /// The coefficient `B` of the Montgomery curve.
     const MONTGOMERY_B: Self::Field = Console::MONTGOMERY_B;
+
+    /// The human-readable prefix of an account address.
+    const ADDRESS_PREFIX: &'static str = "aleo";
 }
 
 impl Network for Testnet3 {
@@ -186,6 +427,32 @@ impl Network for Testnet3 {
         &GENERATOR_G
     }
 
+    /// Writes the precomputed generator tables to the given writer.
+    #[cfg(feature = "std")]
+    fn save_precomputed_tables<W: Write>(writer: W) -> Result<()> {
+        match PRECOMPUTED_TABLES.get() {
+            Some(tables) => Ok(tables.write_le(writer)?),
+            // Note: The tables are not cached, so that they may still be loaded afterwards.
+            None => Ok(PrecomputedTables::setup().write_le(writer)?),
+        }
+    }
+
+    /// Loads the precomputed generator tables from the given reader.
+    #[cfg(feature = "std")]
+    fn load_precomputed_tables<R: Read>(reader: R) -> Result<()> {
+        // Ensure the tables have not been computed or loaded yet, before reading them.
+        ensure!(PRECOMPUTED_TABLES.get().is_none(), "The generator tables have already been initialized");
+        // Read the tables.
+        let tables = PrecomputedTables::read_le(reader)?;
+        // Ensure the tables match the canonical tables, as the points are not checked to be in the subgroup.
+        ensure!(
+            tables.to_bytes_le()? == PrecomputedTables::setup().to_bytes_le()?,
+            "The generator tables do not match the canonical tables"
+        );
+        // Set the tables, which fails if they were initialized while reading.
+        PRECOMPUTED_TABLES.set(tables).map_err(|_| anyhow!("The generator tables have already been initialized"))
+    }
+
     /// Returns the scalar multiplication on the generator `G`.
     fn g_scalar_multiply(scalar: &Scalar<Self>) -> Group<Self> {
         GENERATOR_G
@@ -198,6 +465,87 @@ impl Network for Testnet3 {
             .sum()
     }
 
+    /// Returns a `Choice` of `1` if the given scalars are equal, comparing them in constant time.
+    /// This is intended for comparing secret scalars, such as spend keys, without leaking timing information.
+    ///
+    /// Note: Serializing a scalar does not fail, and a failure would conservatively be treated as a mismatch.
+    fn ct_eq_scalar(a: &Scalar<Self>, b: &Scalar<Self>) -> Choice {
+        Self::ct_eq_bytes(a, b).unwrap_or_else(|_| Choice::from(0u8))
+    }
+
+    /// Returns a deterministic `(secret, public)` key pair for the given index, for reproducible tests.
+    /// The secret is derived as `HashToScalar(test_account_domain || index)`, and the public key is `secret * G`.
+    ///
+    /// Note: The secret keys are derivable by anyone, and must never be used to hold value.
+    #[cfg(any(test, feature = "test-utils"))]
+    fn derive_test_account(index: u64) -> Result<(Scalar<Self>, Group<Self>)> {
+        // Compute the secret as `HashToScalar(test_account_domain || index)`.
+        let domain = Field::<Self>::new_domain_separator("AleoTestAccount0");
+        let secret = Self::hash_to_scalar_psd2(&[domain, Field::from_u64(index)])?;
+        // Compute the public key as `secret * G`.
+        Ok((secret, Self::g_scalar_multiply(&secret)))
+    }
+
+    /// Returns the window bases of the BHP hasher with an input hasher of 256-bits.
+    fn bhp256_bases() -> &'static Vec<Vec<Group<Self>>> {
+        BHP_256.bases()
+    }
+
+    /// Returns the powers of the random base of the BHP commitment with an input hasher of 256-bits.
+    fn bhp256_random_base() -> &'static Vec<Group<Self>> {
+        BHP_256.random_base()
+    }
+
+    /// Returns the window bases of the BHP hasher with an input hasher of 512-bits.
+    fn bhp512_bases() -> &'static Vec<Vec<Group<Self>>> {
+        BHP_512.bases()
+    }
+
+    /// Returns the powers of the random base of the BHP commitment with an input hasher of 512-bits.
+    fn bhp512_random_base() -> &'static Vec<Group<Self>> {
+        BHP_512.random_base()
+    }
+
+    /// Returns the window bases of the BHP hasher with an input hasher of 768-bits.
+    fn bhp768_bases() -> &'static Vec<Vec<Group<Self>>> {
+        BHP_768.bases()
+    }
+
+    /// Returns the powers of the random base of the BHP commitment with an input hasher of 768-bits.
+    fn bhp768_random_base() -> &'static Vec<Group<Self>> {
+        BHP_768.random_base()
+    }
+
+    /// Returns the window bases of the BHP hasher with an input hasher of 1024-bits.
+    fn bhp1024_bases() -> &'static Vec<Vec<Group<Self>>> {
+        BHP_1024.bases()
+    }
+
+    /// Returns the powers of the random base of the BHP commitment with an input hasher of 1024-bits.
+    fn bhp1024_random_base() -> &'static Vec<Group<Self>> {
+        BHP_1024.random_base()
+    }
+
+    /// Returns the bases of the Pedersen hasher for a given (up to) 64-bit input.
+    fn pedersen64_bases() -> &'static Vec<Group<Self>> {
+        PEDERSEN_64.base_window()
+    }
+
+    /// Returns the powers of the random base of the Pedersen commitment for a given (up to) 64-bit input.
+    fn pedersen64_random_base() -> &'static Vec<Group<Self>> {
+        PEDERSEN_64.random_base_window()
+    }
+
+    /// Returns the bases of the Pedersen hasher for a given (up to) 128-bit input.
+    fn pedersen128_bases() -> &'static Vec<Group<Self>> {
+        PEDERSEN_128.base_window()
+    }
+
+    /// Returns the powers of the random base of the Pedersen commitment for a given (up to) 128-bit input.
+    fn pedersen128_random_base() -> &'static Vec<Group<Self>> {
+        PEDERSEN_128.random_base_window()
+    }
+
     /// Returns the sponge parameters used for the sponge in the Marlin SNARK.
     fn marlin_fs_parameters() -> &'static FiatShamirParameters<Self> {
         &MARLIN_FS_PARAMETERS
@@ -263,6 +611,85 @@ impl Network for Testnet3 {
         PEDERSEN_128.commit_uncompressed(input, randomizer)
     }
 
+    /// Returns `true` if the given commitment is a BHP commitment (with an input hasher of 256-bits) of the given input and randomizer.
+    fn verify_commit_bhp256(commitment: &Field<Self>, input: &[bool], randomizer: &Scalar<Self>) -> Result<bool> {
+        Ok(Self::ct_eq_bytes(&BHP_256.commit(input, randomizer)?, commitment)?.into())
+    }
+
+    /// Returns `true` if the given commitment is a BHP commitment (with an input hasher of 512-bits) of the given input and randomizer.
+    fn verify_commit_bhp512(commitment: &Field<Self>, input: &[bool], randomizer: &Scalar<Self>) -> Result<bool> {
+        Ok(Self::ct_eq_bytes(&BHP_512.commit(input, randomizer)?, commitment)?.into())
+    }
+
+    /// Returns `true` if the given commitment is a BHP commitment (with an input hasher of 768-bits) of the given input and randomizer.
+    fn verify_commit_bhp768(commitment: &Field<Self>, input: &[bool], randomizer: &Scalar<Self>) -> Result<bool> {
+        Ok(Self::ct_eq_bytes(&BHP_768.commit(input, randomizer)?, commitment)?.into())
+    }
+
+    /// Returns `true` if the given commitment is a BHP commitment (with an input hasher of 1024-bits) of the given input and randomizer.
+    fn verify_commit_bhp1024(commitment: &Field<Self>, input: &[bool], randomizer: &Scalar<Self>) -> Result<bool> {
+        Ok(Self::ct_eq_bytes(&BHP_1024.commit(input, randomizer)?, commitment)?.into())
+    }
+
+    /// Returns `true` if the given commitment is a Pedersen commitment of the given (up to) 64-bit input and randomizer.
+    fn verify_commit_ped64(commitment: &Group<Self>, input: &[bool], randomizer: &Scalar<Self>) -> Result<bool> {
+        let candidate = PEDERSEN_64.commit_uncompressed(input, randomizer)?;
+        Ok(Self::ct_eq_bytes(&candidate.to_xy_coordinates(), &commitment.to_xy_coordinates())?.into())
+    }
+
+    /// Returns `true` if the given commitment is a Pedersen commitment of the given (up to) 128-bit input and randomizer.
+    fn verify_commit_ped128(commitment: &Group<Self>, input: &[bool], randomizer: &Scalar<Self>) -> Result<bool> {
+        let candidate = PEDERSEN_128.commit_uncompressed(input, randomizer)?;
+        Ok(Self::ct_eq_bytes(&candidate.to_xy_coordinates(), &commitment.to_xy_coordinates())?.into())
+    }
+
+    /// Returns the sum of the given Pedersen commitments (for a given (up to) 64-bit input).
+    /// As Pedersen commitments are additively homomorphic, the result is a commitment to the sum
+    /// of the inputs, whose randomizer is the sum of the individual randomizers.
+    fn add_commitments_ped64(a: &Group<Self>, b: &Group<Self>) -> Group<Self> {
+        *a + b
+    }
+
+    /// Returns the sum of the given Pedersen commitments (for a given (up to) 64-bit input).
+    /// As Pedersen commitments are additively homomorphic, the result is a commitment to the sum
+    /// of the inputs, whose randomizer is the sum of the individual randomizers.
+    fn aggregate_commitments_ped64(commitments: &[Group<Self>]) -> Group<Self> {
+        commitments.iter().sum()
+    }
+
+    /// Returns `true` if the given aggregate commitment is the sum of the Pedersen commitments of the given
+    /// (up to) 64-bit inputs and randomizers, without requiring the individual commitments to be published.
+    /// This method errors if the number of inputs and randomizers do not match.
+    fn verify_aggregate_ped64(
+        aggregate_commitment: &Group<Self>,
+        inputs: &[Vec<bool>],
+        randomizers: &[Scalar<Self>],
+    ) -> Result<bool> {
+        // Ensure there is a randomizer for each input.
+        ensure!(
+            inputs.len() == randomizers.len(),
+            "Mismatching number of inputs ({}) and randomizers ({})",
+            inputs.len(),
+            randomizers.len()
+        );
+        // Compute the individual commitments.
+        let commitments = cfg_iter!(inputs)
+            .zip_eq(randomizers)
+            .map(|(input, randomizer)| Self::commit_ped64(input, randomizer))
+            .collect::<Result<Vec<_>>>()?;
+        // Ensure the aggregate commitment is the sum of the individual commitments.
+        let candidate = Self::aggregate_commitments_ped64(&commitments);
+        Ok(Self::ct_eq_bytes(&candidate.to_xy_coordinates(), &aggregate_commitment.to_xy_coordinates())?.into())
+    }
+
+    /// Returns, for each `(commitment, input, randomizer)`, `true` if the commitment is a BHP commitment
+    /// (with an input hasher of 256-bits) of the given input and randomizer, in the order given.
+    fn verify_commit_bhp256_batch(items: &[(Field<Self>, Vec<bool>, Scalar<Self>)]) -> Result<Vec<bool>> {
+        cfg_iter!(items)
+            .map(|(commitment, input, randomizer)| Self::verify_commit_bhp256(commitment, input, randomizer))
+            .collect()
+    }
+
     /// Returns the BHP hash with an input hasher of 256-bits.
     fn hash_bhp256(input: &[bool]) -> Result<Field<Self>> {
         BHP_256.hash(input)
@@ -293,6 +720,21 @@ impl Network for Testnet3 {
         PEDERSEN_128.hash(input)
     }
 
+    /// Returns the sponge state width (the rate plus the capacity) of the Poseidon hash function with the given rate.
+    fn poseidon_state_width(rate: usize) -> Result<usize> {
+        Self::poseidon_sizes(rate).map(|(width, ..)| width)
+    }
+
+    /// Returns the sponge capacity of the Poseidon hash function with the given rate.
+    fn poseidon_capacity(rate: usize) -> Result<usize> {
+        Self::poseidon_sizes(rate).map(|(_, capacity, ..)| capacity)
+    }
+
+    /// Returns the number of full rounds and partial rounds of the Poseidon hash function with the given rate.
+    fn poseidon_rounds(rate: usize) -> Result<(usize, usize)> {
+        Self::poseidon_sizes(rate).map(|(_, _, full_rounds, partial_rounds)| (full_rounds, partial_rounds))
+    }
+
     /// Returns the Poseidon hash with an input rate of 2.
     fn hash_psd2(input: &[Field<Self>]) -> Result<Field<Self>> {
         POSEIDON_2.hash(input)
@@ -308,6 +750,46 @@ impl Network for Testnet3 {
         POSEIDON_8.hash(input)
     }
 
+    /// Returns the Poseidon hash with an input rate of 8, for each of the given inputs, in the order given.
+    fn hash_psd8_batch(inputs: &[Vec<Field<Self>>]) -> Vec<Result<Field<Self>>> {
+        cfg_iter!(inputs).map(|input| POSEIDON_8.hash(input)).collect()
+    }
+
+    /// Returns the Poseidon hash with an input rate of 8, on the given input prefixed by the given domain.
+    fn hash_with_domain_psd8(domain: &Field<Self>, input: &[Field<Self>]) -> Result<Field<Self>> {
+        // Construct the preimage as `(domain || input)`.
+        let mut preimage = Vec::with_capacity(1 + input.len());
+        preimage.push(*domain);
+        preimage.extend_from_slice(input);
+        // Hash the preimage.
+        POSEIDON_8.hash(&preimage)
+    }
+
+    /// Returns the Poseidon hash with an input rate of 2, on the given bytes.
+    fn hash_bytes_psd2(input: &[u8]) -> Result<Field<Self>> {
+        POSEIDON_2.hash(&Self::pack_bytes(input)?)
+    }
+
+    /// Returns the Poseidon hash with an input rate of 4, on the given bytes.
+    fn hash_bytes_psd4(input: &[u8]) -> Result<Field<Self>> {
+        POSEIDON_4.hash(&Self::pack_bytes(input)?)
+    }
+
+    /// Returns the Poseidon hash with an input rate of 8, on the given bytes.
+    fn hash_bytes_psd8(input: &[u8]) -> Result<Field<Self>> {
+        POSEIDON_8.hash(&Self::pack_bytes(input)?)
+    }
+
+    /// Returns the Poseidon hash with an input rate of 8 on the little-endian bytes of the given object,
+    /// under the object domain.
+    fn hash_object_psd8<T: ToBytes>(object: &T) -> Result<Field<Self>> {
+        // Construct the preimage as `(object_domain || pack(bytes))`.
+        let mut preimage = vec![*OBJECT_DOMAIN];
+        preimage.extend(Self::pack_bytes(&object.to_bytes_le()?)?);
+        // Hash the preimage.
+        POSEIDON_8.hash(&preimage)
+    }
+
     /// Returns the extended Poseidon hash with an input rate of 2.
     fn hash_many_psd2(input: &[Field<Self>], num_outputs: u16) -> Vec<Field<Self>> {
         POSEIDON_2.hash_many(input, num_outputs)
@@ -323,6 +805,32 @@ impl Network for Testnet3 {
         POSEIDON_8.hash_many(input, num_outputs)
     }
 
+    /// Returns `num_outputs` field elements, deterministically derived from the given seed under the given domain.
+    fn hash_to_fields(seed: &[Field<Self>], domain: &str, num_outputs: u16) -> Result<Vec<Field<Self>>> {
+        // Ensure the number of outputs is nonzero.
+        ensure!(num_outputs > 0, "Cannot derive zero field elements from a seed");
+        // Construct the preimage as `(domain || num_outputs || seed)`, so that distinct domains or counts
+        // yield independent outputs.
+        let mut preimage = Vec::with_capacity(2 + seed.len());
+        preimage.push(Field::new_domain_separator(domain));
+        preimage.push(Field::from_u16(num_outputs));
+        preimage.extend_from_slice(seed);
+        // Squeeze the requested number of field elements.
+        Ok(POSEIDON_2.hash_many(&preimage, num_outputs))
+    }
+
+    /// Returns the Poseidon hash with an input rate of 8 of the given root and metadata, under the header domain.
+    fn hash_header(root: &Field<Self>, metadata: &[Field<Self>]) -> Result<Field<Self>> {
+        // Construct the preimage as `(header_domain || root || num_metadata || metadata)`.
+        let mut preimage = Vec::with_capacity(3 + metadata.len());
+        preimage.push(*HEADER_DOMAIN);
+        preimage.push(*root);
+        preimage.push(Field::from_u64(metadata.len() as u64));
+        preimage.extend_from_slice(metadata);
+        // Hash the preimage.
+        POSEIDON_8.hash(&preimage)
+    }
+
     /// Returns the Poseidon hash with an input rate of 2 on the affine curve.
     fn hash_to_group_psd2(input: &[Field<Self>]) -> Result<Group<Self>> {
         POSEIDON_2.hash_to_group(input)
@@ -338,11 +846,39 @@ impl Network for Testnet3 {
         POSEIDON_8.hash_to_group(input)
     }
 
+    /// Returns `true` if the given point is the Poseidon hash with an input rate of 2 on the affine curve
+    /// of the given input, i.e. `point == hash_to_group_psd2(input)`. The comparison is in constant time.
+    fn verify_hash_to_group_psd2(point: &Group<Self>, input: &[Field<Self>]) -> Result<bool> {
+        // Recompute the hash of the input.
+        let candidate = Self::hash_to_group_psd2(input)?;
+        // Compare both coordinates in constant time.
+        Ok(Self::ct_eq_bytes(&candidate.to_xy_coordinates(), &point.to_xy_coordinates())?.into())
+    }
+
+    /// Returns a "nothing-up-my-sleeve" point in the prime-order subgroup, derived from the given label.
+    /// The label is hashed to the curve with Blake2Xs, and the cofactor is cleared, such that the point
+    /// is reproducible from the label, and its discrete logarithm with respect to any generator is unknown.
+    fn hash_to_curve_nums(label: &str) -> Result<Group<Self>> {
+        // Hash the label (prefixed with a domain, to separate it from the bases of the network) to the curve.
+        // Note: `Blake2Xs::hash_to_curve` clears the cofactor.
+        let (point, _, _) = Blake2Xs::hash_to_curve::<<Self as Environment>::Affine>(&format!("AleoNUMS0 || {label}"));
+        let point = Group::<Self>::new(point);
+        // Ensure the point is not the identity, which is in the subgroup, but is not a generator.
+        ensure!(!point.is_zero(), "Failed to hash the label '{label}' to a non-identity point");
+        Ok(point)
+    }
+
     /// Returns the Poseidon hash with an input rate of 2 on the scalar field.
     fn hash_to_scalar_psd2(input: &[Field<Self>]) -> Result<Scalar<Self>> {
         POSEIDON_2.hash_to_scalar(input)
     }
 
+    /// Returns the Poseidon hash with an input rate of 2 on the scalar field, for each of the given inputs,
+    /// in the order given.
+    fn hash_to_scalar_psd2_batch(inputs: &[Vec<Field<Self>>]) -> Vec<Result<Scalar<Self>>> {
+        cfg_iter!(inputs).map(|input| POSEIDON_2.hash_to_scalar(input)).collect()
+    }
+
     /// Returns the Poseidon hash with an input rate of 4 on the scalar field.
     fn hash_to_scalar_psd4(input: &[Field<Self>]) -> Result<Scalar<Self>> {
         POSEIDON_4.hash_to_scalar(input)
@@ -353,6 +889,133 @@ impl Network for Testnet3 {
         POSEIDON_8.hash_to_scalar(input)
     }
 
+    /// Returns the Poseidon hash with an input rate of 2 on the scalar field, on the given bytes.
+    fn hash_bytes_to_scalar_psd2(input: &[u8]) -> Result<Scalar<Self>> {
+        POSEIDON_2.hash_to_scalar(&Self::pack_bytes(input)?)
+    }
+
+    /// Returns the Poseidon hash with an input rate of 4 on the scalar field, on the given bytes.
+    fn hash_bytes_to_scalar_psd4(input: &[u8]) -> Result<Scalar<Self>> {
+        POSEIDON_4.hash_to_scalar(&Self::pack_bytes(input)?)
+    }
+
+    /// Returns the Poseidon hash with an input rate of 8 on the scalar field, on the given bytes.
+    fn hash_bytes_to_scalar_psd8(input: &[u8]) -> Result<Scalar<Self>> {
+        POSEIDON_8.hash_to_scalar(&Self::pack_bytes(input)?)
+    }
+
+    /// Returns the serial number for the given secret key and record commitment.
+    fn compute_serial_number(sk: &Scalar<Self>, commitment: &Field<Self>) -> Result<Field<Self>> {
+        // Compute the generator `H` as `HashToGroup(serial_number_domain || commitment)`.
+        let h = Self::hash_to_group_psd2(&[Self::serial_number_domain(), *commitment])?;
+        // Compute `gamma` as `sk * H`.
+        let gamma = h * sk;
+        // Compute the serial number from `gamma`.
+        Self::serial_number_from_gamma(&gamma, commitment)
+    }
+
+    /// Returns the serial number for the given `gamma`, i.e. `sk * H`, and record commitment.
+    fn serial_number_from_gamma(gamma: &Group<Self>, commitment: &Field<Self>) -> Result<Field<Self>> {
+        // Compute `sn_nonce` as `Hash(serial_number_domain || (COFACTOR * gamma).x)`.
+        let sn_nonce =
+            Self::hash_to_scalar_psd2(&[Self::serial_number_domain(), gamma.mul_by_cofactor().to_x_coordinate()])?;
+        // Compute `serial_number` as `Commit(serial_number_domain || commitment, sn_nonce)`.
+        Self::commit_bhp512(&(Self::serial_number_domain(), *commitment).to_bits_le(), &sn_nonce)
+    }
+
+    /// Returns the serial number for the given secret key and each of the given record commitments, in the order given.
+    fn compute_serial_numbers_batch(sk: &Scalar<Self>, commitments: &[Field<Self>]) -> Result<Vec<Field<Self>>> {
+        cfg_iter!(commitments).map(|commitment| Self::compute_serial_number(sk, commitment)).collect()
+    }
+
+    /// Returns the view key derived from the given spend secret, as `HashToScalar(view_key_domain || secret)`.
+    fn derive_view_key(secret: &Scalar<Self>) -> Result<Scalar<Self>> {
+        // Compute `view_key` as `HashToScalar(view_key_domain || secret)`, with a Poseidon input rate of 2.
+        Self::hash_to_scalar_psd2(&[*VIEW_KEY_DOMAIN, secret.to_field()?])
+    }
+
+    /// Returns the tag for the given tag secret key and serial number.
+    fn compute_tag(sk_tag: &Scalar<Self>, serial_number: &Field<Self>) -> Result<Field<Self>> {
+        // Compute `tag` as `Hash(tag_domain || sk_tag || serial_number)`, with a Poseidon input rate of 4.
+        POSEIDON_4.hash(&[*TAG_DOMAIN, sk_tag.to_field()?, *serial_number])
+    }
+
+    /// Returns the random beacon for the given seed and round.
+    fn random_beacon(seed: &Field<Self>, round: u64) -> Result<Field<Self>> {
+        // Compute `beacon` as `Hash(beacon_domain || seed || round)`, with a Poseidon input rate of 4.
+        POSEIDON_4.hash(&[*BEACON_DOMAIN, *seed, Field::from_u64(round)])
+    }
+
+    /// Returns the encryption randomizer for the given view key and record nonce.
+    fn compute_encryption_randomizer(view_key: &Scalar<Self>, nonce: &Group<Self>) -> Result<Field<Self>> {
+        // Compute the record view key as `(nonce * view_key).x`.
+        let record_view_key = (*nonce * *view_key).to_x_coordinate();
+        // Compute the randomizer as `HashMany(encryption_domain || record_view_key, 1)`.
+        match Self::hash_many_psd8(&[*ENCRYPTION_DOMAIN, record_view_key], 1).first() {
+            Some(randomizer) => Ok(*randomizer),
+            None => bail!("Failed to compute the encryption randomizer"),
+        }
+    }
+
+    /// Returns the VRF output for the given secret key and input, with a proof of its correct evaluation.
+    fn vrf_evaluate(sk: &Scalar<Self>, input: &[Field<Self>]) -> Result<(Field<Self>, VrfProof<Self>)> {
+        // Compute `H` as `HashToGroup(vrf_domain || input)`.
+        let h = Self::hash_to_group_psd2(&[&[*VRF_DOMAIN][..], input].concat())?;
+        // Compute `gamma` as `sk * H`.
+        let gamma = h * sk;
+        // Compute the public key as `sk * G`.
+        let pk = Self::g_scalar_multiply(sk);
+
+        // Compute the nonce `k` as `HashToScalar(vrf_domain || sk || H.x)`.
+        let k = Self::hash_to_scalar_psd4(&[*VRF_DOMAIN, sk.to_field()?, h.to_x_coordinate()])?;
+        // Compute the challenge `c` as `HashToScalar(vrf_domain || pk.x || H.x || gamma.x || (k * G).x || (k * H).x)`.
+        let challenge = Self::vrf_challenge(&pk, &h, &gamma, &Self::g_scalar_multiply(&k), &(h * k))?;
+        // Compute the response `s` as `k + c * sk`.
+        let response = k + challenge * sk;
+
+        Ok((Self::vrf_output(&gamma)?, VrfProof::new(gamma, challenge, response)))
+    }
+
+    /// Returns `true` if the given VRF output and proof are valid for the given public key and input.
+    fn vrf_verify(
+        pk: &Group<Self>,
+        input: &[Field<Self>],
+        output: &Field<Self>,
+        proof: &VrfProof<Self>,
+    ) -> Result<bool> {
+        // Compute `H` as `HashToGroup(vrf_domain || input)`.
+        let h = Self::hash_to_group_psd2(&[&[*VRF_DOMAIN][..], input].concat())?;
+        let (gamma, challenge, response) = (proof.gamma(), proof.challenge(), proof.response());
+
+        // Recompute `k * G` as `s * G - c * pk`, and `k * H` as `s * H - c * gamma`.
+        let u = Self::g_scalar_multiply(response) - *pk * challenge;
+        let v = h * response - *gamma * challenge;
+        // Ensure the challenge matches, and the output is derived from `gamma`.
+        Ok(*challenge == Self::vrf_challenge(pk, &h, gamma, &u, &v)? && *output == Self::vrf_output(gamma)?)
+    }
+
+    /// Returns the account address for the given public key, as a bech32m string.
+    fn public_key_to_address(pk: &Group<Self>) -> Result<String> {
+        // Encode the x-coordinate of the public key into bech32m.
+        let bytes = pk.to_x_coordinate().to_bytes_le()?;
+        Ok(bech32::encode(Self::ADDRESS_PREFIX, bytes.to_base32(), bech32::Variant::Bech32m)?)
+    }
+
+    /// Returns the public key for the given account address.
+    fn address_to_public_key(address: &str) -> Result<Group<Self>> {
+        // Decode the address string from bech32m.
+        let (hrp, data, variant) = bech32::decode(address)?;
+        if hrp != Self::ADDRESS_PREFIX {
+            bail!("Failed to decode address: '{hrp}' is an invalid prefix for {}", Self::NAME)
+        } else if data.is_empty() {
+            bail!("Failed to decode address: data field is empty")
+        } else if variant != bech32::Variant::Bech32m {
+            bail!("Found an address that is not bech32m encoded: {address}");
+        }
+        // Decode the address data from u5 to u8, and recover the public key from its x-coordinate.
+        Group::from_x_coordinate(Field::read_le(&Vec::from_base32(&data)?[..])?)
+    }
+
     /// Returns a Merkle tree with a BHP leaf hasher of 1024-bits and a BHP path hasher of 512-bits.
     fn merkle_tree_bhp<const DEPTH: u8>(leaves: &[Vec<bool>]) -> Result<BHPMerkleTree<Self, DEPTH>> {
         MerkleTree::new(&*BHP_1024, &*BHP_512, leaves)
@@ -380,6 +1043,15 @@ impl Network for Testnet3 {
     ) -> bool {
         path.verify(&*POSEIDON_4, &*POSEIDON_2, root, leaf)
     }
+
+    /// Returns, for each `(leaf, path)`, `true` if the given Merkle path is valid for the given root and leaf,
+    /// in the order given.
+    fn verify_merkle_paths_psd<const DEPTH: u8>(
+        root: &Field<Self>,
+        items: &[(Vec<Field<Self>>, MerklePath<Self, DEPTH>)],
+    ) -> Result<Vec<bool>> {
+        Ok(cfg_iter!(items).map(|(leaf, path)| path.verify(&*POSEIDON_4, &*POSEIDON_2, root, leaf)).collect())
+    }
 }
 
 #[cfg(test)]
@@ -388,6 +1060,8 @@ mod tests {
 
     type CurrentNetwork = Testnet3;
 
+    const ITERATIONS: u64 = 10;
+
     #[test]
     fn test_g_scalar_multiply() {
         // Compute G^r.
@@ -395,4 +1069,843 @@ mod tests {
         let group = CurrentNetwork::g_scalar_multiply(&scalar);
         assert_eq!(group, CurrentNetwork::g_powers()[0] * scalar);
     }
+
+    #[test]
+    fn test_ct_eq_scalar() {
+        let mut rng = TestRng::default();
+
+        // Returns `true` if the given scalars are equal, comparing them in constant time.
+        let ct_eq = |a: &Scalar<_>, b: &Scalar<_>| bool::from(CurrentNetwork::ct_eq_scalar(a, b));
+
+        for _ in 0..ITERATIONS {
+            let a = Scalar::rand(&mut rng);
+            let b = Scalar::rand(&mut rng);
+            // Ensure the comparison matches `==`.
+            assert!(ct_eq(&a, &a));
+            assert!(ct_eq(&a, &Scalar::read_le(&a.to_bytes_le().unwrap()[..]).unwrap()));
+            assert_eq!(a == b, ct_eq(&a, &b));
+            assert!(!ct_eq(&a, &(a + Scalar::one())));
+        }
+        assert!(ct_eq(&Scalar::zero(), &Scalar::zero()));
+    }
+
+    #[test]
+    fn test_ct_eq_bytes() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Ensure the comparison of field elements matches `==`.
+            let a = Field::<CurrentNetwork>::rand(&mut rng);
+            assert!(bool::from(CurrentNetwork::ct_eq_bytes(&a, &a)?));
+            assert!(!bool::from(CurrentNetwork::ct_eq_bytes(&a, &(a + Field::one()))?));
+
+            // Ensure group elements with the same x-coordinate, but a different y-coordinate, are distinguished.
+            let point = Group::<CurrentNetwork>::rand(&mut rng);
+            let (x, y) = point.to_xy_coordinates();
+            let other = Group::from_xy_coordinates_unchecked(x, -y);
+            assert_eq!(point.to_bytes_le()?, other.to_bytes_le()?);
+            assert!(bool::from(CurrentNetwork::ct_eq_bytes(&point.to_xy_coordinates(), &(x, y))?));
+            assert!(!bool::from(CurrentNetwork::ct_eq_bytes(&point.to_xy_coordinates(), &other.to_xy_coordinates())?));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_derive_test_account() -> Result<()> {
+        // Ensure the same index always yields the same key pair.
+        let (secret, public) = CurrentNetwork::derive_test_account(0)?;
+        assert_eq!((secret, public), CurrentNetwork::derive_test_account(0)?);
+        assert_eq!(public, CurrentNetwork::g_scalar_multiply(&secret));
+
+        // Ensure distinct indices yield distinct key pairs.
+        let accounts = (0..ITERATIONS).map(CurrentNetwork::derive_test_account).collect::<Result<Vec<_>>>()?;
+        assert!(!has_duplicates(accounts.iter().map(|(secret, _)| secret)));
+        assert!(!has_duplicates(accounts.iter().map(|(_, public)| public)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_commit() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        macro_rules! check_verify_commit {
+            ($commit:ident, $verify:ident, $num_bits:expr) => {{
+                // Sample a random input and randomizer.
+                let input = (0..$num_bits).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>();
+                let randomizer = Scalar::rand(&mut rng);
+                let commitment = CurrentNetwork::$commit(&input, &randomizer)?;
+                // Ensure the opening is valid.
+                assert!(CurrentNetwork::$verify(&commitment, &input, &randomizer)?);
+
+                // Ensure a wrong randomizer is rejected.
+                let wrong_randomizer = randomizer + Scalar::one();
+                assert!(!CurrentNetwork::$verify(&commitment, &input, &wrong_randomizer)?);
+
+                // Ensure a wrong input is rejected.
+                let mut wrong_input = input.clone();
+                wrong_input[0] = !wrong_input[0];
+                assert!(!CurrentNetwork::$verify(&commitment, &wrong_input, &randomizer)?);
+            }};
+        }
+
+        check_verify_commit!(commit_bhp256, verify_commit_bhp256, 256);
+        check_verify_commit!(commit_bhp512, verify_commit_bhp512, 512);
+        check_verify_commit!(commit_bhp768, verify_commit_bhp768, 768);
+        check_verify_commit!(commit_bhp1024, verify_commit_bhp1024, 1024);
+        check_verify_commit!(commit_ped64, verify_commit_ped64, 64);
+        check_verify_commit!(commit_ped128, verify_commit_ped128, 128);
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_bytes_psd() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for num_bytes in [0, 1, 31, 32, 100] {
+            // Sample random bytes.
+            let input = (0..num_bytes).map(|_| u8::rand(&mut rng)).collect::<Vec<_>>();
+
+            // Manually pack the bytes into field elements.
+            let mut bits_le = input.to_bits_le();
+            bits_le.push(true);
+            let fields = bits_le
+                .chunks(Field::<CurrentNetwork>::size_in_data_bits())
+                .map(Field::from_bits_le)
+                .collect::<Result<Vec<_>>>()?;
+
+            // Ensure the hashes are deterministic, and match hashing the packed field elements.
+            assert_eq!(CurrentNetwork::hash_bytes_psd2(&input)?, CurrentNetwork::hash_bytes_psd2(&input)?);
+            assert_eq!(CurrentNetwork::hash_bytes_psd2(&input)?, CurrentNetwork::hash_psd2(&fields)?);
+            assert_eq!(CurrentNetwork::hash_bytes_psd4(&input)?, CurrentNetwork::hash_psd4(&fields)?);
+            assert_eq!(CurrentNetwork::hash_bytes_psd8(&input)?, CurrentNetwork::hash_psd8(&fields)?);
+
+            // Ensure a trailing zero byte changes the hash.
+            let mut extended = input.clone();
+            extended.push(0u8);
+            assert_ne!(CurrentNetwork::hash_bytes_psd2(&input)?, CurrentNetwork::hash_bytes_psd2(&extended)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_bytes_to_scalar_psd() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for num_bytes in [0, 1, 31, 32, 100] {
+            // Sample random bytes.
+            let input = (0..num_bytes).map(|_| u8::rand(&mut rng)).collect::<Vec<_>>();
+            let fields = CurrentNetwork::pack_bytes(&input)?;
+
+            // Ensure the hashes are deterministic, and match hashing the packed field elements.
+            let candidate = CurrentNetwork::hash_bytes_to_scalar_psd2(&input)?;
+            assert_eq!(candidate, CurrentNetwork::hash_bytes_to_scalar_psd2(&input)?);
+            assert_eq!(candidate, CurrentNetwork::hash_to_scalar_psd2(&fields)?);
+            let expected_psd4 = CurrentNetwork::hash_to_scalar_psd4(&fields)?;
+            assert_eq!(expected_psd4, CurrentNetwork::hash_bytes_to_scalar_psd4(&input)?);
+            let expected_psd8 = CurrentNetwork::hash_to_scalar_psd8(&fields)?;
+            assert_eq!(expected_psd8, CurrentNetwork::hash_bytes_to_scalar_psd8(&input)?);
+
+            // Ensure the output is a valid scalar, i.e. its canonical encoding is read back.
+            assert_eq!(candidate, Scalar::read_le(&candidate.to_bytes_le()?[..])?);
+
+            // Ensure a trailing zero byte changes the hash.
+            let mut extended = input.clone();
+            extended.push(0u8);
+            assert_ne!(candidate, CurrentNetwork::hash_bytes_to_scalar_psd2(&extended)?);
+
+            // Ensure a flipped bit changes the hash.
+            if !input.is_empty() {
+                let mut flipped = input.clone();
+                flipped[0] ^= 1;
+                assert_ne!(candidate, CurrentNetwork::hash_bytes_to_scalar_psd2(&flipped)?);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_to_fields() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample a random seed.
+        let seed = (0..4).map(|_| Field::rand(&mut rng)).collect::<Vec<_>>();
+
+        for num_outputs in [1, 2, 5] {
+            // Ensure the output count is correct, and the outputs are deterministic.
+            let candidate = CurrentNetwork::hash_to_fields(&seed, "AleoTestDomain0", num_outputs)?;
+            assert_eq!(num_outputs as usize, candidate.len());
+            assert_eq!(candidate, CurrentNetwork::hash_to_fields(&seed, "AleoTestDomain0", num_outputs)?);
+
+            // Ensure a distinct domain yields distinct outputs.
+            let other = CurrentNetwork::hash_to_fields(&seed, "AleoTestDomain1", num_outputs)?;
+            assert_ne!(candidate[0], other[0]);
+
+            // Ensure a distinct count yields distinct outputs.
+            let other = CurrentNetwork::hash_to_fields(&seed, "AleoTestDomain0", num_outputs + 1)?;
+            assert_ne!(candidate[0], other[0]);
+        }
+
+        // Ensure zero outputs is rejected.
+        assert!(CurrentNetwork::hash_to_fields(&seed, "AleoTestDomain0", 0).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_compute_serial_number() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let sk = Scalar::rand(&mut rng);
+            let commitment = Field::rand(&mut rng);
+
+            // Ensure the serial number is deterministic.
+            let candidate = CurrentNetwork::compute_serial_number(&sk, &commitment)?;
+            assert_eq!(candidate, CurrentNetwork::compute_serial_number(&sk, &commitment)?);
+
+            // Ensure the serial number matches the one derived from `gamma`.
+            let h = CurrentNetwork::hash_to_group_psd2(&[CurrentNetwork::serial_number_domain(), commitment])?;
+            assert_eq!(candidate, CurrentNetwork::serial_number_from_gamma(&(h * sk), &commitment)?);
+
+            // Ensure a distinct secret key yields a distinct serial number.
+            let other_sk = Scalar::rand(&mut rng);
+            assert_ne!(candidate, CurrentNetwork::compute_serial_number(&other_sk, &commitment)?);
+
+            // Ensure a distinct commitment yields a distinct serial number.
+            let other_commitment = Field::rand(&mut rng);
+            assert_ne!(candidate, CurrentNetwork::compute_serial_number(&sk, &other_commitment)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_compute_serial_numbers_batch() -> Result<()> {
+        let mut rng = TestRng::default();
+        let sk = Scalar::rand(&mut rng);
+
+        for num_commitments in [0, 1, 2, 10, 100] {
+            let commitments = (0..num_commitments).map(|_| Field::rand(&mut rng)).collect::<Vec<_>>();
+
+            // Ensure the batch matches mapping the single serial number, in order.
+            let expected = commitments
+                .iter()
+                .map(|commitment| CurrentNetwork::compute_serial_number(&sk, commitment))
+                .collect::<Result<Vec<_>>>()?;
+            assert_eq!(expected, CurrentNetwork::compute_serial_numbers_batch(&sk, &commitments)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_commit_bhp256_batch() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample a mix of valid and invalid openings.
+        let mut items = Vec::with_capacity(ITERATIONS as usize);
+        let mut expected = Vec::with_capacity(ITERATIONS as usize);
+        for i in 0..ITERATIONS {
+            let input = (0..256).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>();
+            let randomizer = Scalar::rand(&mut rng);
+            let commitment = CurrentNetwork::commit_bhp256(&input, &randomizer)?;
+
+            match i % 2 == 0 {
+                true => items.push((commitment, input, randomizer)),
+                false => items.push((commitment, input, Scalar::rand(&mut rng))),
+            }
+            expected.push(i % 2 == 0);
+        }
+
+        // Ensure the batch verification matches the per-item verification, in order.
+        let candidate = CurrentNetwork::verify_commit_bhp256_batch(&items)?;
+        assert_eq!(expected, candidate);
+        for ((commitment, input, randomizer), is_valid) in items.iter().zip_eq(&candidate) {
+            assert_eq!(CurrentNetwork::verify_commit_bhp256(commitment, input, randomizer)?, *is_valid);
+        }
+
+        // Ensure an empty batch yields no results.
+        assert!(CurrentNetwork::verify_commit_bhp256_batch(&[])?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_bhp256_bases() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample a random input and randomizer.
+        let input = (0..256).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>();
+        let randomizer = Scalar::rand(&mut rng);
+
+        // Construct the preimage as [ 0...0 || DOMAIN || LENGTH(INPUT) || INPUT ], padded to a multiple of 3 bits.
+        let mut preimage = BHP_256.domain().to_vec();
+        preimage.extend((input.len() as u64).to_bits_le());
+        preimage.extend(&input);
+        preimage.resize((preimage.len() + 2) / 3 * 3, false);
+
+        // Recompute the commitment from the exposed bases.
+        let mut expected = Group::<CurrentNetwork>::zero();
+        let window_size = CurrentNetwork::bhp256_bases()[0].len();
+        for (bits, bases) in preimage.chunks(window_size * 3).zip(CurrentNetwork::bhp256_bases()) {
+            for (chunk, base) in bits.chunks(3).zip(bases) {
+                // Compute (1 - 2 * c_2) * (1 + c_0 + 2 * c_1) * base.
+                let mut element = *base;
+                if chunk[0] {
+                    element += base;
+                }
+                if chunk[1] {
+                    element += base.double();
+                }
+                if chunk[2] {
+                    element = -element;
+                }
+                expected += element;
+            }
+        }
+        for (bit, base) in randomizer.to_bits_le().iter().zip_eq(CurrentNetwork::bhp256_random_base()) {
+            if *bit {
+                expected += base;
+            }
+        }
+        assert_eq!(expected.to_x_coordinate(), CurrentNetwork::commit_bhp256(&input, &randomizer)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_pedersen64_bases() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample a random input and randomizer.
+        let input = (0..64).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>();
+        let randomizer = Scalar::rand(&mut rng);
+
+        // Recompute the commitment from the exposed bases.
+        let mut expected = Group::<CurrentNetwork>::zero();
+        for (bit, base) in input.iter().zip_eq(CurrentNetwork::pedersen64_bases()) {
+            if *bit {
+                expected += base;
+            }
+        }
+        for (bit, base) in randomizer.to_bits_le().iter().zip_eq(CurrentNetwork::pedersen64_random_base()) {
+            if *bit {
+                expected += base;
+            }
+        }
+        assert_eq!(expected, CurrentNetwork::commit_ped64(&input, &randomizer)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_aggregate_commitments_ped64() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample two random inputs (whose sum does not overflow), and two random randomizers.
+            let (x, y) = (u32::rand(&mut rng) as u64, u32::rand(&mut rng) as u64);
+            let (r1, r2) = (Scalar::rand(&mut rng), Scalar::rand(&mut rng));
+
+            // Commit to each input individually.
+            let a = CurrentNetwork::commit_ped64(&x.to_bits_le(), &r1)?;
+            let b = CurrentNetwork::commit_ped64(&y.to_bits_le(), &r2)?;
+
+            // Ensure combining the commitments is a commitment to the sum of the inputs and randomizers.
+            let expected = CurrentNetwork::commit_ped64(&(x + y).to_bits_le(), &(r1 + r2))?;
+            assert_eq!(expected, CurrentNetwork::add_commitments_ped64(&a, &b));
+            assert_eq!(expected, CurrentNetwork::aggregate_commitments_ped64(&[a, b]));
+        }
+
+        // Ensure aggregating no commitments yields the identity.
+        assert_eq!(Group::zero(), CurrentNetwork::aggregate_commitments_ped64(&[]));
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_aggregate_ped64() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for num_items in [1, 2, 10] {
+            // Sample random inputs and randomizers.
+            let inputs = (0..num_items).map(|_| u64::rand(&mut rng).to_bits_le()).collect::<Vec<_>>();
+            let randomizers = (0..num_items).map(|_| Scalar::rand(&mut rng)).collect::<Vec<_>>();
+
+            // Compute the aggregate commitment.
+            let commitments = inputs
+                .iter()
+                .zip_eq(&randomizers)
+                .map(|(input, randomizer)| CurrentNetwork::commit_ped64(input, randomizer))
+                .collect::<Result<Vec<_>>>()?;
+            let aggregate = CurrentNetwork::aggregate_commitments_ped64(&commitments);
+
+            // Ensure the valid aggregate commitment is accepted.
+            assert!(CurrentNetwork::verify_aggregate_ped64(&aggregate, &inputs, &randomizers)?);
+
+            // Ensure a tampered aggregate commitment is rejected.
+            let tampered = aggregate + Group::generator();
+            assert!(!CurrentNetwork::verify_aggregate_ped64(&tampered, &inputs, &randomizers)?);
+
+            // Ensure a tampered input is rejected.
+            let mut tampered_inputs = inputs.clone();
+            tampered_inputs[0][0] = !tampered_inputs[0][0];
+            assert!(!CurrentNetwork::verify_aggregate_ped64(&aggregate, &tampered_inputs, &randomizers)?);
+
+            // Ensure a tampered randomizer is rejected.
+            let mut tampered_randomizers = randomizers.clone();
+            tampered_randomizers[0] += Scalar::one();
+            assert!(!CurrentNetwork::verify_aggregate_ped64(&aggregate, &inputs, &tampered_randomizers)?);
+
+            // Ensure mismatching lengths are rejected.
+            assert!(CurrentNetwork::verify_aggregate_ped64(&aggregate, &inputs, &randomizers[1..]).is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_derive_view_key() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let secret = Scalar::rand(&mut rng);
+
+            // Ensure the view key is deterministic, and matches the documented derivation.
+            let candidate = CurrentNetwork::derive_view_key(&secret)?;
+            assert_eq!(candidate, CurrentNetwork::derive_view_key(&secret)?);
+            assert_eq!(candidate, CurrentNetwork::hash_to_scalar_psd2(&[*VIEW_KEY_DOMAIN, secret.to_field()?])?);
+
+            // Ensure a distinct secret yields a distinct view key.
+            let other_secret = Scalar::rand(&mut rng);
+            assert_ne!(candidate, CurrentNetwork::derive_view_key(&other_secret)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_precomputed_tables() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Save the tables, and read them back.
+        let mut bytes = Vec::new();
+        CurrentNetwork::save_precomputed_tables(&mut bytes)?;
+        let tables = PrecomputedTables::read_le(&bytes[..])?;
+        assert_eq!(bytes, tables.to_bytes_le()?);
+
+        // Ensure the loaded tables produce identical results.
+        assert_eq!(CurrentNetwork::g_powers(), &tables.g_powers);
+        for _ in 0..ITERATIONS {
+            let scalar = Scalar::rand(&mut rng);
+            let bits = scalar.to_bits_le();
+            let expected: Group<CurrentNetwork> =
+                tables.g_powers.iter().zip_eq(&bits).filter(|(_, bit)| **bit).map(|(base, _)| base).sum();
+            assert_eq!(CurrentNetwork::g_scalar_multiply(&scalar), expected);
+
+            let input = (0..64).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>();
+            assert_eq!(CurrentNetwork::commit_bhp256(&input, &scalar)?, tables.bhp_256.commit(&input, &scalar)?);
+            assert_eq!(CurrentNetwork::commit_bhp512(&input, &scalar)?, tables.bhp_512.commit(&input, &scalar)?);
+            assert_eq!(CurrentNetwork::commit_bhp768(&input, &scalar)?, tables.bhp_768.commit(&input, &scalar)?);
+            assert_eq!(CurrentNetwork::commit_bhp1024(&input, &scalar)?, tables.bhp_1024.commit(&input, &scalar)?);
+            let expected = tables.pedersen_64.commit_uncompressed(&input, &scalar)?;
+            assert_eq!(CurrentNetwork::commit_ped64(&input, &scalar)?, expected);
+            let expected = tables.pedersen_128.commit_uncompressed(&input, &scalar)?;
+            assert_eq!(CurrentNetwork::commit_ped128(&input, &scalar)?, expected);
+        }
+
+        // Ensure an unknown version, or truncated tables, fail to be read.
+        let mut tampered = bytes.clone();
+        tampered[..2].copy_from_slice(&1u16.to_le_bytes());
+        assert!(PrecomputedTables::read_le(&tampered[..]).is_err());
+        assert!(PrecomputedTables::read_le(&bytes[..bytes.len() - 1]).is_err());
+
+        // Ensure the tables cannot be loaded once they have been initialized.
+        assert!(CurrentNetwork::load_precomputed_tables(&bytes[..]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_poseidon_sizes() -> Result<()> {
+        /// Ensures the exposed sizes match the given Poseidon parameters.
+        fn check_sizes<F: PrimeField, const RATE: usize, const CAPACITY: usize>(
+            parameters: &PoseidonParameters<F, RATE, CAPACITY>,
+        ) -> Result<()> {
+            let width = CurrentNetwork::poseidon_state_width(RATE)?;
+            let capacity = CurrentNetwork::poseidon_capacity(RATE)?;
+            let (full_rounds, partial_rounds) = CurrentNetwork::poseidon_rounds(RATE)?;
+
+            assert_eq!(RATE + CAPACITY, width);
+            assert_eq!(CAPACITY, capacity);
+            assert_eq!(parameters.full_rounds, full_rounds);
+            assert_eq!(parameters.partial_rounds, partial_rounds);
+
+            // Ensure the round constants and MDS matrix are sized accordingly.
+            assert_eq!(full_rounds + partial_rounds, parameters.ark.len());
+            assert!(parameters.ark.iter().all(|round| round.len() == width));
+            assert_eq!(width, parameters.mds.len());
+            assert!(parameters.mds.iter().all(|row| row.len() == width));
+            Ok(())
+        }
+
+        check_sizes(&**POSEIDON_2.parameters())?;
+        check_sizes(&**POSEIDON_4.parameters())?;
+        check_sizes(&**POSEIDON_8.parameters())?;
+
+        // Ensure an uninstantiated rate fails.
+        assert!(CurrentNetwork::poseidon_state_width(3).is_err());
+        assert!(CurrentNetwork::poseidon_capacity(0).is_err());
+        assert!(CurrentNetwork::poseidon_rounds(16).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_psd8_batch() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample inputs of varying lengths.
+        let inputs = (0..ITERATIONS as usize)
+            .map(|i| (0..i % 12).map(|_| Field::rand(&mut rng)).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        // Ensure the batch hash matches the per-input hash, in order.
+        let candidate = CurrentNetwork::hash_psd8_batch(&inputs);
+        assert_eq!(inputs.len(), candidate.len());
+        for (input, candidate) in inputs.iter().zip_eq(candidate) {
+            assert_eq!(CurrentNetwork::hash_psd8(input)?, candidate?);
+        }
+
+        // Ensure an empty batch yields no results.
+        assert!(CurrentNetwork::hash_psd8_batch(&[]).is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_to_scalar_psd2_batch() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample inputs of varying lengths.
+        let inputs = (0..ITERATIONS as usize)
+            .map(|i| (0..i % 6).map(|_| Field::rand(&mut rng)).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        // Ensure the batch hash matches the per-input hash, in order.
+        let candidate = CurrentNetwork::hash_to_scalar_psd2_batch(&inputs);
+        assert_eq!(inputs.len(), candidate.len());
+        for (input, candidate) in inputs.iter().zip_eq(candidate) {
+            assert_eq!(CurrentNetwork::hash_to_scalar_psd2(input)?, candidate?);
+        }
+
+        // Ensure an empty batch yields no results.
+        assert!(CurrentNetwork::hash_to_scalar_psd2_batch(&[]).is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_with_domain_psd8() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a random domain and input.
+            let domain = Field::rand(&mut rng);
+            let input = (0..5).map(|_| Field::rand(&mut rng)).collect::<Vec<_>>();
+
+            // Ensure the hash is deterministic.
+            let candidate = CurrentNetwork::hash_with_domain_psd8(&domain, &input)?;
+            assert_eq!(candidate, CurrentNetwork::hash_with_domain_psd8(&domain, &input)?);
+
+            // Ensure the hash matches hashing the domain as the first input.
+            let mut preimage = vec![domain];
+            preimage.extend_from_slice(&input);
+            assert_eq!(candidate, CurrentNetwork::hash_psd8(&preimage)?);
+
+            // Ensure different domains produce different outputs for the same input.
+            assert_ne!(candidate, CurrentNetwork::hash_with_domain_psd8(&(domain + Field::one()), &input)?);
+            assert_ne!(candidate, CurrentNetwork::hash_psd8(&input)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_merkle_paths_psd() -> Result<()> {
+        const DEPTH: u8 = 8;
+
+        let mut rng = TestRng::default();
+
+        // Sample random leaves, and construct a Merkle tree.
+        let leaves = (0..10).map(|_| vec![Field::rand(&mut rng), Field::rand(&mut rng)]).collect::<Vec<_>>();
+        let tree = CurrentNetwork::merkle_tree_psd::<DEPTH>(&leaves)?;
+
+        // Prove each leaf.
+        let mut items = leaves
+            .iter()
+            .enumerate()
+            .map(|(index, leaf)| Ok((leaf.clone(), tree.prove(index, leaf)?)))
+            .collect::<Result<Vec<_>>>()?;
+
+        // Ensure all valid paths are accepted, matching the individual verification.
+        let candidate = CurrentNetwork::verify_merkle_paths_psd(tree.root(), &items)?;
+        assert_eq!(vec![true; items.len()], candidate);
+
+        // Tamper with one leaf, and ensure only its path is rejected.
+        items[3].0[0] += Field::one();
+        let candidate = CurrentNetwork::verify_merkle_paths_psd(tree.root(), &items)?;
+        for (index, (is_valid, (leaf, path))) in candidate.iter().zip_eq(&items).enumerate() {
+            assert_eq!(index != 3, *is_valid);
+            assert_eq!(CurrentNetwork::verify_merkle_path_psd(path, tree.root(), leaf), *is_valid);
+        }
+
+        // Ensure all paths are rejected for a different root.
+        let candidate = CurrentNetwork::verify_merkle_paths_psd(&Field::rand(&mut rng), &items)?;
+        assert_eq!(vec![false; items.len()], candidate);
+        Ok(())
+    }
+
+    #[test]
+    fn test_compute_tag() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let sk_tag = Scalar::rand(&mut rng);
+            let serial_number = Field::rand(&mut rng);
+
+            // Ensure the tag is deterministic, and matches the documented derivation.
+            let candidate = CurrentNetwork::compute_tag(&sk_tag, &serial_number)?;
+            assert_eq!(candidate, CurrentNetwork::compute_tag(&sk_tag, &serial_number)?);
+            assert_eq!(candidate, CurrentNetwork::hash_psd4(&[*TAG_DOMAIN, sk_tag.to_field()?, serial_number])?);
+
+            // Ensure the tag is sensitive to both inputs.
+            assert_ne!(candidate, CurrentNetwork::compute_tag(&Scalar::rand(&mut rng), &serial_number)?);
+            assert_ne!(candidate, CurrentNetwork::compute_tag(&sk_tag, &Field::rand(&mut rng))?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_random_beacon() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let seed = Field::rand(&mut rng);
+            let round = u64::rand(&mut rng) >> 1;
+
+            // Ensure the beacon is deterministic, and matches the documented derivation.
+            let candidate = CurrentNetwork::random_beacon(&seed, round)?;
+            assert_eq!(candidate, CurrentNetwork::random_beacon(&seed, round)?);
+            assert_eq!(candidate, CurrentNetwork::hash_psd4(&[*BEACON_DOMAIN, seed, Field::from_u64(round)])?);
+
+            // Ensure the beacon is separated across rounds and seeds.
+            assert_ne!(candidate, CurrentNetwork::random_beacon(&seed, round + 1)?);
+            assert_ne!(candidate, CurrentNetwork::random_beacon(&Field::rand(&mut rng), round)?);
+        }
+
+        // Ensure consecutive rounds yield distinct beacons.
+        let seed = Field::rand(&mut rng);
+        let beacons = (0..100).map(|round| CurrentNetwork::random_beacon(&seed, round)).collect::<Result<Vec<_>>>()?;
+        assert!(!has_duplicates(&beacons));
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_to_curve_nums() -> Result<()> {
+        use snarkvm_curves::ProjectiveCurve;
+
+        let points = ["", "a", "b", "AleoAuxiliaryGenerator0", "AleoAuxiliaryGenerator1"]
+            .into_iter()
+            .map(|label| {
+                // Ensure the same label yields the same point.
+                let point = CurrentNetwork::hash_to_curve_nums(label)?;
+                assert_eq!(point, CurrentNetwork::hash_to_curve_nums(label)?);
+                // Ensure the point is in the prime-order subgroup, and is not the identity.
+                assert!(point.to_affine().is_in_correct_subgroup_assuming_on_curve());
+                assert!(!point.is_zero());
+                Ok(point)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Ensure different labels yield different points, which differ from the generator.
+        assert!(!has_duplicates(&points));
+        assert!(!points.contains(&CurrentNetwork::g_powers()[0]));
+        Ok(())
+    }
+
+    #[test]
+    fn test_vrf() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for num_inputs in [0, 1, 2, 5] {
+            // Sample a key pair and an input.
+            let sk = Scalar::rand(&mut rng);
+            let pk = CurrentNetwork::g_scalar_multiply(&sk);
+            let input = (0..num_inputs).map(|_| Field::rand(&mut rng)).collect::<Vec<_>>();
+
+            // Ensure the evaluation is deterministic, and verifies.
+            let (output, proof) = CurrentNetwork::vrf_evaluate(&sk, &input)?;
+            assert_eq!((output, proof), CurrentNetwork::vrf_evaluate(&sk, &input)?);
+            assert!(CurrentNetwork::vrf_verify(&pk, &input, &output, &proof)?);
+
+            // Ensure a tampered output fails.
+            assert!(!CurrentNetwork::vrf_verify(&pk, &input, &(output + Field::one()), &proof)?);
+
+            // Ensure a tampered proof fails.
+            let (gamma, challenge, response) = (*proof.gamma(), *proof.challenge(), *proof.response());
+            let generator = CurrentNetwork::g_powers()[0];
+            for tampered in [
+                VrfProof::new(gamma + generator, challenge, response),
+                VrfProof::new(gamma, challenge + Scalar::one(), response),
+                VrfProof::new(gamma, challenge, response + Scalar::one()),
+            ] {
+                assert!(!CurrentNetwork::vrf_verify(&pk, &input, &output, &tampered)?);
+            }
+
+            // Ensure a different public key or input fails.
+            let other_pk = CurrentNetwork::g_scalar_multiply(&Scalar::rand(&mut rng));
+            assert!(!CurrentNetwork::vrf_verify(&other_pk, &input, &output, &proof)?);
+            let mut other_input = input.clone();
+            other_input.push(Field::rand(&mut rng));
+            assert!(!CurrentNetwork::vrf_verify(&pk, &other_input, &output, &proof)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_public_key_to_address() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a public key.
+            let pk = CurrentNetwork::g_scalar_multiply(&Scalar::rand(&mut rng));
+
+            // Ensure the address round-trips.
+            let address = CurrentNetwork::public_key_to_address(&pk)?;
+            assert_eq!(63, address.len());
+            assert!(address.starts_with(&format!("{}1", CurrentNetwork::ADDRESS_PREFIX)));
+            assert_eq!(pk, CurrentNetwork::address_to_public_key(&address)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_address_to_public_key_fails() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let pk = CurrentNetwork::g_scalar_multiply(&Scalar::rand(&mut rng));
+        let address = CurrentNetwork::public_key_to_address(&pk)?;
+        let data = pk.to_x_coordinate().to_bytes_le()?.to_base32();
+
+        // Ensure a malformed address fails.
+        assert!(CurrentNetwork::address_to_public_key("").is_err());
+        assert!(CurrentNetwork::address_to_public_key(&address[..address.len() - 1]).is_err());
+        let mut tampered = address.clone();
+        tampered.replace_range(10..11, if &address[10..11] == "q" { "p" } else { "q" });
+        assert!(CurrentNetwork::address_to_public_key(&tampered).is_err());
+
+        // Ensure an address for another network fails.
+        let other = bech32::encode("aleotest", data.clone(), bech32::Variant::Bech32m)?;
+        assert!(CurrentNetwork::address_to_public_key(&other).is_err());
+
+        // Ensure an address that is not bech32m encoded fails.
+        let other = bech32::encode(CurrentNetwork::ADDRESS_PREFIX, data, bech32::Variant::Bech32)?;
+        assert!(CurrentNetwork::address_to_public_key(&other).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_hash_to_group_psd2() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for num_inputs in [0, 1, 2, 5] {
+            // Sample a random input, and compute its hash.
+            let input = (0..num_inputs).map(|_| Field::rand(&mut rng)).collect::<Vec<_>>();
+            let point = CurrentNetwork::hash_to_group_psd2(&input)?;
+
+            // Ensure the matching pair verifies.
+            assert!(CurrentNetwork::verify_hash_to_group_psd2(&point, &input)?);
+
+            // Ensure a different point does not verify, including the negation that shares its x-coordinate.
+            assert!(!CurrentNetwork::verify_hash_to_group_psd2(&-point, &input)?);
+            assert!(!CurrentNetwork::verify_hash_to_group_psd2(&Group::rand(&mut rng), &input)?);
+
+            // Ensure a different input does not verify.
+            let mut other_input = input.clone();
+            other_input.push(Field::rand(&mut rng));
+            assert!(!CurrentNetwork::verify_hash_to_group_psd2(&point, &other_input)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_compute_encryption_randomizer() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a view key and its address, and a record nonce for a random scalar `r`.
+            let view_key = Scalar::rand(&mut rng);
+            let address = CurrentNetwork::g_scalar_multiply(&view_key);
+            let r = Scalar::rand(&mut rng);
+            let nonce = CurrentNetwork::g_scalar_multiply(&r);
+
+            // Ensure the randomizer is deterministic, and matches the documented derivation.
+            let candidate = CurrentNetwork::compute_encryption_randomizer(&view_key, &nonce)?;
+            assert_eq!(candidate, CurrentNetwork::compute_encryption_randomizer(&view_key, &nonce)?);
+            let record_view_key = (nonce * view_key).to_x_coordinate();
+            assert_eq!(
+                candidate,
+                CurrentNetwork::hash_many_psd8(&[CurrentNetwork::encryption_domain(), record_view_key], 1)[0]
+            );
+
+            // Ensure the sender, who knows `r` and the address, derives the same record view key.
+            assert_eq!(record_view_key, (address * r).to_x_coordinate());
+
+            // Ensure the randomizer is sensitive to the view key and the nonce.
+            let other_view_key = Scalar::rand(&mut rng);
+            assert_ne!(candidate, CurrentNetwork::compute_encryption_randomizer(&other_view_key, &nonce)?);
+            let other_nonce = CurrentNetwork::g_scalar_multiply(&Scalar::rand(&mut rng));
+            assert_ne!(candidate, CurrentNetwork::compute_encryption_randomizer(&view_key, &other_nonce)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_header() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample a random root and metadata.
+        let root = Field::rand(&mut rng);
+        let metadata = (0..4).map(|_| Field::rand(&mut rng)).collect::<Vec<_>>();
+
+        // Ensure the hash is deterministic.
+        let candidate = CurrentNetwork::hash_header(&root, &metadata)?;
+        assert_eq!(candidate, CurrentNetwork::hash_header(&root, &metadata)?);
+
+        // Ensure the hash is domain-separated from the plain Poseidon hash.
+        let mut preimage = vec![root];
+        preimage.extend_from_slice(&metadata);
+        assert_ne!(candidate, CurrentNetwork::hash_psd8(&preimage)?);
+
+        // Ensure the hash is sensitive to the root.
+        assert_ne!(candidate, CurrentNetwork::hash_header(&Field::rand(&mut rng), &metadata)?);
+        // Ensure the hash is sensitive to each metadata field.
+        for i in 0..metadata.len() {
+            let mut other = metadata.clone();
+            other[i] += Field::one();
+            assert_ne!(candidate, CurrentNetwork::hash_header(&root, &other)?);
+        }
+        // Ensure the hash is sensitive to the number of metadata fields.
+        assert_ne!(candidate, CurrentNetwork::hash_header(&root, &metadata[..3])?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_object_psd8() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let object = (Field::<CurrentNetwork>::rand(&mut rng), Group::<CurrentNetwork>::rand(&mut rng));
+
+            // Ensure the hash is deterministic.
+            let candidate = CurrentNetwork::hash_object_psd8(&object)?;
+            assert_eq!(candidate, CurrentNetwork::hash_object_psd8(&object)?);
+
+            // Ensure the hash is domain-separated from the plain byte hash.
+            assert_ne!(candidate, CurrentNetwork::hash_bytes_psd8(&object.to_bytes_le()?)?);
+
+            // Ensure the hash is sensitive to changes in the object.
+            let other = (object.0 + Field::one(), object.1);
+            assert_ne!(candidate, CurrentNetwork::hash_object_psd8(&other)?);
+        }
+        Ok(())
+    }
 }
@@ -0,0 +1,60 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Note: This is an integration test, so that it runs in its own process, where the generator tables
+//! are not initialized by any other test before they are loaded.
+
+use snarkvm_console_network::{prelude::*, Testnet3};
+
+type CurrentNetwork = Testnet3;
+
+/// The number of bytes in the version and in the number of points, before the first power of `G`.
+const HEADER_SIZE: usize = 6;
+/// The number of bytes in the affine coordinates of a point.
+const POINT_SIZE: usize = 64;
+
+#[test]
+#[cfg(feature = "std")]
+fn test_load_precomputed_tables() -> Result<()> {
+    // Save the tables, which does not initialize them.
+    let mut bytes = Vec::new();
+    CurrentNetwork::save_precomputed_tables(&mut bytes)?;
+
+    // Returns the byte range of the power of `G` at the given index.
+    let point = |index: usize| HEADER_SIZE + index * POINT_SIZE..HEADER_SIZE + (index + 1) * POINT_SIZE;
+
+    // Swap the first two powers of `G`, which are valid points, but do not form the canonical tables.
+    let mut swapped = bytes.clone();
+    swapped[point(0)].copy_from_slice(&bytes[point(1)]);
+    swapped[point(1)].copy_from_slice(&bytes[point(0)]);
+
+    // Ensure the swapped tables are rejected, and do not initialize the tables.
+    assert!(CurrentNetwork::load_precomputed_tables(&swapped[..]).is_err());
+
+    // Load the canonical tables.
+    CurrentNetwork::load_precomputed_tables(&bytes[..])?;
+
+    // Ensure the loaded tables are used.
+    let (x, y) = CurrentNetwork::g_powers()[0].to_xy_coordinates();
+    assert_eq!(bytes[point(0)], [x.to_bytes_le()?, y.to_bytes_le()?].concat());
+    let mut candidate = Vec::new();
+    CurrentNetwork::save_precomputed_tables(&mut candidate)?;
+    assert_eq!(bytes, candidate);
+
+    // Ensure the tables cannot be loaded again.
+    assert!(CurrentNetwork::load_precomputed_tables(&bytes[..]).is_err());
+    Ok(())
+}
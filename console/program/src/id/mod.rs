@@ -20,6 +20,7 @@ mod serialize;
 mod to_address;
 mod to_bits;
 mod to_fields;
+mod to_randomizer_domain;
 
 use crate::Identifier;
 use snarkvm_console_network::prelude::*;
@@ -0,0 +1,53 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> ProgramID<N> {
+    /// Returns the randomizer domain for this program ID, which domain-separates
+    /// the randomizers of this program from the randomizers of every other program.
+    pub fn to_randomizer_domain(&self) -> Result<Field<N>> {
+        // Compute the program randomizer domain as `Hash(randomizer_domain || Hash(program_id))`.
+        let program_hash = N::hash_psd2(&self.to_fields()?)?;
+        N::hash_psd2(&[N::randomizer_domain(), program_hash])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_to_randomizer_domain() -> Result<()> {
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo")?;
+
+        // Ensure the domain is deterministic.
+        let domain = program_id.to_randomizer_domain()?;
+        assert_eq!(domain, ProgramID::<CurrentNetwork>::from_str("hello.aleo")?.to_randomizer_domain()?);
+        // Ensure the domain is separated from the global randomizer domain.
+        assert_ne!(domain, CurrentNetwork::randomizer_domain());
+
+        // Ensure different program IDs yield different domains.
+        for other in ["hello.world", "world.aleo", "credits.aleo"] {
+            let other = ProgramID::<CurrentNetwork>::from_str(other)?;
+            assert_ne!(domain, other.to_randomizer_domain()?);
+        }
+        Ok(())
+    }
+}
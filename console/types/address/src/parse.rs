@@ -16,8 +16,6 @@
 
 use super::*;
 
-static ADDRESS_PREFIX: &str = "aleo";
-
 impl<E: Environment> Parser for Address<E> {
     /// Parses a string into an address.
     #[inline]
@@ -46,7 +44,7 @@ impl<E: Environment> FromStr for Address<E> {
         }
         // Decode the address string from bech32m.
         let (hrp, data, variant) = bech32::decode(address)?;
-        if hrp != ADDRESS_PREFIX {
+        if hrp != E::ADDRESS_PREFIX {
             bail!("Failed to decode address: '{hrp}' is an invalid prefix")
         } else if data.is_empty() {
             bail!("Failed to decode address: data field is empty")
@@ -71,7 +69,7 @@ impl<E: Environment> Display for Address<E> {
         let bytes = self.to_bytes_le().map_err(|_| fmt::Error)?;
         // Encode the bytes into bech32m.
         let string =
-            bech32::encode(ADDRESS_PREFIX, bytes.to_base32(), bech32::Variant::Bech32m).map_err(|_| fmt::Error)?;
+            bech32::encode(E::ADDRESS_PREFIX, bytes.to_base32(), bech32::Variant::Bech32m).map_err(|_| fmt::Error)?;
         // Output the string.
         Display::fmt(&string, f)
     }
@@ -101,7 +99,7 @@ mod tests {
             let expected = format!("{address}");
             let (remainder, candidate) = Address::<CurrentEnvironment>::parse(&expected).unwrap();
             assert_eq!(format!("{expected}"), candidate.to_string());
-            assert_eq!(ADDRESS_PREFIX, candidate.to_string().split('1').next().unwrap());
+            assert_eq!(CurrentEnvironment::ADDRESS_PREFIX, candidate.to_string().split('1').next().unwrap());
             assert_eq!("", remainder);
         }
         Ok(())
@@ -118,7 +116,7 @@ mod tests {
             // Check the string representation.
             let candidate = format!("{expected}");
             assert_eq!(expected, Address::from_str(&candidate)?);
-            assert_eq!(ADDRESS_PREFIX, candidate.to_string().split('1').next().unwrap());
+            assert_eq!(CurrentEnvironment::ADDRESS_PREFIX, candidate.to_string().split('1').next().unwrap());
         }
         Ok(())
     }
@@ -133,7 +131,7 @@ mod tests {
 
             let candidate = expected.to_string();
             assert_eq!(format!("{expected}"), candidate);
-            assert_eq!(ADDRESS_PREFIX, candidate.split('1').next().unwrap());
+            assert_eq!(CurrentEnvironment::ADDRESS_PREFIX, candidate.split('1').next().unwrap());
 
             let candidate_recovered = Address::<CurrentEnvironment>::from_str(&candidate.to_string())?;
             assert_eq!(expected, candidate_recovered);
@@ -248,6 +248,43 @@ impl<E: Environment> SquareRoot for Field<E> {
     }
 }
 
+impl<E: Environment> Field<E> {
+    /// Returns `true` if `self` is a quadratic residue or zero, without computing its square root.
+    /// This evaluates the Legendre symbol as `self^((MODULUS - 1) / 2)`.
+    #[inline]
+    pub fn is_quadratic_residue(&self) -> bool {
+        !self.field.legendre().is_qnr()
+    }
+
+    /// Returns `self` to the power of `exponent`, using a Montgomery ladder.
+    ///
+    /// Unlike `Pow::pow`, which only multiplies on the set bits of the exponent, this method performs
+    /// one multiplication and one squaring for each of the `Field::size_in_bits()` bits of the exponent,
+    /// and selects the operands arithmetically rather than by branching on the bits. This removes the
+    /// dependence of the sequence of operations on the exponent, and should be used when the exponent is secret.
+    /// Note: The running time remains only as independent of the exponent as the underlying field arithmetic.
+    pub fn pow_ct(&self, exponent: &Self) -> Self {
+        // Conditionally swaps `a` and `b` if `swap` is one, and leaves them unchanged if `swap` is zero.
+        let conditional_swap = |a: &mut Self, b: &mut Self, swap: &Self| {
+            let delta = (*a - *b) * swap;
+            *a -= delta;
+            *b += delta;
+        };
+
+        // Initialize the ladder, maintaining the invariant `r1 = r0 * self`.
+        let (mut r0, mut r1) = (Field::one(), *self);
+        // Iterate over every bit of the exponent, from the most-significant bit.
+        for bit in exponent.to_bits_be() {
+            let swap = Field::from_u64(bit as u64);
+            conditional_swap(&mut r0, &mut r1, &swap);
+            r1 *= r0;
+            r0 = r0.square();
+            conditional_swap(&mut r0, &mut r1, &swap);
+        }
+        r0
+    }
+}
+
 impl<E: Environment> Sum<Field<E>> for Field<E> {
     /// Returns the `sum` of `self` and `other`.
     #[inline]
@@ -295,4 +332,47 @@ mod tests {
         let result = std::panic::catch_unwind(|| one / zero);
         assert!(result.is_err()); // Probe further for specific error type here, if desired
     }
+
+    #[test]
+    fn test_is_quadratic_residue() {
+        let mut rng = TestRng::default();
+
+        // Ensure zero is a quadratic residue.
+        assert!(Field::<CurrentEnvironment>::zero().is_quadratic_residue());
+
+        for _ in 0..1000 {
+            // Ensure the result matches whether a square root exists.
+            let candidate = Field::<CurrentEnvironment>::rand(&mut rng);
+            assert_eq!(candidate.square_root().is_ok(), candidate.is_quadratic_residue());
+
+            // Ensure every square is a quadratic residue.
+            assert!(candidate.square().is_quadratic_residue());
+        }
+    }
+
+    #[test]
+    fn test_pow_ct() {
+        let mut rng = TestRng::default();
+
+        let zero = Field::<CurrentEnvironment>::zero();
+        let one = Field::<CurrentEnvironment>::one();
+
+        // Ensure the edge cases match the regular exponentiation.
+        for base in [zero, one, -one] {
+            for exponent in [zero, one, -one] {
+                assert_eq!(base.pow(exponent), base.pow_ct(&exponent));
+            }
+        }
+
+        for _ in 0..100 {
+            // Ensure the result matches the regular exponentiation.
+            let base = Field::<CurrentEnvironment>::rand(&mut rng);
+            let exponent = Field::<CurrentEnvironment>::rand(&mut rng);
+            assert_eq!(base.pow(exponent), base.pow_ct(&exponent));
+
+            // Ensure the trivial exponents are handled.
+            assert_eq!(one, base.pow_ct(&zero));
+            assert_eq!(base, base.pow_ct(&one));
+        }
+    }
 }
@@ -161,4 +161,20 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_hash_object_psd8() -> Result<()> {
+        // Sample the transitions.
+        let transitions =
+            crate::process::test_helpers::sample_transitions(&[("token.aleo", "transfer"), ("token.aleo", "transfer")]);
+
+        // Ensure the hash is stable across a round trip through bytes.
+        let expected = CurrentNetwork::hash_object_psd8(&transitions[0])?;
+        let transition = Transition::<CurrentNetwork>::read_le(&transitions[0].to_bytes_le()?[..])?;
+        assert_eq!(expected, CurrentNetwork::hash_object_psd8(&transition)?);
+
+        // Ensure the hash is sensitive to changes in the transition.
+        assert_ne!(expected, CurrentNetwork::hash_object_psd8(&transitions[1])?);
+        Ok(())
+    }
 }
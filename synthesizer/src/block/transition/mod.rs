@@ -22,6 +22,8 @@ pub use output::Output;
 
 mod bytes;
 mod merkle;
+#[cfg(any(test, feature = "test-utils"))]
+mod sample;
 mod serialize;
 mod string;
 
@@ -0,0 +1,44 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Transition<N> {
+    /// Returns a copy of the transition with the given program ID, function name, inputs, outputs, and fee,
+    /// for testing the `Transition` and `Execution` APIs.
+    ///
+    /// Note: The copy is not cryptographically valid, as it reuses the proof, `tpk`, and `tcm` of `self`.
+    pub fn sample_derived(
+        &self,
+        program_id: ProgramID<N>,
+        function_name: Identifier<N>,
+        inputs: Vec<Input<N>>,
+        outputs: Vec<Output<N>>,
+        fee: i64,
+    ) -> Result<Self> {
+        Self::new(
+            program_id,
+            function_name,
+            inputs,
+            outputs,
+            self.finalize.clone(),
+            self.proof.clone(),
+            self.tpk,
+            self.tcm,
+            fee,
+        )
+    }
+}
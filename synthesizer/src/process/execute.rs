@@ -92,52 +92,9 @@ impl<N: Network> Process<N> {
             #[cfg(debug_assertions)]
             println!("Verifying transition for {}/{}...", transition.program_id(), transition.function_name());
 
-            // Ensure the transition ID is correct.
-            ensure!(**transition.id() == transition.to_root()?, "The transition ID is incorrect");
-            // Ensure the number of inputs is within the allowed range.
-            ensure!(transition.inputs().len() <= N::MAX_INPUTS, "Transition exceeded maximum number of inputs");
-            // Ensure the number of outputs is within the allowed range.
-            ensure!(transition.outputs().len() <= N::MAX_INPUTS, "Transition exceeded maximum number of outputs");
-
-            // Compute the function ID as `Hash(network_id, program_id, function_name)`.
-            let function_id = N::hash_bhp1024(
-                &(
-                    U16::<N>::new(N::ID),
-                    transition.program_id().name(),
-                    transition.program_id().network(),
-                    transition.function_name(),
-                )
-                    .to_bits_le(),
-            )?;
-
-            // Ensure each input is valid.
-            if transition
-                .inputs()
-                .iter()
-                .enumerate()
-                .any(|(index, input)| !input.verify(function_id, transition.tcm(), index))
-            {
-                bail!("Failed to verify a transition input")
-            }
-            lap!(timer, "Verify the inputs");
-
-            // Ensure each output is valid.
-            let num_inputs = transition.inputs().len();
-            if transition
-                .outputs()
-                .iter()
-                .enumerate()
-                .any(|(index, output)| !output.verify(function_id, transition.tcm(), num_inputs + index))
-            {
-                bail!("Failed to verify a transition output")
-            }
-            lap!(timer, "Verify the outputs");
-
-            // Ensure the fee is correct.
-            match Program::is_coinbase(transition.program_id(), transition.function_name()) {
-                true => ensure!(transition.fee() < &0, "The fee must be negative in a coinbase transition"),
-                false => ensure!(transition.fee() >= &0, "The fee must be zero or positive"),
-            }
+            // Ensure the transition is well-formed.
+            Self::verify_transition_structure(&transition)?;
+            lap!(timer, "Verify the transition structure");
 
             // Compute the x- and y-coordinate of `tpk`.
             let (tpk_x, tpk_y) = transition.tpk().to_xy_coordinates();
@@ -227,6 +184,57 @@ impl<N: Network> Process<N> {
         Ok(())
     }
 
+    /// Ensures the given transition is well-formed, i.e. its ID, its number of inputs and outputs,
+    /// its input and output IDs, and the sign of its fee are correct.
+    /// Note: This does *not* verify the transition proof, nor that the program and function exist.
+    pub(crate) fn verify_transition_structure(transition: &Transition<N>) -> Result<()> {
+        // Ensure the transition ID is correct.
+        ensure!(**transition.id() == transition.to_root()?, "The transition ID is incorrect");
+        // Ensure the number of inputs is within the allowed range.
+        ensure!(transition.inputs().len() <= N::MAX_INPUTS, "Transition exceeded maximum number of inputs");
+        // Ensure the number of outputs is within the allowed range.
+        ensure!(transition.outputs().len() <= N::MAX_INPUTS, "Transition exceeded maximum number of outputs");
+
+        // Compute the function ID as `Hash(network_id, program_id, function_name)`.
+        let function_id = N::hash_bhp1024(
+            &(
+                U16::<N>::new(N::ID),
+                transition.program_id().name(),
+                transition.program_id().network(),
+                transition.function_name(),
+            )
+                .to_bits_le(),
+        )?;
+
+        // Ensure each input is valid.
+        if transition
+            .inputs()
+            .iter()
+            .enumerate()
+            .any(|(index, input)| !input.verify(function_id, transition.tcm(), index))
+        {
+            bail!("Failed to verify a transition input")
+        }
+
+        // Ensure each output is valid.
+        let num_inputs = transition.inputs().len();
+        if transition
+            .outputs()
+            .iter()
+            .enumerate()
+            .any(|(index, output)| !output.verify(function_id, transition.tcm(), num_inputs + index))
+        {
+            bail!("Failed to verify a transition output")
+        }
+
+        // Ensure the fee is correct.
+        match Program::is_coinbase(transition.program_id(), transition.function_name()) {
+            true => ensure!(transition.fee() < &0, "The fee must be negative in a coinbase transition"),
+            false => ensure!(transition.fee() >= &0, "The fee must be zero or positive"),
+        }
+        Ok(())
+    }
+
     /// Finalizes the execution.
     /// This method assumes the given execution **is valid**.
     #[inline]
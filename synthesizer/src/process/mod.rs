@@ -279,8 +279,12 @@ impl<N: Network> Process<N> {
 #[cfg(test)]
 pub(crate) mod test_helpers {
     use super::*;
-    use crate::{Process, Program, Transition};
-    use console::{account::PrivateKey, network::Testnet3, program::Identifier};
+    use crate::{Input, Output, Process, Program, Transition};
+    use console::{
+        account::PrivateKey,
+        network::Testnet3,
+        program::{Identifier, ProgramID},
+    };
 
     use once_cell::sync::OnceCell;
 
@@ -388,6 +392,45 @@ function compute:
         execution.pop().unwrap()
     }
 
+    /// Samples a list of distinct transitions, one for each of the given `(program ID, function name)` pairs,
+    /// by relabelling the transitions of `Execution::sample_execution`.
+    pub(crate) fn sample_transitions(locators: &[(&str, &str)]) -> Vec<Transition<CurrentNetwork>> {
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+        // Sample the transitions, in executions of up to the maximum number of transitions each.
+        locators
+            .chunks(Execution::<CurrentNetwork>::MAX_TRANSITIONS)
+            .flat_map(|locators| {
+                let execution = Execution::<CurrentNetwork>::sample_execution(locators.len(), rng).unwrap();
+                execution.into_transitions().zip_eq(locators).collect::<Vec<_>>()
+            })
+            .map(|(transition, (program_id, function_name))| {
+                transition
+                    .sample_derived(
+                        ProgramID::from_str(program_id).unwrap(),
+                        Identifier::from_str(function_name).unwrap(),
+                        transition.inputs().to_vec(),
+                        transition.outputs().to_vec(),
+                        *transition.fee(),
+                    )
+                    .unwrap()
+            })
+            .collect()
+    }
+
+    /// Samples a transition with the given inputs and outputs.
+    pub(crate) fn sample_transition_with(
+        inputs: Vec<Input<CurrentNetwork>>,
+        outputs: Vec<Output<CurrentNetwork>>,
+    ) -> Transition<CurrentNetwork> {
+        // Retrieve a transition to derive from.
+        let transition = sample_transition();
+        // Construct the transition with the given inputs and outputs.
+        transition
+            .sample_derived(*transition.program_id(), *transition.function_name(), inputs, outputs, *transition.fee())
+            .unwrap()
+    }
+
     /// Initializes a new process with the given program.
     pub(crate) fn sample_process(program: &Program<CurrentNetwork>) -> Process<CurrentNetwork> {
         // Construct a new process.
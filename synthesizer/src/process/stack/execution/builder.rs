@@ -0,0 +1,152 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// A builder for an `Execution`, which validates the execution once, when it is built.
+#[derive(Clone)]
+pub struct ExecutionBuilder<N: Network> {
+    /// The transitions.
+    transitions: Vec<Transition<N>>,
+    /// The global state root.
+    global_state_root: N::StateRoot,
+    /// The inclusion proof.
+    inclusion_proof: Option<Proof<N>>,
+}
+
+impl<N: Network> ExecutionBuilder<N> {
+    /// Initializes a new `ExecutionBuilder`, without transitions.
+    pub fn new() -> Self {
+        Self { transitions: Vec::new(), global_state_root: Default::default(), inclusion_proof: None }
+    }
+
+    /// Sets the global state root of the execution.
+    pub fn with_global_state_root(mut self, global_state_root: N::StateRoot) -> Self {
+        self.global_state_root = global_state_root;
+        self
+    }
+
+    /// Sets the inclusion proof of the execution.
+    pub fn with_inclusion_proof(mut self, inclusion_proof: Option<Proof<N>>) -> Self {
+        self.inclusion_proof = inclusion_proof;
+        self
+    }
+
+    /// Appends the given transition to the execution. The transition is validated at `build` time.
+    pub fn push(mut self, transition: Transition<N>) -> Self {
+        self.transitions.push(transition);
+        self
+    }
+
+    /// Appends the given transition to the execution, erroring immediately if the transition ID
+    /// was already pushed, or if the execution would exceed `Execution::MAX_TRANSITIONS`.
+    pub fn try_push(self, transition: Transition<N>) -> Result<Self> {
+        // Ensure the transition ID is unique.
+        let transition_id = transition.id();
+        ensure!(
+            !self.transitions.iter().any(|candidate| candidate.id() == transition_id),
+            "Cannot push the transition '{transition_id}' to the execution more than once"
+        );
+        // Ensure the number of transitions is within the limit.
+        ensure!(
+            self.transitions.len() < Execution::<N>::MAX_TRANSITIONS,
+            "Cannot push more than {} transitions to the execution",
+            Execution::<N>::MAX_TRANSITIONS
+        );
+        Ok(self.push(transition))
+    }
+
+    /// Returns the execution, after ensuring the transitions are non-empty and have unique IDs,
+    /// and the execution is within its limits.
+    pub fn build(self) -> Result<Execution<N>> {
+        // Ensure the transitions are not empty.
+        ensure!(!self.transitions.is_empty(), "Cannot build an execution without transitions");
+        // Ensure the transition IDs are unique.
+        let mut transition_ids = IndexSet::with_capacity(self.transitions.len());
+        for transition in &self.transitions {
+            ensure!(
+                transition_ids.insert(transition.id()),
+                "Cannot build an execution that contains the transition '{}' more than once",
+                transition.id()
+            );
+        }
+        // Construct the execution.
+        let execution = Execution::from(self.transitions.into_iter(), self.global_state_root, self.inclusion_proof)?;
+        // Ensure the execution is within its limits.
+        execution.validate_against_limits()?;
+        Ok(execution)
+    }
+}
+
+impl<N: Network> Default for ExecutionBuilder<N> {
+    /// Initializes a new `ExecutionBuilder`, without transitions.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_build() -> Result<()> {
+        // Sample the execution.
+        let expected = Execution::<CurrentNetwork>::sample_execution(3, &mut TestRng::default())?;
+        let (transitions, root, proof) = expected.clone().into_parts();
+
+        // Ensure building with `push` and with `try_push` matches the sampled execution.
+        let builder =
+            ExecutionBuilder::<CurrentNetwork>::new().with_global_state_root(root).with_inclusion_proof(proof);
+        let candidate = transitions.iter().cloned().fold(builder.clone(), ExecutionBuilder::push).build()?;
+        assert_eq!(expected, candidate);
+        let candidate = transitions.iter().cloned().try_fold(builder, ExecutionBuilder::try_push)?.build()?;
+        assert_eq!(expected, candidate);
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_fails() -> Result<()> {
+        // Sample the transitions.
+        let (transitions, _, _) =
+            Execution::<CurrentNetwork>::sample_execution(2, &mut TestRng::default())?.into_parts();
+        let builder = ExecutionBuilder::<CurrentNetwork>::new().push(transitions[0].clone());
+
+        // Ensure an empty execution fails.
+        let error = ExecutionBuilder::<CurrentNetwork>::new().build().unwrap_err();
+        assert!(error.to_string().contains("without transitions"), "{error}");
+
+        // Ensure a duplicate transition fails, when pushed and when built.
+        assert!(builder.clone().try_push(transitions[0].clone()).is_err());
+        let error = builder.clone().push(transitions[0].clone()).build().unwrap_err();
+        assert!(error.to_string().contains("more than once"), "{error}");
+        builder.clone().push(transitions[1].clone()).build()?;
+
+        // Ensure exceeding the number of transitions fails, when pushed and when built.
+        let locators = vec![("token.aleo", "transfer"); Execution::<CurrentNetwork>::MAX_TRANSITIONS + 1];
+        let mut transitions = crate::process::test_helpers::sample_transitions(&locators);
+        let last = transitions.pop().unwrap();
+        let builder =
+            transitions.into_iter().try_fold(ExecutionBuilder::<CurrentNetwork>::new(), ExecutionBuilder::try_push)?;
+        assert!(builder.clone().try_push(last.clone()).is_err());
+        let error = builder.push(last).build().unwrap_err();
+        assert!(error.to_string().starts_with("Execution exceeds"), "{error}");
+        Ok(())
+    }
+}
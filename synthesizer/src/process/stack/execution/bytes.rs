@@ -18,23 +18,44 @@ use super::*;
 
 impl<N: Network> FromBytes for Execution<N> {
     /// Reads the execution from a buffer.
-    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+    fn read_le<R: Read>(reader: R) -> IoResult<Self> {
+        Self::read_le_streaming(reader).map_err(|e| error(e.to_string()))
+    }
+}
+
+impl<N: Network> Execution<N> {
+    /// Reads the execution from a reader, one transition at a time.
+    ///
+    /// Each transition is inserted into the execution as soon as it is read, so that a large
+    /// execution is never buffered twice, and the stream is rejected as soon as it is found to be
+    /// invalid or truncated, or to contain a duplicate transition. This is the implementation of `read_le`.
+    pub fn read_le_streaming<R: Read>(mut reader: R) -> Result<Self> {
         // Read the version.
         let version = u16::read_le(&mut reader)?;
         // Ensure the version is valid.
-        if version != 0 {
-            return Err(error("Invalid execution version"));
-        }
+        ensure!(version == VERSION, "Invalid execution version '{version}'");
         // Read the number of transitions.
         let num_transitions = u16::read_le(&mut reader)?;
         // Ensure the number of transitions is nonzero.
         if num_transitions == 0 {
             warn!("Execution (from 'read_le') has no transitions");
-            return Err(error("Execution (from 'read_le') has no transitions"));
+            bail!("Execution (from 'read_le') has no transitions");
         }
+
         // Read the transitions.
-        let transitions =
-            (0..num_transitions).map(|_| Transition::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        let mut transitions = IndexMap::with_capacity(num_transitions as usize);
+        for index in 0..num_transitions {
+            // Read the next transition.
+            let transition = Transition::<N>::read_le(&mut reader)
+                .map_err(|e| anyhow!("Failed to read transition {index} of {num_transitions}: {e}"))?;
+            // Ensure the transition ID is unique.
+            let transition_id = *transition.id();
+            ensure!(
+                transitions.insert(transition_id, transition).is_none(),
+                "Execution (from 'read_le') contains a duplicate transition '{transition_id}'"
+            );
+        }
+
         // Read the global state root.
         let global_state_root = N::StateRoot::read_le(&mut reader)?;
         // Read the inclusion proof variant.
@@ -43,10 +64,74 @@ impl<N: Network> FromBytes for Execution<N> {
         let inclusion_proof = match inclusion_variant {
             0 => None,
             1 => Some(Proof::read_le(&mut reader)?),
-            _ => return Err(error("Invalid inclusion proof variant '{inclusion_variant}'")),
+            _ => bail!("Invalid inclusion proof variant '{inclusion_variant}'"),
         };
         // Return the new `Execution` instance.
-        Self::from(transitions.into_iter(), global_state_root, inclusion_proof).map_err(|e| error(e.to_string()))
+        Ok(Self { transitions, global_state_root, inclusion_proof, metadata: None })
+    }
+
+    /// Writes the given executions to a stream, as a sequence of records, where each record is
+    /// the byte representation of an execution, prefixed by its length in bytes as a `u32`.
+    /// As each record is self-delimiting, the stream may be extended by appending further records.
+    pub fn write_executions<W: Write>(mut writer: W, executions: &[Self]) -> Result<()> {
+        for (index, execution) in executions.iter().enumerate() {
+            // Serialize the execution.
+            let bytes = execution.to_bytes_le()?;
+            // Ensure the length fits in the prefix.
+            let num_bytes = match u32::try_from(bytes.len()) {
+                Ok(num_bytes) => num_bytes,
+                Err(_) => bail!("Execution {index} is too large for a record ({} bytes)", bytes.len()),
+            };
+            // Write the length, followed by the execution.
+            num_bytes.write_le(&mut writer)?;
+            writer.write_all(&bytes)?;
+        }
+        Ok(())
+    }
+
+    /// Reads the executions from a stream of records written by `Execution::write_executions`,
+    /// until the end of the stream.
+    ///
+    /// This method errors if the stream ends within a record, i.e. a truncated final record is never dropped,
+    /// or if a record does not contain exactly one execution.
+    pub fn read_executions<R: Read>(mut reader: R) -> Result<Vec<Self>> {
+        let mut executions = Vec::new();
+        loop {
+            let index = executions.len();
+
+            // Read the length prefix, stopping if the stream ends before the record.
+            let mut prefix = [0u8; 4];
+            let mut num_read = 0;
+            while num_read < prefix.len() {
+                match reader.read(&mut prefix[num_read..]) {
+                    Ok(0) => break,
+                    Ok(n) => num_read += n,
+                    Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+                    Err(e) => return Err(e.into()),
+                }
+            }
+            match num_read {
+                0 => return Ok(executions),
+                4 => (),
+                _ => bail!("Record {index} is truncated: found {num_read} of 4 bytes for its length"),
+            }
+            let num_bytes = u32::from_le_bytes(prefix) as usize;
+
+            // Read the execution bytes, without allocating more than the stream provides.
+            let mut bytes = Vec::new();
+            (&mut reader).take(num_bytes as u64).read_to_end(&mut bytes)?;
+            ensure!(
+                bytes.len() == num_bytes,
+                "Record {index} is truncated: found {} of {num_bytes} bytes",
+                bytes.len()
+            );
+
+            // Deserialize the execution, ensuring the record contains no trailing bytes.
+            let mut remaining = &bytes[..];
+            let execution = Self::read_le(&mut remaining).map_err(|e| anyhow!("Failed to read record {index}: {e}"))?;
+            ensure!(remaining.is_empty(), "Record {index} contains {} trailing bytes", remaining.len());
+            executions.push(execution);
+        }
     }
 }
 
@@ -54,7 +139,7 @@ impl<N: Network> ToBytes for Execution<N> {
     /// Writes the execution to a buffer.
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
         // Write the version.
-        0u16.write_le(&mut writer)?;
+        VERSION.write_le(&mut writer)?;
         // Write the number of transitions.
         (self.transitions.len() as u16).write_le(&mut writer)?;
         // Write the transitions.
@@ -93,4 +178,108 @@ mod tests {
         assert!(Execution::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
         Ok(())
     }
+
+    #[test]
+    fn test_bytes_version() -> Result<()> {
+        // Construct a new execution.
+        let expected = crate::process::test_helpers::sample_execution();
+        let expected_bytes = expected.to_bytes_le()?;
+
+        // Ensure the bytes are prefixed with the current version.
+        assert_eq!(VERSION.to_bytes_le()?, expected_bytes[..2]);
+        assert_eq!(expected, Execution::read_le(&expected_bytes[..])?);
+        assert_eq!(expected, Execution::read_le_streaming(&expected_bytes[..])?);
+
+        // Ensure an unknown version is rejected.
+        for version in [VERSION + 1, u16::MAX] {
+            let mut bytes = expected_bytes.clone();
+            bytes[..2].copy_from_slice(&version.to_le_bytes());
+            assert!(Execution::<CurrentNetwork>::read_le(&bytes[..]).is_err());
+            assert!(Execution::<CurrentNetwork>::read_le_streaming(&bytes[..]).is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_le_streaming() -> Result<()> {
+        // Construct a new execution.
+        let expected = crate::process::test_helpers::sample_execution();
+        let expected_bytes = expected.to_bytes_le()?;
+
+        // Ensure a valid stream is read back into the same execution.
+        assert_eq!(expected, Execution::read_le_streaming(&expected_bytes[..])?);
+
+        // Ensure a truncated stream fails.
+        for num_bytes in [0, 2, 4, expected_bytes.len() / 2, expected_bytes.len() - 1] {
+            assert!(Execution::<CurrentNetwork>::read_le_streaming(&expected_bytes[..num_bytes]).is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_bytes_duplicate_transition() -> Result<()> {
+        // Sample a transition.
+        let transition = Execution::<CurrentNetwork>::sample_execution(1, &mut TestRng::default())?.pop()?;
+
+        // Construct the bytes of an execution with the same transition twice.
+        let mut bytes = VERSION.to_bytes_le()?;
+        2u16.write_le(&mut bytes)?;
+        transition.write_le(&mut bytes)?;
+        transition.write_le(&mut bytes)?;
+        <CurrentNetwork as Network>::StateRoot::default().write_le(&mut bytes)?;
+        0u8.write_le(&mut bytes)?;
+
+        // Ensure the duplicate transition is rejected.
+        assert!(Execution::<CurrentNetwork>::read_le(&bytes[..]).is_err());
+        assert!(Execution::<CurrentNetwork>::read_le_streaming(&bytes[..]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_executions_stream() -> Result<()> {
+        // Sample the executions.
+        let rng = &mut TestRng::default();
+        let executions =
+            (0..3).map(|_| Execution::<CurrentNetwork>::sample_execution(1, rng)).collect::<Result<Vec<_>>>()?;
+
+        for num_executions in 0..=executions.len() {
+            // Ensure the executions round-trip through a stream.
+            let mut stream = Vec::new();
+            Execution::write_executions(&mut stream, &executions[..num_executions])?;
+            assert_eq!(&executions[..num_executions], Execution::read_executions(&stream[..])?);
+        }
+
+        // Ensure a stream may be extended by appending records.
+        let mut stream = Vec::new();
+        Execution::write_executions(&mut stream, &executions[..1])?;
+        Execution::write_executions(&mut stream, &executions[1..])?;
+        assert_eq!(executions, Execution::read_executions(&stream[..])?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_executions_truncated() -> Result<()> {
+        // Sample the executions.
+        let executions = vec![crate::process::test_helpers::sample_execution(); 2];
+        let mut stream = Vec::new();
+        Execution::write_executions(&mut stream, &executions)?;
+        let record_len = 4 + executions[0].to_bytes_le()?.len();
+        assert_eq!(2 * record_len, stream.len());
+
+        // Ensure a stream truncated within the final record fails, rather than dropping the record.
+        for num_bytes in [record_len + 1, record_len + 4, record_len + 5, stream.len() - 1] {
+            assert!(Execution::<CurrentNetwork>::read_executions(&stream[..num_bytes]).is_err());
+        }
+        // Ensure a stream truncated at a record boundary yields the complete records.
+        assert_eq!(executions[..1], Execution::read_executions(&stream[..record_len])?);
+
+        // Ensure a record with trailing bytes fails.
+        let mut stream = Vec::new();
+        let bytes = executions[0].to_bytes_le()?;
+        (bytes.len() as u32 + 1).write_le(&mut stream)?;
+        stream.extend_from_slice(&bytes);
+        stream.push(0);
+        assert!(Execution::<CurrentNetwork>::read_executions(&stream[..]).is_err());
+        Ok(())
+    }
 }
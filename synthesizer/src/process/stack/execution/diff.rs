@@ -0,0 +1,148 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The differences between two executions.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExecutionDiff<N: Network> {
+    /// The IDs of the transitions that are only in the first execution.
+    only_in_self: Vec<N::TransitionID>,
+    /// The IDs of the transitions that are only in the second execution.
+    only_in_other: Vec<N::TransitionID>,
+    /// The IDs of the transitions that are in both executions, but differ.
+    modified: Vec<N::TransitionID>,
+    /// Whether the global state roots match.
+    global_state_roots_match: bool,
+    /// Whether the inclusion proofs match.
+    inclusion_proofs_match: bool,
+}
+
+impl<N: Network> ExecutionDiff<N> {
+    /// Returns the IDs of the transitions that are only in the first execution.
+    pub fn only_in_self(&self) -> &[N::TransitionID] {
+        &self.only_in_self
+    }
+
+    /// Returns the IDs of the transitions that are only in the second execution.
+    pub fn only_in_other(&self) -> &[N::TransitionID] {
+        &self.only_in_other
+    }
+
+    /// Returns the IDs of the transitions that are in both executions, but differ.
+    pub fn modified(&self) -> &[N::TransitionID] {
+        &self.modified
+    }
+
+    /// Returns `true` if the global state roots match.
+    pub const fn global_state_roots_match(&self) -> bool {
+        self.global_state_roots_match
+    }
+
+    /// Returns `true` if the inclusion proofs match.
+    pub const fn inclusion_proofs_match(&self) -> bool {
+        self.inclusion_proofs_match
+    }
+
+    /// Returns `true` if the executions contain the same transitions, global state root, and inclusion proof.
+    /// Note: This ignores the order of the transitions.
+    pub fn is_empty(&self) -> bool {
+        self.only_in_self.is_empty()
+            && self.only_in_other.is_empty()
+            && self.modified.is_empty()
+            && self.global_state_roots_match
+            && self.inclusion_proofs_match
+    }
+}
+
+impl<N: Network> Execution<N> {
+    /// Returns the differences between `self` and `other`.
+    /// The transition IDs in each list are in the order of the execution they are taken from.
+    pub fn diff(&self, other: &Execution<N>) -> ExecutionDiff<N> {
+        let mut only_in_self = Vec::new();
+        let mut modified = Vec::new();
+        for (id, transition) in &self.transitions {
+            match other.transitions.get(id) {
+                None => only_in_self.push(*id),
+                Some(other_transition) if other_transition != transition => modified.push(*id),
+                Some(_) => (),
+            }
+        }
+        let only_in_other =
+            other.transitions.keys().filter(|id| !self.transitions.contains_key(*id)).copied().collect();
+
+        ExecutionDiff {
+            only_in_self,
+            only_in_other,
+            modified,
+            global_state_roots_match: self.global_state_root == other.global_state_root,
+            inclusion_proofs_match: self.inclusion_proof == other.inclusion_proof,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_diff() -> Result<()> {
+        // Sample the transitions.
+        let rng = &mut TestRng::default();
+        let (transitions, root, proof) = Execution::<CurrentNetwork>::sample_execution(3, rng)?.into_parts();
+
+        // Ensure an execution has no differences with itself.
+        let a = Execution::<CurrentNetwork>::from(transitions.clone().into_iter(), root, proof.clone())?;
+        assert!(a.diff(&a).is_empty());
+
+        // Construct a modified copy of the last transition, which has the same ID but a different fee.
+        let transition = &transitions[2];
+        let modified = transition.sample_derived(
+            *transition.program_id(),
+            *transition.function_name(),
+            transition.inputs().to_vec(),
+            transition.outputs().to_vec(),
+            *transition.fee() + 1,
+        )?;
+        assert_eq!(transition.id(), modified.id());
+
+        // Remove the first transition, and modify the last transition.
+        let b = Execution::<CurrentNetwork>::from(
+            vec![transitions[1].clone(), modified].into_iter(),
+            <CurrentNetwork as Network>::StateRoot::from(Field::from_u64(1)),
+            proof,
+        )?;
+
+        // Ensure the differences are reported.
+        let diff = a.diff(&b);
+        assert!(!diff.is_empty());
+        assert_eq!(diff.only_in_self(), &[*transitions[0].id()]);
+        assert!(diff.only_in_other().is_empty());
+        assert_eq!(diff.modified(), &[*transitions[2].id()]);
+        assert!(!diff.global_state_roots_match());
+        assert!(diff.inclusion_proofs_match());
+
+        // Ensure the differences are reported in reverse.
+        let diff = b.diff(&a);
+        assert!(diff.only_in_self().is_empty());
+        assert_eq!(diff.only_in_other(), &[*transitions[0].id()]);
+        assert_eq!(diff.modified(), &[*transitions[2].id()]);
+        Ok(())
+    }
+}
@@ -14,16 +14,37 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod builder;
+pub use builder::*;
+
+mod diff;
+pub use diff::*;
+
+mod serial_number_set;
+pub use serial_number_set::*;
+
+mod verify_cache;
+pub use verify_cache::*;
+
 mod bytes;
+#[cfg(any(test, feature = "test-utils"))]
+mod sample;
 mod serialize;
 mod string;
 
 use crate::{snark::Proof, Transition};
-use console::{account::Field, network::prelude::*};
+use console::{
+    account::{Field, Scalar},
+    network::{prelude::*, IncrementalPoseidonTree},
+    program::{Identifier, ProgramID, TRANSACTIONS_DEPTH, TRANSACTION_DEPTH},
+};
+
+use indexmap::{IndexMap, IndexSet};
 
-use indexmap::IndexMap;
+/// The version of the execution byte serialization.
+const VERSION: u16 = 0u16;
 
-#[derive(Clone, Default, PartialEq, Eq)]
+#[derive(Clone, Default, Eq)]
 pub struct Execution<N: Network> {
     /// The transitions.
     transitions: IndexMap<N::TransitionID, Transition<N>>,
@@ -31,12 +52,25 @@ pub struct Execution<N: Network> {
     global_state_root: N::StateRoot,
     /// The inclusion proof.
     inclusion_proof: Option<Proof<N>>,
+    /// The (opaque) metadata, which is not consensus-relevant (see `Execution::metadata`).
+    metadata: Option<Vec<u8>>,
 }
 
 impl<N: Network> Execution<N> {
+    /// The maximum number of transitions allowed in an execution.
+    /// Note: One transition in the transaction is held back for the additional fee.
+    pub const MAX_TRANSITIONS: usize = usize::pow(2, TRANSACTION_DEPTH as u32) - 1;
+    /// The maximum size of an execution in bytes (128 KiB).
+    pub const MAX_EXECUTION_BYTES: usize = 128 * 1024;
+
     /// Initialize a new `Execution` instance.
     pub fn new() -> Self {
-        Self { transitions: Default::default(), global_state_root: Default::default(), inclusion_proof: None }
+        Self {
+            transitions: Default::default(),
+            global_state_root: Default::default(),
+            inclusion_proof: None,
+            metadata: None,
+        }
     }
 
     /// Initializes a new `Execution` instance with the given transitions.
@@ -46,8 +80,12 @@ impl<N: Network> Execution<N> {
         inclusion_proof: Option<Proof<N>>,
     ) -> Result<Self> {
         // Construct the execution.
-        let execution =
-            Self { transitions: transitions.map(|t| (*t.id(), t)).collect(), global_state_root, inclusion_proof };
+        let execution = Self {
+            transitions: transitions.map(|t| (*t.id(), t)).collect(),
+            global_state_root,
+            inclusion_proof,
+            metadata: None,
+        };
         // Ensure the transitions are not empty.
         ensure!(!execution.transitions.is_empty(), "Execution cannot initialize from empty list of transitions");
         // Return the new `Execution` instance.
@@ -63,6 +101,29 @@ impl<N: Network> Execution<N> {
     pub const fn inclusion_proof(&self) -> Option<&Proof<N>> {
         self.inclusion_proof.as_ref()
     }
+
+    /// Returns the metadata, such as a timestamp or origin, that is carried alongside the execution.
+    ///
+    /// Note: The metadata is not consensus-relevant, and never affects a consensus-relevant comparison.
+    /// It is excluded from `PartialEq`, `Execution::digest`, and the byte and serde serializations,
+    /// and as such, it is not preserved when the execution is serialized.
+    pub fn metadata(&self) -> Option<&[u8]> {
+        self.metadata.as_deref()
+    }
+
+    /// Sets the metadata of the execution, which is not consensus-relevant (see `Execution::metadata`).
+    pub fn set_metadata(&mut self, metadata: Option<Vec<u8>>) {
+        self.metadata = metadata;
+    }
+}
+
+impl<N: Network> PartialEq for Execution<N> {
+    /// Returns `true` if the executions are equal, ignoring their metadata.
+    fn eq(&self, other: &Self) -> bool {
+        self.transitions == other.transitions
+            && self.global_state_root == other.global_state_root
+            && self.inclusion_proof == other.inclusion_proof
+    }
 }
 
 impl<N: Network> Execution<N> {
@@ -111,6 +172,259 @@ impl<N: Network> Execution<N> {
     pub fn is_empty(&self) -> bool {
         self.transitions.is_empty()
     }
+
+    /// Ensures the execution is within `MAX_TRANSITIONS` and `MAX_EXECUTION_BYTES`,
+    /// returning a single error that lists every limit that is violated.
+    pub fn validate_against_limits(&self) -> Result<()> {
+        self.check_limits(Self::MAX_TRANSITIONS, Self::MAX_EXECUTION_BYTES)
+    }
+
+    /// Ensures the execution is within the given number of transitions and bytes,
+    /// returning a single error that lists every limit that is violated.
+    fn check_limits(&self, max_transitions: usize, max_bytes: usize) -> Result<()> {
+        let mut violations = Vec::with_capacity(2);
+        // Check the number of transitions.
+        if self.len() > max_transitions {
+            violations.push(format!("contains {} transitions (maximum is {max_transitions})", self.len()));
+        }
+        // Check the size in bytes.
+        let num_bytes = self.to_bytes_le()?.len();
+        if num_bytes > max_bytes {
+            violations.push(format!("is {num_bytes} bytes (maximum is {max_bytes})"));
+        }
+        match violations.is_empty() {
+            true => Ok(()),
+            false => bail!("Execution exceeds {} limit(s): it {}", violations.len(), violations.join(", and ")),
+        }
+    }
+
+    /// Ensures each record is consumed at most once, and produced at most once, across the transitions.
+    ///
+    /// Note: This does **not** verify that the transitions are linked, i.e. that a record consumed by a transition
+    /// was produced earlier in the execution. A record input only reveals its serial number, which cannot be
+    /// linked to the commitment of the record it consumes without the secrets of the record owner. As such, an input
+    /// may consume a record produced earlier in the execution, or a record from outside the execution.
+    ///
+    /// The error names the first transition that repeats a serial number or a commitment.
+    pub fn verify_unique_records(&self) -> Result<()> {
+        let mut serial_numbers = IndexSet::new();
+        let mut commitments = IndexSet::new();
+        for (id, transition) in &self.transitions {
+            // Ensure each record is consumed at most once.
+            for serial_number in transition.serial_numbers() {
+                ensure!(
+                    serial_numbers.insert(serial_number),
+                    "Transition '{id}' consumes a record (serial number '{serial_number}') that was already consumed"
+                );
+            }
+            // Ensure each record is produced at most once.
+            for commitment in transition.commitments() {
+                ensure!(
+                    commitments.insert(commitment),
+                    "Transition '{id}' produces a record (commitment '{commitment}') that was already produced"
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Ensures every transition in the execution belongs to a program in the given allowlist,
+    /// e.g. to enforce a program-level policy for a permissioned deployment before executing.
+    ///
+    /// The error names the first transition that calls a program outside of the allowlist.
+    pub fn verify_allowed_programs(&self, allowed: &[ProgramID<N>]) -> Result<()> {
+        for (id, transition) in &self.transitions {
+            ensure!(
+                allowed.contains(transition.program_id()),
+                "Transition '{id}' calls '{}', which is not an allowed program",
+                transition.program_id()
+            );
+        }
+        Ok(())
+    }
+
+    /// Returns a deterministic estimate of the cost of the execution, computed as the weighted sum
+    /// `N::COST_PER_TRANSITION * num_transitions + N::COST_PER_INPUT * num_inputs + N::COST_PER_OUTPUT * num_outputs`,
+    /// where the inputs and outputs are counted across all transitions. The sum saturates at `u64::MAX`.
+    pub fn estimated_cost(&self) -> u64 {
+        self.transitions.values().fold(0u64, |cost, transition| {
+            cost.saturating_add(N::COST_PER_TRANSITION)
+                .saturating_add(N::COST_PER_INPUT.saturating_mul(transition.inputs().len() as u64))
+                .saturating_add(N::COST_PER_OUTPUT.saturating_mul(transition.outputs().len() as u64))
+        })
+    }
+
+    /// Returns the digest of the execution, computed as the Poseidon hash of its byte representation.
+    /// As the byte representation covers every transition, the global state root, and the inclusion proof,
+    /// two executions share a digest if and only if they are identical (up to a hash collision).
+    pub fn digest(&self) -> Result<Field<N>> {
+        N::hash_bytes_psd8(&self.to_bytes_le()?)
+    }
+
+    /// Returns a compact integrity token for the execution, computed as the little-endian bytes of
+    /// `Hash(digest || num_transitions)`, where `Hash` is the Poseidon hash with an input rate of 2.
+    /// Peers may compare tokens before exchanging the full executions.
+    pub fn integrity_token(&self) -> Result<[u8; 32]> {
+        // Compute the hash of the digest and the number of transitions.
+        let hash = N::hash_psd2(&[self.digest()?, Field::from_u64(self.len() as u64)])?;
+        // Convert the hash into bytes.
+        match hash.to_bytes_le()?.try_into() {
+            Ok(token) => Ok(token),
+            Err(bytes) => bail!("Expected a 32-byte integrity token, found {} bytes", bytes.len()),
+        }
+    }
+
+    /// Returns a BHP commitment to the execution under the given randomizer, computed as
+    /// `CommitBHP256(digest, randomizer)`, where `digest` is the Poseidon hash of the byte representation
+    /// of the execution (see `Execution::digest`), while the randomizer blinds it.
+    ///
+    /// Note: The commitment is to the digest, rather than to the bytes themselves, such that it has a fixed
+    /// input size. As such, the commitment binds the entire execution only as long as the BHP commitment
+    /// is binding *and* the Poseidon hash is collision-resistant, as two executions with the same digest
+    /// share a commitment under the same randomizer.
+    pub fn commit(&self, randomizer: &Scalar<N>) -> Result<Field<N>> {
+        N::commit_bhp256(&self.digest()?.to_bits_le(), randomizer)
+    }
+
+    /// Returns `true` if the given commitment is the commitment to the execution under the given randomizer.
+    pub fn verify_commitment(&self, commitment: &Field<N>, randomizer: &Scalar<N>) -> Result<bool> {
+        N::verify_commit_bhp256(commitment, &self.digest()?.to_bits_le(), randomizer)
+    }
+
+    /// Returns a deterministic identifier for the execution, computed as `Hash(edition || transition_ids)`,
+    /// where `Hash` is the Poseidon hash with an input rate of 8, and the transition IDs are taken in order.
+    /// The identifier shares its type with the transition IDs.
+    pub fn compute_execution_id(&self) -> Result<N::TransitionID> {
+        // Construct the preimage from the edition and the ordered transition IDs.
+        let preimage = std::iter::once(Field::from_u16(N::EDITION))
+            .chain(self.transitions.keys().map(|transition_id| **transition_id))
+            .collect::<Vec<_>>();
+        // Compute the execution ID.
+        Ok(N::hash_psd8(&preimage)?.into())
+    }
+
+    /// Returns the root of the Poseidon Merkle tree of depth `TRANSACTIONS_DEPTH` over the digests of the given
+    /// executions, in order. The root matches that of `N::merkle_tree_psd` over the leaves `[digest]`.
+    ///
+    /// The executions are streamed into an `IncrementalPoseidonTree`, which retains only the frontier of the tree,
+    /// and bounds the memory to `O(TRANSACTIONS_DEPTH)` hashes.
+    pub fn transactions_root<I: Iterator<Item = Execution<N>>>(executions: I) -> Result<Field<N>> {
+        let mut tree = IncrementalPoseidonTree::<N, TRANSACTIONS_DEPTH>::new()?;
+        for execution in executions {
+            tree.append_field(execution.digest()?)?;
+        }
+        Ok(*tree.root())
+    }
+
+    /// Returns the size in bytes of each transition, in the order the transitions appear in the execution.
+    ///
+    /// The sizes sum to the size of the execution, less the bytes for the version, the number of transitions,
+    /// the global state root, and the inclusion proof.
+    pub fn size_breakdown(&self) -> Result<Vec<(N::TransitionID, u64)>> {
+        self.transitions.iter().map(|(id, transition)| Ok((*id, transition.to_bytes_le()?.len() as u64))).collect()
+    }
+
+    /// Returns the size in bytes of the proof of each transition, in the order the transitions appear in the execution.
+    ///
+    /// As the proofs often dominate the size of the execution, this complements `Execution::size_breakdown`.
+    pub fn proof_size_breakdown(&self) -> Result<Vec<(N::TransitionID, u64)>> {
+        self.transitions
+            .iter()
+            .map(|(id, transition)| Ok((*id, transition.proof().to_bytes_le()?.len() as u64)))
+            .collect()
+    }
+
+    /// Returns `true` if the executions are equal, ignoring the order of their transitions.
+    /// This method is `O(n)`, as each transition is looked up by its ID in the other execution.
+    pub fn eq_unordered(&self, other: &Execution<N>) -> bool {
+        self.global_state_root == other.global_state_root
+            && self.inclusion_proof == other.inclusion_proof
+            && self.transitions.len() == other.transitions.len()
+            && self.transitions.iter().all(|(id, transition)| other.transitions.get(id) == Some(transition))
+    }
+
+    /// Reorders the transitions into a canonical order, independent of their insertion order.
+    ///
+    /// The transitions are stably sorted by the key `(program ID, function name, index)`, where
+    /// the program ID is ordered by its `Ord` implementation (network, then name), the function name
+    /// is ordered by its string representation, and ties preserve the original index of the transition.
+    pub fn sort_canonical(&mut self) {
+        self.transitions.sort_by(|_, a, _, b| {
+            a.program_id()
+                .cmp(b.program_id())
+                .then_with(|| a.function_name().to_string().cmp(&b.function_name().to_string()))
+        });
+    }
+
+    /// Retains only the transitions for which the given predicate returns `true`, preserving their order.
+    pub fn retain<F: FnMut(&Transition<N>) -> bool>(&mut self, mut f: F) {
+        self.transitions.retain(|_, transition| f(transition));
+    }
+
+    /// Retains only the transitions of the given program, preserving their order.
+    ///
+    /// Note: This method leaves the execution empty if no transition belongs to the given program.
+    /// As an execution without transitions cannot be deserialized, check `is_empty` before writing it.
+    pub fn retain_by_program(&mut self, program_id: &ProgramID<N>) {
+        self.retain(|transition| transition.program_id() == program_id);
+    }
+}
+
+impl<N: Network> Execution<N> {
+    /// Returns the concatenation of `self` and `other`, with the transitions of `other` appended after those of `self`.
+    ///
+    /// This method errors if the executions have different global state roots or inclusion proofs,
+    /// or if they contain a transition with the same ID.
+    pub fn try_add(mut self, other: Execution<N>) -> Result<Self> {
+        // Ensure the global state roots match.
+        ensure!(
+            self.global_state_root == other.global_state_root,
+            "Cannot concatenate executions with different global state roots ('{}' and '{}')",
+            self.global_state_root,
+            other.global_state_root
+        );
+        // Ensure the inclusion proofs match.
+        ensure!(
+            self.inclusion_proof == other.inclusion_proof,
+            "Cannot concatenate executions with different inclusion proofs"
+        );
+        // Append the transitions, ensuring each transition ID is unique.
+        for (transition_id, transition) in other.transitions {
+            ensure!(
+                !self.transitions.contains_key(&transition_id),
+                "Cannot concatenate executions that both contain the transition '{transition_id}'"
+            );
+            self.transitions.insert(transition_id, transition);
+        }
+        Ok(self)
+    }
+}
+
+impl<N: Network> Add for Execution<N> {
+    type Output = Self;
+
+    /// Returns the concatenation of `self` and `other`.
+    ///
+    /// # Panics
+    /// This method panics if the executions have different global state roots or inclusion proofs,
+    /// or if they contain a transition with the same ID. Use `Execution::try_add` to handle these cases.
+    fn add(self, other: Self) -> Self::Output {
+        match self.try_add(other) {
+            Ok(execution) => execution,
+            Err(error) => panic!("{error}"),
+        }
+    }
+}
+
+impl<N: Network> AddAssign for Execution<N> {
+    /// Appends the transitions of `other` to `self`.
+    ///
+    /// # Panics
+    /// This method panics if the executions have different global state roots or inclusion proofs,
+    /// or if they contain a transition with the same ID. Use `Execution::try_add` to handle these cases.
+    fn add_assign(&mut self, other: Self) {
+        *self = core::mem::take(self) + other;
+    }
 }
 
 impl<N: Network> Execution<N> {
@@ -119,13 +433,558 @@ impl<N: Network> Execution<N> {
         self.transitions.into_values()
     }
 
+    /// Returns the transitions, global state root, and inclusion proof, and consumes `self`.
+    /// The transitions are returned in order, such that `Execution::from` reconstructs the execution.
+    pub fn into_parts(self) -> (Vec<Transition<N>>, N::StateRoot, Option<Proof<N>>) {
+        (self.transitions.into_values().collect(), self.global_state_root, self.inclusion_proof)
+    }
+
     /// Returns an iterator over the underlying transitions.
     pub fn transitions(&self) -> impl '_ + ExactSizeIterator + DoubleEndedIterator<Item = &Transition<N>> {
         self.transitions.values()
     }
 
+    /// Returns a mutable iterator over the underlying transitions, for editing the transitions in place.
+    ///
+    /// Note: The transitions are keyed by their transition ID, which is derived from their inputs and outputs.
+    /// As such, a transition must not be edited in a way that changes its transition ID, as the execution
+    /// would otherwise no longer find the transition by its ID. To change the identity of a transition,
+    /// reconstruct the execution with `Execution::into_parts` and `Execution::from` instead.
+    pub fn transitions_mut(&mut self) -> impl '_ + ExactSizeIterator + DoubleEndedIterator<Item = &mut Transition<N>> {
+        self.transitions.values_mut()
+    }
+
+    /// Returns the fee transition, if the execution has one.
+    ///
+    /// The fee transition is the only transition in the execution that calls `credits.aleo/fee`.
+    /// If no transition, or more than one transition, calls `credits.aleo/fee`, there is no fee transition.
+    /// As such, the fee transition does not depend on the order of the transitions (see `Execution::sort_canonical`).
+    pub fn fee_transition(&self) -> Option<&Transition<N>> {
+        let fee_program_id = ProgramID::<N>::from_str("credits.aleo").ok()?;
+        let fee_function = Identifier::<N>::from_str("fee").ok()?;
+
+        let mut fee_transitions = self.transitions.values().filter(|transition| {
+            *transition.program_id() == fee_program_id && *transition.function_name() == fee_function
+        });
+        match (fee_transitions.next(), fee_transitions.next()) {
+            (Some(fee_transition), None) => Some(fee_transition),
+            _ => None,
+        }
+    }
+
+    /// Returns an iterator over the transitions, in order, excluding the fee transition.
+    /// See `Execution::fee_transition` for the rule that designates the fee transition.
+    pub fn non_fee_transitions(&self) -> impl '_ + Iterator<Item = &Transition<N>> {
+        let fee_transition_id = self.fee_transition().map(Transition::id);
+        self.transitions.values().filter(move |transition| Some(transition.id()) != fee_transition_id)
+    }
+
     /// Returns an iterator over the commitments.
     pub fn commitments(&self) -> impl '_ + Iterator<Item = &Field<N>> {
         self.transitions.values().flat_map(Transition::commitments)
     }
+
+    /// Returns an iterator over the transition proofs, in the order the transitions appear in the execution,
+    /// e.g. as the input to a batch verifier or an aggregator.
+    ///
+    /// Every transition carries a proof, so this yields exactly one proof per transition.
+    /// Note: The inclusion proof is not a transition proof, and is not yielded (see `Execution::inclusion_proof`).
+    pub fn proofs(&self) -> impl '_ + ExactSizeIterator + DoubleEndedIterator<Item = &Proof<N>> {
+        self.transitions.values().map(Transition::proof)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_into_parts() -> Result<()> {
+        // Sample the execution.
+        let expected = crate::process::test_helpers::sample_execution();
+
+        // Destructure the execution, and reconstruct it from its parts.
+        let (transitions, global_state_root, inclusion_proof) = expected.clone().into_parts();
+        assert_eq!(expected.len(), transitions.len());
+        let candidate = Execution::from(transitions.into_iter(), global_state_root, inclusion_proof)?;
+        assert_eq!(expected, candidate);
+        Ok(())
+    }
+
+    #[test]
+    fn test_sort_canonical() -> Result<()> {
+        // Sample the transitions.
+        let transitions = crate::process::test_helpers::sample_transitions(&[
+            ("token.aleo", "transfer"),
+            ("credits.aleo", "mint"),
+            ("token.aleo", "mint"),
+            ("credits.aleo", "transfer"),
+        ]);
+        let (root, proof) = (Default::default(), None);
+
+        // Construct two executions with different orderings of the same transitions.
+        let mut a = Execution::<CurrentNetwork>::from(transitions.clone().into_iter(), root, proof.clone())?;
+        let mut b = Execution::<CurrentNetwork>::from(transitions.clone().into_iter().rev(), root, proof)?;
+        assert_ne!(a, b);
+
+        // Ensure the executions are equal after sorting.
+        a.sort_canonical();
+        b.sort_canonical();
+        assert_eq!(a, b);
+
+        // Ensure the canonical order is by program ID, then function name.
+        let expected = [&transitions[1], &transitions[3], &transitions[2], &transitions[0]];
+        let expected_ids = expected.iter().map(|transition| transition.id());
+        assert!(a.transitions().map(Transition::id).eq(expected_ids));
+        Ok(())
+    }
+
+    #[test]
+    fn test_retain_by_program() -> Result<()> {
+        // Sample the transitions.
+        let transitions = crate::process::test_helpers::sample_transitions(&[
+            ("token.aleo", "transfer"),
+            ("credits.aleo", "mint"),
+            ("token.aleo", "mint"),
+            ("credits.aleo", "transfer"),
+            ("token.aleo", "burn"),
+        ]);
+        let execution = Execution::<CurrentNetwork>::from(transitions.clone().into_iter(), Default::default(), None)?;
+
+        // Ensure only the transitions of the given program remain, in order.
+        let mut candidate = execution.clone();
+        candidate.retain_by_program(&ProgramID::from_str("token.aleo")?);
+        let expected_ids = [&transitions[0], &transitions[2], &transitions[4]].map(Transition::id);
+        assert!(candidate.transitions().map(Transition::id).eq(expected_ids));
+
+        // Ensure retaining a program without transitions leaves the execution empty.
+        let mut candidate = execution;
+        candidate.retain_by_program(&ProgramID::from_str("other.aleo")?);
+        assert!(candidate.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_non_fee_transitions() -> Result<()> {
+        // Sample the transitions.
+        let transitions = crate::process::test_helpers::sample_transitions(&[
+            ("token.aleo", "transfer"),
+            ("credits.aleo", "fee"),
+            ("token.aleo", "mint"),
+            ("credits.aleo", "fee"),
+        ]);
+
+        // Ensure an execution without a fee transition yields every transition.
+        let non_fee = [transitions[0].clone(), transitions[2].clone()];
+        let execution = Execution::<CurrentNetwork>::from(non_fee.iter().cloned(), Default::default(), None)?;
+        assert!(execution.fee_transition().is_none());
+        assert!(execution.non_fee_transitions().map(Transition::id).eq(non_fee.iter().map(Transition::id)));
+
+        // Ensure an execution with a fee transition yields every transition except the fee transition, in order.
+        let mut execution =
+            Execution::<CurrentNetwork>::from(transitions[..3].iter().cloned(), Default::default(), None)?;
+        assert_eq!(Some(transitions[1].id()), execution.fee_transition().map(Transition::id));
+        assert!(execution.non_fee_transitions().map(Transition::id).eq(non_fee.iter().map(Transition::id)));
+
+        // Ensure the fee transition does not depend on the order of the transitions.
+        execution.sort_canonical();
+        assert_eq!(Some(transitions[1].id()), execution.fee_transition().map(Transition::id));
+
+        // Ensure an execution with more than one call to `credits.aleo/fee` has no fee transition.
+        let execution = Execution::<CurrentNetwork>::from(transitions.iter().cloned(), Default::default(), None)?;
+        assert!(execution.fee_transition().is_none());
+        assert!(execution.non_fee_transitions().map(Transition::id).eq(transitions.iter().map(Transition::id)));
+
+        // Ensure an empty execution yields no transitions.
+        let execution = Execution::<CurrentNetwork>::new();
+        assert!(execution.fee_transition().is_none());
+        assert_eq!(0, execution.non_fee_transitions().count());
+        Ok(())
+    }
+
+    #[test]
+    fn test_proofs() -> Result<()> {
+        // Sample the execution.
+        let execution = crate::process::test_helpers::sample_execution();
+        assert!(!execution.is_empty());
+
+        // Ensure there is one proof per transition, in order.
+        assert_eq!(execution.len(), execution.proofs().len());
+        assert!(execution
+            .proofs()
+            .zip_eq(execution.transitions())
+            .all(|(proof, transition)| proof == transition.proof()));
+
+        // Ensure an empty execution yields no proofs.
+        assert_eq!(0, Execution::<CurrentNetwork>::new().proofs().count());
+        Ok(())
+    }
+
+    #[test]
+    fn test_eq_unordered() -> Result<()> {
+        // Sample the transitions.
+        let rng = &mut TestRng::default();
+        let (transitions, root, proof) = Execution::<CurrentNetwork>::sample_execution(3, rng)?.into_parts();
+
+        // Construct two executions with different orderings of the same transitions.
+        let a = Execution::<CurrentNetwork>::from(transitions.clone().into_iter(), root, proof.clone())?;
+        let b = Execution::<CurrentNetwork>::from(transitions.clone().into_iter().rev(), root, proof.clone())?;
+        assert_ne!(a, b);
+        assert!(a.eq_unordered(&b));
+        assert!(b.eq_unordered(&a));
+
+        // Ensure an execution with a missing transition is not equal.
+        let c = Execution::<CurrentNetwork>::from(transitions.into_iter().skip(1), root, proof)?;
+        assert!(!a.eq_unordered(&c));
+        assert!(!c.eq_unordered(&a));
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_against_limits() -> Result<()> {
+        // Ensure a sampled execution is within the limits.
+        let execution = crate::process::test_helpers::sample_execution();
+        execution.validate_against_limits()?;
+
+        // Sample more transitions than are allowed in an execution.
+        let locators = vec![("token.aleo", "transfer"); Execution::<CurrentNetwork>::MAX_TRANSITIONS + 1];
+        let transitions = crate::process::test_helpers::sample_transitions(&locators);
+        let execution = Execution::<CurrentNetwork>::from(transitions.into_iter(), Default::default(), None)?;
+        assert_eq!(Execution::<CurrentNetwork>::MAX_TRANSITIONS + 1, execution.len());
+
+        // Ensure exceeding the number of transitions is reported.
+        let error = execution.validate_against_limits().unwrap_err().to_string();
+        assert!(error.contains(&format!("contains {} transitions", execution.len())), "{error}");
+
+        // Ensure exceeding both the number of transitions and the size is reported in a single error.
+        let num_bytes = execution.to_bytes_le()?.len();
+        let error = execution.check_limits(Execution::<CurrentNetwork>::MAX_TRANSITIONS, num_bytes - 1).unwrap_err();
+        let error = error.to_string();
+        assert!(error.starts_with("Execution exceeds 2 limit(s)"), "{error}");
+        assert!(error.contains("transitions"), "{error}");
+        assert!(error.contains(&format!("is {num_bytes} bytes")), "{error}");
+        Ok(())
+    }
+
+    #[test]
+    fn test_integrity_token() -> Result<()> {
+        // Sample the execution, and a replacement transition, which has a distinct input.
+        let (mut transitions, root, proof) =
+            Execution::<CurrentNetwork>::sample_execution(4, &mut TestRng::default())?.into_parts();
+        let other_transition = transitions.pop().unwrap();
+        let execution = Execution::<CurrentNetwork>::from(transitions.clone().into_iter(), root, proof.clone())?;
+
+        // Ensure the token is stable, including across a byte serialization round trip.
+        let token = execution.integrity_token()?;
+        assert_eq!(token, execution.integrity_token()?);
+        assert_eq!(token, Execution::<CurrentNetwork>::read_le(&execution.to_bytes_le()?[..])?.integrity_token()?);
+
+        // Ensure changing a transition changes the token.
+        let mut candidate = transitions.clone();
+        candidate[1] = other_transition;
+        let candidate = Execution::<CurrentNetwork>::from(candidate.into_iter(), root, proof.clone())?;
+        assert_eq!(execution.len(), candidate.len());
+        assert_ne!(token, candidate.integrity_token()?);
+
+        // Ensure changing the number of transitions changes the token.
+        let candidate = Execution::<CurrentNetwork>::from(transitions.into_iter().take(2), root, proof)?;
+        assert_ne!(token, candidate.integrity_token()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_transactions_root() -> Result<()> {
+        // Sample the executions.
+        let rng = &mut TestRng::default();
+        let executions =
+            (0..5).map(|_| Execution::<CurrentNetwork>::sample_execution(1, rng)).collect::<Result<Vec<_>>>()?;
+
+        for num_executions in 0..=executions.len() {
+            let executions = &executions[..num_executions];
+
+            // Ensure the streamed root matches the root of the full Merkle tree.
+            let leaves =
+                executions.iter().map(|execution| Ok(vec![execution.digest()?])).collect::<Result<Vec<_>>>()?;
+            let expected = *CurrentNetwork::merkle_tree_psd::<TRANSACTIONS_DEPTH>(&leaves)?.root();
+            let candidate = Execution::transactions_root(executions.iter().cloned())?;
+            assert_eq!(expected, candidate);
+
+            // Ensure the root depends on the order of the executions.
+            if num_executions > 1 {
+                assert_ne!(expected, Execution::transactions_root(executions.iter().rev().cloned())?);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_compute_execution_id() -> Result<()> {
+        // Sample the executions.
+        let execution = Execution::<CurrentNetwork>::sample_execution(3, &mut TestRng::default())?;
+        let (transitions, root, proof) = execution.clone().into_parts();
+        let reversed = Execution::<CurrentNetwork>::from(transitions.clone().into_iter().rev(), root, proof.clone())?;
+        let other = Execution::<CurrentNetwork>::from(transitions.into_iter().take(2), root, proof)?;
+
+        // Ensure the execution ID is stable.
+        let expected = execution.compute_execution_id()?;
+        assert_eq!(expected, execution.compute_execution_id()?);
+        assert_eq!(expected, execution.clone().compute_execution_id()?);
+
+        // Ensure the execution ID depends on the order of the transitions.
+        assert_ne!(expected, reversed.compute_execution_id()?);
+        // Ensure the execution ID depends on the set of transitions.
+        assert_ne!(expected, other.compute_execution_id()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_commit() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample the executions.
+        let execution = Execution::<CurrentNetwork>::sample_execution(2, &mut rng)?;
+        let (transitions, root, proof) = execution.clone().into_parts();
+        let other = Execution::<CurrentNetwork>::from(transitions.into_iter().take(1), root, proof)?;
+
+        // Ensure the commitment is deterministic, and verifies, for a fixed randomizer.
+        let randomizer = Scalar::rand(&mut rng);
+        let commitment = execution.commit(&randomizer)?;
+        assert_eq!(commitment, execution.commit(&randomizer)?);
+        assert!(execution.verify_commitment(&commitment, &randomizer)?);
+
+        // Ensure a different randomizer yields a different commitment, which does not verify.
+        let other_randomizer = Scalar::rand(&mut rng);
+        assert_ne!(commitment, execution.commit(&other_randomizer)?);
+        assert!(!execution.verify_commitment(&commitment, &other_randomizer)?);
+
+        // Ensure a different execution yields a different commitment, which does not verify.
+        assert_ne!(commitment, other.commit(&randomizer)?);
+        assert!(!other.verify_commitment(&commitment, &randomizer)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_metadata() -> Result<()> {
+        // Sample an execution, and a copy of it with metadata.
+        let execution = crate::process::test_helpers::sample_execution();
+        assert!(execution.metadata().is_none());
+        let mut candidate = execution.clone();
+        candidate.set_metadata(Some(b"origin: test".to_vec()));
+        assert_eq!(Some(&b"origin: test"[..]), candidate.metadata());
+
+        // Ensure the metadata does not affect equality, the digest, or the byte serialization.
+        assert_eq!(execution, candidate);
+        assert_eq!(execution.digest()?, candidate.digest()?);
+        assert_eq!(execution.to_bytes_le()?, candidate.to_bytes_le()?);
+
+        // Ensure the metadata is not preserved by the byte serialization.
+        let recovered = Execution::<CurrentNetwork>::read_le(&candidate.to_bytes_le()?[..])?;
+        assert!(recovered.metadata().is_none());
+
+        // Ensure the metadata can be cleared.
+        candidate.set_metadata(None);
+        assert!(candidate.metadata().is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_size_breakdown() -> Result<()> {
+        // Sample the execution.
+        let execution = Execution::<CurrentNetwork>::sample_execution(3, &mut TestRng::default())?;
+        let transitions = execution.transitions().cloned().collect::<Vec<_>>();
+
+        // Ensure each entry matches the size of its transition, in order.
+        let breakdown = execution.size_breakdown()?;
+        assert_eq!(transitions.len(), breakdown.len());
+        for ((id, num_bytes), transition) in breakdown.iter().zip_eq(&transitions) {
+            assert_eq!(transition.id(), id);
+            assert_eq!(transition.to_bytes_le()?.len() as u64, *num_bytes);
+        }
+
+        // Ensure the sizes sum to the size of the execution, less the overhead.
+        // The overhead is the version, the number of transitions, the global state root, and the proof variant.
+        let overhead = 2 + 2 + execution.global_state_root().to_bytes_le()?.len() + 1;
+        let total = breakdown.iter().map(|(_, num_bytes)| num_bytes).sum::<u64>();
+        assert_eq!(execution.to_bytes_le()?.len(), total as usize + overhead);
+        Ok(())
+    }
+
+    #[test]
+    fn test_proof_size_breakdown() -> Result<()> {
+        // Sample the execution.
+        let execution = Execution::<CurrentNetwork>::sample_execution(3, &mut TestRng::default())?;
+        let transitions = execution.transitions().cloned().collect::<Vec<_>>();
+
+        // Ensure each entry matches the size of the proof of its transition, in order.
+        let breakdown = execution.proof_size_breakdown()?;
+        assert_eq!(transitions.len(), breakdown.len());
+        for ((id, num_bytes), transition) in breakdown.iter().zip_eq(&transitions) {
+            assert_eq!(transition.id(), id);
+            assert_eq!(transition.proof().to_bytes_le()?.len() as u64, *num_bytes);
+        }
+
+        // Ensure the sizes sum to the size of all of the proofs.
+        let mut proof_bytes = Vec::new();
+        for proof in execution.proofs() {
+            proof.write_le(&mut proof_bytes)?;
+        }
+        let total = breakdown.iter().map(|(_, num_bytes)| num_bytes).sum::<u64>();
+        assert_eq!(proof_bytes.len(), total as usize);
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_unique_records() -> Result<()> {
+        // Constructs a transition consuming and producing the given serial numbers and commitments.
+        let sample = |serial_numbers: &[u64], commitments: &[u64]| {
+            let inputs = serial_numbers
+                .iter()
+                .map(|sn| crate::Input::Record(Field::from_u64(*sn), Field::from_u64(*sn + 1000)))
+                .collect();
+            let outputs = commitments
+                .iter()
+                .map(|cm| crate::Output::Record(Field::from_u64(*cm), Field::from_u64(*cm + 1000), None))
+                .collect();
+            crate::process::test_helpers::sample_transition_with(inputs, outputs)
+        };
+        let (root, proof) = (Default::default(), None);
+
+        // Ensure transitions with distinct serial numbers and distinct commitments are accepted.
+        // Note: The serial numbers are unrelated to the commitments, as they cannot be linked.
+        let transitions = vec![sample(&[1, 2], &[11, 12]), sample(&[3], &[13]), sample(&[4, 5], &[14])];
+        let execution = Execution::<CurrentNetwork>::from(transitions.into_iter(), root, proof.clone())?;
+        assert!(execution.verify_unique_records().is_ok());
+
+        // Ensure transitions that repeat a serial number are rejected.
+        let transitions = vec![sample(&[1, 2], &[11, 12]), sample(&[3], &[13]), sample(&[3, 4], &[14])];
+        let execution = Execution::<CurrentNetwork>::from(transitions.into_iter(), root, proof.clone())?;
+        assert!(execution.verify_unique_records().is_err());
+
+        // Ensure transitions that repeat a commitment are rejected.
+        let transitions = vec![sample(&[1, 2], &[11, 12]), sample(&[3], &[12])];
+        let execution = Execution::<CurrentNetwork>::from(transitions.into_iter(), root, proof)?;
+        assert!(execution.verify_unique_records().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_allowed_programs() -> Result<()> {
+        // Sample the transitions.
+        let transitions = crate::process::test_helpers::sample_transitions(&[
+            ("token.aleo", "transfer"),
+            ("credits.aleo", "mint"),
+            ("token.aleo", "mint"),
+        ]);
+        let execution = Execution::<CurrentNetwork>::from(transitions.iter().cloned(), Default::default(), None)?;
+        let (token, credits) = (ProgramID::from_str("token.aleo")?, ProgramID::from_str("credits.aleo")?);
+
+        // Ensure an execution of only allowed programs succeeds.
+        execution.verify_allowed_programs(&[token, credits])?;
+        execution.verify_allowed_programs(&[credits, token, ProgramID::from_str("other.aleo")?])?;
+
+        // Ensure an execution with a disallowed program fails, naming the program.
+        let error = execution.verify_allowed_programs(&[token]).unwrap_err();
+        assert!(error.to_string().contains("'credits.aleo'"));
+        assert!(error.to_string().contains(&transitions[1].id().to_string()));
+        assert!(execution.verify_allowed_programs(&[]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_estimated_cost() -> Result<()> {
+        // Constructs a transition with the given number of inputs and outputs.
+        let sample = |index: u64, num_inputs: u64, num_outputs: u64| {
+            let inputs = (0..num_inputs).map(|i| crate::Input::Public(Field::from_u64(index * 100 + i), None));
+            let outputs = (0..num_outputs).map(|i| crate::Output::Public(Field::from_u64(index * 100 + i), None));
+            crate::process::test_helpers::sample_transition_with(inputs.collect(), outputs.collect())
+        };
+        // Returns the expected cost for the given list of `(num_inputs, num_outputs)`.
+        let expected = |counts: &[(u64, u64)]| {
+            counts.iter().fold(0, |cost, (num_inputs, num_outputs)| {
+                cost + CurrentNetwork::COST_PER_TRANSITION
+                    + CurrentNetwork::COST_PER_INPUT * num_inputs
+                    + CurrentNetwork::COST_PER_OUTPUT * num_outputs
+            })
+        };
+        let (root, proof) = (Default::default(), None);
+
+        // Ensure the estimate scales with the number of transitions, inputs, and outputs.
+        let mut previous = 0;
+        for counts in [vec![(1, 1)], vec![(2, 1)], vec![(2, 3)], vec![(2, 3), (1, 0)], vec![(4, 4), (2, 3), (1, 1)]] {
+            let transitions = counts
+                .iter()
+                .enumerate()
+                .map(|(index, (num_inputs, num_outputs))| sample(index as u64, *num_inputs, *num_outputs));
+            let execution = Execution::<CurrentNetwork>::from(transitions.into_iter(), root, proof.clone())?;
+
+            let cost = execution.estimated_cost();
+            assert_eq!(expected(&counts), cost);
+            assert!(cost > previous);
+            previous = cost;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_transitions_mut() -> Result<()> {
+        // Sample the execution.
+        let mut execution = crate::process::test_helpers::sample_execution();
+        let expected_ids = execution.transitions().map(|transition| *transition.id()).collect::<Vec<_>>();
+        let expected_fees = execution.transitions().map(|transition| *transition.fee() + 1).collect::<Vec<_>>();
+
+        // Replace each transition with a copy that differs only in its fee, which preserves its ID.
+        for transition in execution.transitions_mut() {
+            *transition = transition.sample_derived(
+                *transition.program_id(),
+                *transition.function_name(),
+                transition.inputs().to_vec(),
+                transition.outputs().to_vec(),
+                *transition.fee() + 1,
+            )?;
+        }
+
+        // Ensure the edits are applied, and each transition is still keyed by its ID, in the same order.
+        for ((transition, expected_id), expected_fee) in
+            execution.transitions().zip_eq(&expected_ids).zip_eq(&expected_fees)
+        {
+            assert_eq!(expected_id, transition.id());
+            assert_eq!(expected_fee, transition.fee());
+            assert_eq!(Some(transition), execution.find_transition(expected_id));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_add() -> Result<()> {
+        // Sample the transitions.
+        let rng = &mut TestRng::default();
+        let (transitions, root, proof) = Execution::<CurrentNetwork>::sample_execution(3, rng)?.into_parts();
+
+        // Construct the expected execution, and its two halves.
+        let expected = Execution::<CurrentNetwork>::from(transitions.clone().into_iter(), root, proof.clone())?;
+        let a = Execution::<CurrentNetwork>::from(transitions[..1].to_vec().into_iter(), root, proof.clone())?;
+        let b = Execution::<CurrentNetwork>::from(transitions[1..].to_vec().into_iter(), root, proof)?;
+
+        // Ensure the concatenation preserves the order of the transitions.
+        assert_eq!(expected, a.clone().try_add(b.clone())?);
+        assert_eq!(expected, a.clone() + b.clone());
+        let mut candidate = a.clone();
+        candidate += b.clone();
+        assert_eq!(expected, candidate);
+
+        // Ensure concatenating executions with a shared transition fails.
+        assert!(a.clone().try_add(expected.clone()).is_err());
+        let (first, second) = (a.clone(), expected.clone());
+        let result = std::panic::catch_unwind(move || first + second);
+        assert!(result.is_err());
+        let result = std::panic::catch_unwind(move || {
+            let mut candidate = a;
+            candidate += expected;
+        });
+        assert!(result.is_err());
+        Ok(())
+    }
 }
@@ -0,0 +1,123 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::{Block, Input, Output};
+use console::{
+    program::{Literal, Plaintext},
+    types::U16,
+};
+
+impl<N: Network> Execution<N> {
+    /// Samples an execution with the given number of transitions, for testing the `Execution` APIs.
+    ///
+    /// Each transition is derived from the first transition in the genesis block, and is given a random
+    /// public input and output (with their IDs), in place of its records. As such, the execution is
+    /// structurally valid, and passes `Execution::validate_against_limits`, `Execution::verify_unique_records`,
+    /// and the structural checks of `Process::verify_execution` on each transition.
+    ///
+    /// Note: The transitions are not cryptographically valid, and the execution does not verify.
+    pub fn sample_execution<R: Rng + CryptoRng>(num_transitions: usize, rng: &mut R) -> Result<Self> {
+        // Ensure the number of transitions is within bounds.
+        ensure!(num_transitions > 0, "Cannot sample an execution without transitions");
+        ensure!(
+            num_transitions <= Self::MAX_TRANSITIONS,
+            "Cannot sample an execution with {num_transitions} transitions (maximum is {})",
+            Self::MAX_TRANSITIONS
+        );
+
+        // Retrieve the genesis block.
+        let genesis = Block::<N>::read_le(N::genesis_bytes())?;
+        // Retrieve a transition to derive from.
+        let transition = match genesis.transitions().next() {
+            Some(transition) => transition,
+            None => bail!("The genesis block does not contain a transition"),
+        };
+
+        // Compute the function ID as `Hash(network_id, program_id, function_name)`.
+        let function_id = N::hash_bhp1024(
+            &(
+                U16::<N>::new(N::ID),
+                transition.program_id().name(),
+                transition.program_id().network(),
+                transition.function_name(),
+            )
+                .to_bits_le(),
+        )?;
+
+        // Samples a public value at the given index, with its ID as `Hash(function ID || value || tcm || index)`.
+        let mut sample_public = |index: u16| -> Result<(Field<N>, Plaintext<N>)> {
+            let plaintext = Plaintext::Literal(Literal::Field(Field::rand(rng)), Default::default());
+            let mut preimage = vec![function_id];
+            preimage.extend(plaintext.to_fields()?);
+            preimage.push(*transition.tcm());
+            preimage.push(Field::from_u16(index));
+            Ok((N::hash_psd8(&preimage)?, plaintext))
+        };
+
+        // Construct a distinct transition for each index, by sampling its input and output.
+        let transitions = (0..num_transitions)
+            .map(|_| {
+                let (input_id, input) = sample_public(0)?;
+                let (output_id, output) = sample_public(1)?;
+                transition.sample_derived(
+                    *transition.program_id(),
+                    *transition.function_name(),
+                    vec![Input::Public(input_id, Some(input))],
+                    vec![Output::Public(output_id, Some(output))],
+                    *transition.fee(),
+                )
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Return the execution.
+        Self::from(transitions.into_iter(), N::StateRoot::from(genesis.previous_state_root()), None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_sample_execution() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample the execution.
+        let execution = Execution::<CurrentNetwork>::sample_execution(4, rng)?;
+        assert_eq!(4, execution.len());
+
+        // Ensure the execution passes the structural checks, including those of `Process::verify_execution`.
+        execution.validate_against_limits()?;
+        execution.verify_unique_records()?;
+        for transition in execution.transitions() {
+            crate::Process::<CurrentNetwork>::verify_transition_structure(transition)?;
+        }
+
+        // Ensure the transitions are distinct.
+        let ids = execution.transitions().map(|transition| *transition.id()).collect::<IndexSet<_>>();
+        assert_eq!(execution.len(), ids.len());
+
+        // Ensure an empty execution, or one exceeding the maximum number of transitions, is rejected.
+        assert!(Execution::<CurrentNetwork>::sample_execution(0, rng).is_err());
+        let num_transitions = Execution::<CurrentNetwork>::MAX_TRANSITIONS + 1;
+        assert!(Execution::<CurrentNetwork>::sample_execution(num_transitions, rng).is_err());
+        Ok(())
+    }
+}
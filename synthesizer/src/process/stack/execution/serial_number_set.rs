@@ -0,0 +1,117 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// A growing set of the serial numbers spent across executions, for detecting double-spends.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SerialNumberSet<N: Network> {
+    /// The spent serial numbers, in insertion order.
+    serial_numbers: IndexSet<Field<N>>,
+}
+
+impl<N: Network> SerialNumberSet<N> {
+    /// Initializes a new, empty set of serial numbers.
+    pub fn new() -> Self {
+        Self { serial_numbers: IndexSet::new() }
+    }
+
+    /// Inserts all serial numbers of the given execution into the set.
+    ///
+    /// This method errors if a serial number of the execution is already in the set, or is spent twice
+    /// within the execution, naming the conflicting serial number. On error, the set is left unchanged.
+    pub fn insert_execution(&mut self, execution: &Execution<N>) -> Result<()> {
+        // Collect the serial numbers of the execution, ensuring there are no conflicts.
+        let mut serial_numbers = IndexSet::new();
+        for serial_number in execution.transitions().flat_map(Transition::serial_numbers) {
+            ensure!(
+                !self.serial_numbers.contains(serial_number) && serial_numbers.insert(*serial_number),
+                "Serial number '{serial_number}' is already spent"
+            );
+        }
+        // Insert the serial numbers.
+        self.serial_numbers.extend(serial_numbers);
+        Ok(())
+    }
+
+    /// Returns `true` if the given serial number is in the set.
+    pub fn contains(&self, serial_number: &Field<N>) -> bool {
+        self.serial_numbers.contains(serial_number)
+    }
+
+    /// Returns an iterator over the serial numbers, in insertion order.
+    pub fn iter(&self) -> impl '_ + ExactSizeIterator<Item = &Field<N>> {
+        self.serial_numbers.iter()
+    }
+
+    /// Returns the number of serial numbers in the set.
+    pub fn len(&self) -> usize {
+        self.serial_numbers.len()
+    }
+
+    /// Returns `true` if the set is empty.
+    pub fn is_empty(&self) -> bool {
+        self.serial_numbers.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Samples an execution with one transition for each given list of serial numbers.
+    fn sample_execution(serial_numbers: &[&[u64]]) -> Result<Execution<CurrentNetwork>> {
+        // Construct a transition consuming each list of serial numbers.
+        let transitions = serial_numbers.iter().map(|serial_numbers| {
+            let inputs = serial_numbers
+                .iter()
+                .map(|sn| crate::Input::Record(Field::from_u64(*sn), Field::from_u64(*sn + 1000)))
+                .collect();
+            crate::process::test_helpers::sample_transition_with(inputs, vec![])
+        });
+        Execution::from(transitions, Default::default(), None)
+    }
+
+    #[test]
+    fn test_insert_execution() -> Result<()> {
+        let mut set = SerialNumberSet::<CurrentNetwork>::new();
+        assert!(set.is_empty());
+
+        // Ensure non-conflicting executions are inserted.
+        set.insert_execution(&sample_execution(&[&[1, 2], &[3]])?)?;
+        set.insert_execution(&sample_execution(&[&[4], &[5, 6]])?)?;
+        assert_eq!(6, set.len());
+        for serial_number in 1..=6 {
+            assert!(set.contains(&Field::from_u64(serial_number)));
+        }
+        assert!(set.iter().copied().eq((1..=6).map(Field::from_u64)));
+
+        // Ensure an execution spending an already-spent serial number is rejected, and names it.
+        let error = set.insert_execution(&sample_execution(&[&[7], &[8, 3]])?).unwrap_err();
+        assert!(error.to_string().contains(&Field::<CurrentNetwork>::from_u64(3).to_string()));
+        // Ensure the set is unchanged.
+        assert_eq!(6, set.len());
+        assert!(!set.contains(&Field::from_u64(7)));
+
+        // Ensure an execution spending the same serial number twice is rejected.
+        assert!(set.insert_execution(&sample_execution(&[&[9, 9]])?).is_err());
+        assert_eq!(6, set.len());
+        Ok(())
+    }
+}
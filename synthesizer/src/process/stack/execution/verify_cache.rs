@@ -0,0 +1,209 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// A cache of the digests of the executions that were verified successfully,
+/// such that an execution seen again is not re-verified.
+///
+/// Only successful verifications are cached, as a failed verification may succeed later
+/// (e.g. once the program of the execution is deployed). Executions are keyed on `Execution::digest`,
+/// and thus any change to an execution, including to its global state root or inclusion proof, is a cache miss.
+///
+/// The cache holds up to `capacity` digests. Once it is full, recording a new digest evicts the oldest one,
+/// in insertion order, regardless of how often it was hit.
+///
+/// The cache is not internally synchronized. To share it across threads, wrap it in a `Mutex` or an `RwLock`,
+/// noting that `verify_cached` requires a write lock, as it records each successful verification.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExecutionVerifyCache<N: Network> {
+    /// The maximum number of digests in the cache.
+    capacity: usize,
+    /// The digests of the verified executions, in insertion order.
+    digests: IndexSet<Field<N>>,
+}
+
+impl<N: Network> ExecutionVerifyCache<N> {
+    /// The default maximum number of digests in the cache.
+    pub const DEFAULT_CAPACITY: usize = 1 << 16;
+
+    /// Initializes a new, empty cache, with the default capacity.
+    pub fn new() -> Self {
+        Self::with_capacity(Self::DEFAULT_CAPACITY)
+    }
+
+    /// Initializes a new, empty cache, holding up to the given number of digests.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self { capacity, digests: IndexSet::new() }
+    }
+
+    /// Verifies the given execution with the given verifier, unless the execution was already verified successfully.
+    /// For example, `cache.verify_cached(&execution, |execution| process.verify_execution::<true>(execution))`.
+    ///
+    /// On a cache miss, the execution is verified, and its digest is recorded if the verification succeeds,
+    /// evicting the oldest digest if the cache is full.
+    pub fn verify_cached<F>(&mut self, execution: &Execution<N>, verify: F) -> Result<()>
+    where
+        F: FnOnce(&Execution<N>) -> Result<()>,
+    {
+        // Compute the digest of the execution.
+        let digest = execution.digest()?;
+        // If the execution was already verified, return early.
+        if self.digests.contains(&digest) {
+            return Ok(());
+        }
+        // Verify the execution.
+        verify(execution)?;
+        // Record the successful verification.
+        self.digests.insert(digest);
+        // Evict the oldest digests, until the cache is within its capacity.
+        while self.digests.len() > self.capacity {
+            self.digests.shift_remove_index(0);
+        }
+        Ok(())
+    }
+
+    /// Returns `true` if the given execution was verified successfully.
+    pub fn contains(&self, execution: &Execution<N>) -> Result<bool> {
+        Ok(self.digests.contains(&execution.digest()?))
+    }
+
+    /// Removes all executions from the cache.
+    pub fn clear(&mut self) {
+        self.digests.clear()
+    }
+
+    /// Returns the maximum number of executions in the cache.
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of executions in the cache.
+    pub fn len(&self) -> usize {
+        self.digests.len()
+    }
+
+    /// Returns `true` if the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.digests.is_empty()
+    }
+}
+
+impl<N: Network> Default for ExecutionVerifyCache<N> {
+    /// Initializes a new, empty cache, with the default capacity.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    use std::cell::Cell;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_verify_cached() -> Result<()> {
+        // Sample an execution, and a modified copy of it with an additional transition.
+        let execution = crate::process::test_helpers::sample_execution();
+        let mut modified = execution.clone();
+        modified.push(crate::process::test_helpers::sample_transitions(&[("token.aleo", "mint")]).remove(0));
+        assert_ne!(execution.digest()?, modified.digest()?);
+
+        // Initialize a cache, and a verifier that counts the number of verifications.
+        let mut cache = ExecutionVerifyCache::<CurrentNetwork>::new();
+        let num_verifications = Cell::new(0);
+        let verify = |_: &Execution<CurrentNetwork>| {
+            num_verifications.set(num_verifications.get() + 1);
+            Ok(())
+        };
+
+        // Ensure the first verification misses the cache.
+        cache.verify_cached(&execution, verify)?;
+        assert_eq!(1, num_verifications.get());
+        assert!(cache.contains(&execution)?);
+
+        // Ensure the second verification of the same execution hits the cache.
+        cache.verify_cached(&execution, verify)?;
+        assert_eq!(1, num_verifications.get());
+        assert_eq!(1, cache.len());
+
+        // Ensure a modified execution misses the cache.
+        assert!(!cache.contains(&modified)?);
+        cache.verify_cached(&modified, verify)?;
+        assert_eq!(2, num_verifications.get());
+        assert_eq!(2, cache.len());
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_cached_failure() -> Result<()> {
+        let execution = crate::process::test_helpers::sample_execution();
+        let mut cache = ExecutionVerifyCache::<CurrentNetwork>::new();
+
+        // Ensure a failed verification is not cached.
+        assert!(cache.verify_cached(&execution, |_| bail!("Failed to verify the execution")).is_err());
+        assert!(!cache.contains(&execution)?);
+        assert!(cache.is_empty());
+
+        // Ensure the execution is verified again, and cached once it succeeds.
+        cache.verify_cached(&execution, |_| Ok(()))?;
+        assert!(cache.contains(&execution)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_cached_eviction() -> Result<()> {
+        // Sample three distinct executions.
+        let first = crate::process::test_helpers::sample_execution();
+        let transitions =
+            crate::process::test_helpers::sample_transitions(&[("token.aleo", "mint"), ("token.aleo", "burn")]);
+        let mut second = first.clone();
+        second.push(transitions[0].clone());
+        let mut third = second.clone();
+        third.push(transitions[1].clone());
+
+        // Initialize a cache holding up to two executions.
+        let mut cache = ExecutionVerifyCache::<CurrentNetwork>::with_capacity(2);
+        assert_eq!(2, cache.capacity());
+
+        // Ensure the cache holds the first two executions.
+        cache.verify_cached(&first, |_| Ok(()))?;
+        cache.verify_cached(&second, |_| Ok(()))?;
+        assert!(cache.contains(&first)?);
+        assert!(cache.contains(&second)?);
+
+        // Ensure a hit does not change the eviction order.
+        cache.verify_cached(&first, |_| Ok(()))?;
+
+        // Ensure the third execution evicts the first, as it is the oldest.
+        cache.verify_cached(&third, |_| Ok(()))?;
+        assert_eq!(2, cache.len());
+        assert!(!cache.contains(&first)?);
+        assert!(cache.contains(&second)?);
+        assert!(cache.contains(&third)?);
+
+        // Ensure the default cache has the default capacity.
+        assert_eq!(
+            ExecutionVerifyCache::<CurrentNetwork>::DEFAULT_CAPACITY,
+            ExecutionVerifyCache::<CurrentNetwork>::default().capacity()
+        );
+        Ok(())
+    }
+}